@@ -3,17 +3,22 @@
 //! Each time block is a region that can be patched independently.
 //! Uses deterministic UUIDs for stable region IDs across re-parses.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::sync::{Arc, RwLock};
 
 use anyhow::{Result, bail};
 use mti::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
 use survival_kernel::artifact::Artifact;
+use survival_kernel::pressure::Sensor;
 use survival_kernel::region::{Patch, PatchOp, RegionId, RegionView};
 use uuid::Uuid;
 
+use crate::sensors::{GapSensor, UtilizationSensor};
+
 /// Namespace UUID for generating deterministic region IDs (MTI v5).
 const REGION_NAMESPACE: Uuid = Uuid::from_bytes([
     0x7b, 0xa8, 0xc9, 0x20, 0xae, 0xbe, 0x22, 0xe2, 0x91, 0xc5, 0x01, 0xd1, 0x5e, 0xe5, 0x41, 0xda,
@@ -35,6 +40,17 @@ fn create_region_mti(schedule_id: &str, day: u8, block: u8) -> RegionId {
     MagicTypeId::new(prefix, suffix)
 }
 
+/// Create a deterministic region ID for a split or merged span of slots.
+fn create_span_mti(schedule_id: &str, day: u8, start_slot: u8, end_slot: u8) -> RegionId {
+    let name = format!(
+        "{}:day:{}:slots:{}-{}",
+        schedule_id, day, start_slot, end_slot
+    );
+    let v5_uuid = Uuid::new_v5(&REGION_NAMESPACE, name.as_bytes());
+    let prefix = TypeIdPrefix::try_from("region").expect("region is a valid prefix");
+    MagicTypeId::new(prefix, TypeIdSuffix::from(v5_uuid))
+}
+
 /// Unique identifier for a meeting.
 pub type MeetingId = u32;
 
@@ -302,7 +318,8 @@ pub struct ScheduleArtifact {
     region_order: Vec<RegionId>,
     /// Schedule identifier for deterministic region IDs.
     schedule_id: String,
-    /// Slots per time block (region granularity).
+    /// Slots per time block (initial region granularity; blocks may later be
+    /// split or merged).
     _slots_per_block: u8,
     /// Optional shared schedule for sensor synchronization.
     shared_schedule: Option<SharedSchedule>,
@@ -423,6 +440,19 @@ impl ScheduleArtifact {
         compute_pressure_from_grid(&self.schedule)
     }
 
+    /// Meetings occupying any slot of a time block.
+    fn block_meetings(&self, day: u8, start_slot: u8, end_slot: u8) -> HashSet<MeetingId> {
+        let mut meetings = HashSet::new();
+        for room in &self.schedule.rooms {
+            for slot in start_slot..end_slot {
+                if let Some(meeting_id) = self.schedule.get(room.id, day, slot) {
+                    meetings.insert(meeting_id);
+                }
+            }
+        }
+        meetings
+    }
+
     /// Get region metadata (day, start_slot, end_slot) for a region ID.
     pub fn region_metadata(&self, region_id: &RegionId) -> Option<(u8, u8, u8)> {
         self.region_map.get(region_id).copied()
@@ -499,6 +529,53 @@ impl ScheduleArtifact {
         (should_accept, delta)
     }
 
+    /// Change in every pressure signal if a patch were applied.
+    ///
+    /// Returns after-minus-before values keyed like the sensor signals
+    /// (`overlap_count`, `unscheduled_count`, and the patched block's
+    /// `gap_ratio` and `utilization_variance`), or None if the patch cannot be
+    /// applied.
+    pub fn evaluate_patch_signals(&self, patch: &Patch) -> Option<HashMap<String, f64>> {
+        let (day, start_slot, end_slot) = self.region_metadata(&patch.region)?;
+        let PatchOp::Replace(new_content) = &patch.op else {
+            return None;
+        };
+        let assignments = self
+            .parse_block_schedule(new_content, day, start_slot, end_slot)
+            .ok()?;
+
+        let mut test_schedule = self.schedule.clone();
+        apply_block_schedule_to_grid(&mut test_schedule, day, start_slot, end_slot, &assignments);
+
+        let overlaps =
+            test_schedule.count_overlaps() as f64 - self.schedule.count_overlaps() as f64;
+        let unscheduled =
+            test_schedule.count_unscheduled() as f64 - self.schedule.count_unscheduled() as f64;
+
+        // Gaps and utilization only change within the patched block
+        let view = Artifact::read_region(self, patch.region.clone()).ok()?;
+        let block_signals = |grid: ScheduleGrid| -> Option<HashMap<String, f64>> {
+            let grid: SharedSchedule = Arc::new(RwLock::new(grid));
+            let mut signals = GapSensor::new(grid.clone()).measure(&view).ok()?;
+            signals.extend(UtilizationSensor::new(grid).measure(&view).ok()?);
+            Some(signals)
+        };
+        let before = block_signals(self.schedule.clone())?;
+        let after = block_signals(test_schedule)?;
+        let block_delta =
+            |key: &str| after.get(key).unwrap_or(&0.0) - before.get(key).unwrap_or(&0.0);
+
+        Some(HashMap::from([
+            ("overlap_count".to_string(), overlaps),
+            ("unscheduled_count".to_string(), unscheduled),
+            ("gap_ratio".to_string(), block_delta("gap_ratio")),
+            (
+                "utilization_variance".to_string(),
+                block_delta("utilization_variance"),
+            ),
+        ]))
+    }
+
     /// Get rejected patches for a specific region (for prompt injection).
     ///
     /// Returns up to `max` patches, sorted by weight (highest first).
@@ -628,6 +705,41 @@ impl ScheduleArtifact {
         assignments
     }
 
+    /// Format block assignments as region content, one line per room.
+    ///
+    /// This is the format `parse_block_schedule` reads back.
+    fn format_block(
+        &self,
+        day: u8,
+        assignments: &HashMap<RoomId, Vec<(MeetingId, u8, u8)>>,
+    ) -> String {
+        let mut content_lines = Vec::new();
+
+        for room in &self.schedule.rooms {
+            let room_assignments = assignments.get(&room.id).cloned().unwrap_or_default();
+            if room_assignments.is_empty() {
+                content_lines.push(format!("Room {}: [empty]", room.name));
+            } else {
+                let meeting_strs: Vec<String> = room_assignments
+                    .iter()
+                    .map(|(id, start, end)| {
+                        let start_time = TimeSlot::new(day, *start);
+                        let end_time = TimeSlot::new(day, *end);
+                        format!(
+                            "{} ({}-{})",
+                            id,
+                            start_time.format().split(' ').nth(1).unwrap_or("??:??"),
+                            end_time.format().split(' ').nth(1).unwrap_or("??:??")
+                        )
+                    })
+                    .collect();
+                content_lines.push(format!("Room {}: {}", room.name, meeting_strs.join(", ")));
+            }
+        }
+
+        content_lines.join("\n")
+    }
+
     /// Parse a block schedule from LLM response.
     ///
     /// Expected format:
@@ -806,7 +918,7 @@ fn apply_block_schedule_to_grid(
 }
 
 /// Extract meeting ID from a string like "5 (10:00-11:00)" or "Meeting 5".
-fn extract_meeting_id(s: &str) -> Option<MeetingId> {
+pub(crate) fn extract_meeting_id(s: &str) -> Option<MeetingId> {
     // Try to find first number in the string
     let mut num_start = None;
     let mut num_end = 0;
@@ -830,7 +942,7 @@ fn extract_meeting_id(s: &str) -> Option<MeetingId> {
 }
 
 /// Extract start slot from time string like "(10:00-11:00)".
-fn extract_start_slot(s: &str) -> Option<u8> {
+pub(crate) fn extract_start_slot(s: &str) -> Option<u8> {
     // Look for HH:MM pattern
     let re = regex::Regex::new(r"(\d{1,2}):(\d{2})").ok()?;
     let caps = re.captures(s)?;
@@ -864,31 +976,7 @@ impl Artifact for ScheduleArtifact {
 
         // Build content: current assignments
         let assignments = self.block_assignments(day, start_slot, end_slot);
-        let mut content_lines = Vec::new();
-
-        for room in &self.schedule.rooms {
-            let room_assignments = assignments.get(&room.id).cloned().unwrap_or_default();
-            if room_assignments.is_empty() {
-                content_lines.push(format!("Room {}: [empty]", room.name));
-            } else {
-                let meeting_strs: Vec<String> = room_assignments
-                    .iter()
-                    .map(|(id, start, end)| {
-                        let start_time = TimeSlot::new(day, *start);
-                        let end_time = TimeSlot::new(day, *end);
-                        format!(
-                            "{} ({}-{})",
-                            id,
-                            start_time.format().split(' ').nth(1).unwrap_or("??:??"),
-                            end_time.format().split(' ').nth(1).unwrap_or("??:??")
-                        )
-                    })
-                    .collect();
-                content_lines.push(format!("Room {}: {}", room.name, meeting_strs.join(", ")));
-            }
-        }
-
-        let content = content_lines.join("\n");
+        let content = self.format_block(day, &assignments);
 
         // Build metadata
         let mut metadata = HashMap::new();
@@ -950,6 +1038,8 @@ impl Artifact for ScheduleArtifact {
             kind: "time_block".to_string(),
             content,
             metadata,
+            parent: None,
+            children: Vec::new(),
         })
     }
 
@@ -985,6 +1075,15 @@ impl Artifact for ScheduleArtifact {
         Ok(())
     }
 
+    fn snapshot(&self) -> Option<Box<dyn Artifact>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn on_restored(&mut self) {
+        // Sensors read the shared schedule, which still holds the abandoned one
+        self.sync_shared_schedule();
+    }
+
     fn source(&self) -> Option<String> {
         let mut lines = Vec::new();
 
@@ -1051,10 +1150,170 @@ impl Artifact for ScheduleArtifact {
         ScheduleArtifact::evaluate_patch(self, patch)
     }
 
+    fn evaluate_patch_signals(&self, patch: &Patch) -> Option<HashMap<String, f64>> {
+        ScheduleArtifact::evaluate_patch_signals(self, patch)
+    }
+
     fn total_pressure(&self) -> Option<f64> {
         // Return actual pressure from grid state
         Some(ScheduleArtifact::total_pressure(self))
     }
+
+    fn coupled_regions(&self, id: &RegionId) -> Vec<RegionId> {
+        // Blocks that still hold a meeting now placed in this block
+        let Some(&(day, start_slot, end_slot)) = self.region_map.get(id) else {
+            return Vec::new();
+        };
+        let block_meetings = self.block_meetings(day, start_slot, end_slot);
+        if block_meetings.is_empty() {
+            return Vec::new();
+        }
+
+        self.region_map
+            .iter()
+            .filter(|(rid, _)| *rid != id)
+            .filter(|(_, (d, s, e))| {
+                self.block_meetings(*d, *s, *e)
+                    .iter()
+                    .any(|m| block_meetings.contains(m))
+            })
+            .map(|(rid, _)| rid.clone())
+            .collect()
+    }
+
+    fn neighbors(&self, id: &RegionId) -> Vec<(RegionId, f64)> {
+        // Blocks directly before and after on the same day
+        let Some(&(day, start_slot, end_slot)) = self.region_map.get(id) else {
+            return Vec::new();
+        };
+        self.region_order
+            .iter()
+            .filter(|rid| {
+                self.region_map
+                    .get(*rid)
+                    .is_some_and(|&(d, s, e)| d == day && (e == start_slot || s == end_slot))
+            })
+            .map(|rid| (rid.clone(), 1.0))
+            .collect()
+    }
+
+    fn perturb_region(&self, id: &RegionId, seed: u64) -> Option<Patch> {
+        // Move one of the block's meetings, or place an unscheduled one that
+        // fits, to a random room and start, preferring free slots
+        let &(day, start_slot, end_slot) = self.region_map.get(id)?;
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut assignments = self.block_assignments(day, start_slot, end_slot);
+
+        let placed: Vec<(RoomId, usize)> = self
+            .schedule
+            .rooms
+            .iter()
+            .flat_map(|r| (0..assignments[&r.id].len()).map(move |i| (r.id, i)))
+            .collect();
+        let mut unscheduled = self.unscheduled_meetings_for_block(day, start_slot, end_slot);
+        unscheduled.sort_by_key(|m| m.id);
+
+        let (meeting_id, from) =
+            if !unscheduled.is_empty() && (placed.is_empty() || rng.random_bool(0.5)) {
+                (unscheduled[rng.random_range(0..unscheduled.len())].id, None)
+            } else if !placed.is_empty() {
+                let (room, i) = placed[rng.random_range(0..placed.len())];
+                let (meeting_id, start, _) = assignments.get_mut(&room)?.remove(i);
+                (meeting_id, Some((room, start)))
+            } else {
+                return None;
+            };
+
+        let duration = self
+            .schedule
+            .meetings
+            .get(&meeting_id)?
+            .duration_slots
+            .min(end_slot - start_slot);
+        let positions: Vec<(RoomId, u8)> = self
+            .schedule
+            .rooms
+            .iter()
+            .flat_map(|r| (start_slot..=end_slot - duration).map(move |s| (r.id, s)))
+            .filter(|&position| Some(position) != from)
+            .collect();
+        let free: Vec<(RoomId, u8)> = positions
+            .iter()
+            .copied()
+            .filter(|&(room, s)| {
+                assignments[&room]
+                    .iter()
+                    .all(|&(_, start, end)| s + duration <= start || end <= s)
+            })
+            .collect();
+        let candidates = if free.is_empty() { positions } else { free };
+        if candidates.is_empty() {
+            return None;
+        }
+        let (room, start) = candidates[rng.random_range(0..candidates.len())];
+
+        let room_assignments = assignments.get_mut(&room)?;
+        room_assignments.push((meeting_id, start, start + duration));
+        room_assignments.sort_by_key(|&(_, s, _)| s);
+
+        Some(Patch {
+            region: id.clone(),
+            op: PatchOp::Replace(self.format_block(day, &assignments)),
+            rationale: "stagnation perturbation".to_string(),
+            expected_delta: HashMap::new(),
+        })
+    }
+
+    fn split_region(&mut self, id: &RegionId) -> Option<Vec<RegionId>> {
+        // Halve the block; single-slot blocks cannot be split
+        let &(day, start_slot, end_slot) = self.region_map.get(id)?;
+        if end_slot - start_slot < 2 {
+            return None;
+        }
+        let mid_slot = start_slot + (end_slot - start_slot) / 2;
+        let halves = [(start_slot, mid_slot), (mid_slot, end_slot)];
+        let new_ids: Vec<RegionId> = halves
+            .iter()
+            .map(|&(s, e)| create_span_mti(&self.schedule_id, day, s, e))
+            .collect();
+
+        let position = self.region_order.iter().position(|rid| rid == id)?;
+        self.region_order
+            .splice(position..=position, new_ids.iter().cloned());
+        self.region_map.remove(id);
+        for (new_id, &(s, e)) in new_ids.iter().zip(&halves) {
+            self.region_map.insert(new_id.clone(), (day, s, e));
+        }
+        Some(new_ids)
+    }
+
+    fn merge_regions(&mut self, ids: &[RegionId]) -> Option<RegionId> {
+        // Only contiguous blocks on the same day merge
+        let mut spans: Vec<(u8, u8, u8)> = ids
+            .iter()
+            .map(|id| self.region_map.get(id).copied())
+            .collect::<Option<_>>()?;
+        spans.sort_by_key(|&(_, s, _)| s);
+        let day = spans.first()?.0;
+        let contiguous = spans
+            .windows(2)
+            .all(|w| w[0].0 == day && w[1].0 == day && w[0].2 == w[1].1);
+        if ids.len() < 2 || !contiguous {
+            return None;
+        }
+        let (start_slot, end_slot) = (spans[0].1, spans[spans.len() - 1].2);
+        let new_id = create_span_mti(&self.schedule_id, day, start_slot, end_slot);
+
+        let position = self.region_order.iter().position(|rid| ids.contains(rid))?;
+        self.region_order.insert(position, new_id.clone());
+        self.region_order.retain(|rid| !ids.contains(rid));
+        for id in ids {
+            self.region_map.remove(id);
+        }
+        self.region_map
+            .insert(new_id.clone(), (day, start_slot, end_slot));
+        Some(new_id)
+    }
 }
 
 impl fmt::Display for ScheduleArtifact {
@@ -1161,6 +1420,103 @@ mod tests {
         assert!(view.content.contains("Room C"));
     }
 
+    #[test]
+    fn test_perturb_region_changes_block() {
+        let mut artifact = sample_artifact();
+        let region = artifact.region_ids()[0].clone();
+
+        // Same seed, same patch; an empty block gets an unscheduled meeting
+        let patch = artifact.perturb_region(&region, 7).unwrap();
+        assert_eq!(patch.op, artifact.perturb_region(&region, 7).unwrap().op);
+        artifact.apply_patch(patch).unwrap();
+        assert_eq!(artifact.schedule.count_unscheduled(), 2);
+
+        // Later kicks rewrite the block without losing what it holds
+        for seed in 0..10 {
+            let before = artifact.read_region(region.clone()).unwrap().content;
+            let patch = artifact.perturb_region(&region, seed).unwrap();
+            artifact.apply_patch(patch).unwrap();
+            assert_ne!(
+                artifact.read_region(region.clone()).unwrap().content,
+                before
+            );
+            assert!(artifact.schedule.count_unscheduled() <= 2);
+        }
+    }
+
+    #[test]
+    fn test_patch_signals_cover_every_axis() {
+        let artifact = sample_artifact();
+        let region = artifact.region_ids()[0].clone();
+
+        // Placing an unscheduled meeting fills part of the empty block
+        let patch = artifact.perturb_region(&region, 7).unwrap();
+        let deltas = artifact.evaluate_patch_signals(&patch).unwrap();
+        let mut keys: Vec<_> = deltas.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            [
+                "gap_ratio",
+                "overlap_count",
+                "unscheduled_count",
+                "utilization_variance"
+            ]
+        );
+        assert_eq!(deltas["unscheduled_count"], -1.0);
+        assert!(deltas["gap_ratio"] < 0.0);
+    }
+
+    #[test]
+    fn test_restored_snapshot_resyncs_shared_schedule() {
+        let shared: SharedSchedule = Arc::new(RwLock::new(sample_artifact().schedule.clone()));
+        let mut artifact = sample_artifact().with_shared_schedule(shared.clone());
+        let region = artifact.region_ids()[0].clone();
+        let mut restored = artifact.snapshot().unwrap();
+
+        let patch = artifact.perturb_region(&region, 1).unwrap();
+        artifact.apply_patch(patch).unwrap();
+        assert_eq!(shared.read().unwrap().count_unscheduled(), 2);
+
+        restored.on_restored();
+        assert_eq!(shared.read().unwrap().count_unscheduled(), 3);
+    }
+
+    #[test]
+    fn test_split_and_merge_blocks() {
+        let mut artifact = sample_artifact();
+        let first = artifact.region_ids()[0].clone();
+
+        let halves = artifact.split_region(&first).unwrap();
+        assert_eq!(halves.len(), 2);
+        assert_eq!(artifact.region_ids().len(), 21);
+        assert_eq!(artifact.region_ids()[..2], halves[..]);
+        assert_eq!(artifact.region_metadata(&halves[0]), Some((0, 0, 2)));
+        assert_eq!(artifact.region_metadata(&halves[1]), Some((0, 2, 4)));
+        assert!(artifact.region_metadata(&first).is_none());
+
+        let neighbors: Vec<RegionId> = artifact
+            .neighbors(&halves[1])
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert!(neighbors.contains(&halves[0]));
+        assert_eq!(neighbors.len(), 2);
+
+        let merged = artifact.merge_regions(&halves).unwrap();
+        assert_eq!(artifact.region_ids().len(), 20);
+        assert_eq!(artifact.region_ids()[0], merged);
+        assert_eq!(artifact.region_metadata(&merged), Some((0, 0, 4)));
+
+        // Blocks on different days do not merge
+        let ids = artifact.region_ids();
+        assert!(
+            artifact
+                .merge_regions(&[ids[3].clone(), ids[4].clone()])
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_region_ids_stable() {
         let artifact1 = sample_artifact();
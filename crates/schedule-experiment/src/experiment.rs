@@ -19,12 +19,13 @@ use tracing::{debug, info, warn};
 use acton_reactive::prelude::*;
 use survival_kernel::artifact::Artifact;
 use survival_kernel::config::{
-    ActivationConfig, DecayConfig, KernelConfig, PressureAxisConfig, SelectionConfig,
+    AcceptanceMode, ActivationConfig, DecayConfig, ExecutionMode, HalfLife, KernelConfig,
+    PressureAxisConfig, SelectionConfig, StagnationConfig,
 };
 use survival_kernel::pressure::Sensor;
 use survival_kernel::{
-    AsyncKernelBuilder, PatchActorsReady, SensorsReady, Tick, TickComplete, TickResult,
-    WaitForPatchActors, WaitForSensors,
+    AsyncKernelBuilder, CouplingMatrix, Normalization, PatchActorsReady, SensorsReady, Tick,
+    TickComplete, TickResult, WaitForPatchActors, WaitForSensors,
 };
 
 use crate::artifact::{ScheduleArtifact, SharedSchedule};
@@ -34,6 +35,7 @@ use crate::generator::{ScheduleGenerator, ScheduleGeneratorConfig};
 use crate::llm_actor::{
     LlmActor, LlmActorConfig, SamplingBand, SamplingConfig, UpdateBand, UpdateModel,
 };
+use crate::repair::{DropUnknownMeetings, SnapToBlock};
 use crate::results::{
     BandEscalationEvent, ConversationStats, EscalationEvent, ExperimentConfig, ExperimentResult,
     PatchRejection, TickMetrics,
@@ -81,6 +83,22 @@ pub struct ExperimentRunnerConfig {
     pub examples_enabled: bool,
     /// Example bank configuration
     pub example_bank_config: ExampleBankConfig,
+    /// Run regions autonomously (tickless async mode) instead of in lockstep
+    pub tickless: bool,
+    /// Repair near-miss proposals (unknown meetings, block overruns) before evaluation
+    pub repair_patches: bool,
+    /// Reject any patch that adds an overlap, whatever else it improves
+    pub hard_overlaps: bool,
+    /// Accept only patches that worsen no pressure axis
+    pub pareto: bool,
+    /// Rescaling applied to every pressure axis signal before weighting
+    pub normalization: Normalization,
+    /// Count decay half-lives in ticks instead of wall-clock time
+    pub tick_decay: bool,
+    /// Ticks without pressure improvement before the kernel kicks the
+    /// highest-pressure regions (0 = never); see
+    /// `ScheduleArtifact::perturb_region` for what a kick changes
+    pub stagnation_patience: usize,
 }
 
 impl Default for ExperimentRunnerConfig {
@@ -103,6 +121,13 @@ impl Default for ExperimentRunnerConfig {
             inhibition_enabled: true,
             examples_enabled: true,
             example_bank_config: ExampleBankConfig::default(),
+            tickless: false,
+            repair_patches: false,
+            hard_overlaps: false,
+            pareto: false,
+            normalization: Normalization::None,
+            tick_decay: false,
+            stagnation_patience: 0,
         }
     }
 }
@@ -330,6 +355,7 @@ impl ExperimentRunner {
                 max_tokens: 256, // Schedules need more tokens than Latin squares
                 band,
                 randomize_sampling: true,
+                ..LlmActorConfig::default()
             };
 
             LlmActor::spawn(
@@ -344,10 +370,17 @@ impl ExperimentRunner {
         }
 
         // Build kernel and spawn
-        let coordinator_handle = AsyncKernelBuilder::new(kernel_config, Box::new(artifact.clone()))
-            .add_sensor(Box::new(sensor))
-            .spawn(&mut runtime)
-            .await;
+        let mut builder = AsyncKernelBuilder::new(kernel_config, Box::new(artifact.clone()))
+            .add_sensor(Box::new(sensor));
+        if self.config.repair_patches {
+            let meetings = artifact.meetings();
+            builder = builder
+                .add_transformer(Box::new(DropUnknownMeetings::new(meetings.keys().copied())))
+                .add_transformer(Box::new(SnapToBlock::new(
+                    meetings.values().map(|m| (m.id, m.duration_slots)),
+                )));
+        }
+        let coordinator_handle = builder.spawn(&mut runtime).await;
 
         // Create observer to collect TickComplete broadcasts
         let (tick_tx, mut tick_rx) = tokio::sync::mpsc::channel::<TickResult>(1000);
@@ -391,6 +424,7 @@ impl ExperimentRunner {
         let mut total_completion_tokens = 0u32;
         let mut final_pressure = 0.0;
         let mut solved = false;
+        let mut coupling = CouplingMatrix::new();
 
         info!(
             max_ticks,
@@ -421,6 +455,9 @@ impl ExperimentRunner {
             total_prompt_tokens += result.prompt_tokens;
             total_completion_tokens += result.completion_tokens;
             final_pressure = result.total_pressure;
+            for sample in &result.coupling {
+                coupling.record(sample);
+            }
 
             // Track velocity based on actual pressure improvement (not just patch application)
             // This ensures escalation triggers when stuck even if patches are being applied
@@ -634,6 +671,8 @@ impl ExperimentRunner {
                     completion_tokens: result.completion_tokens,
                     patch_rejections: HashMap::new(),
                     messages_per_tick: None,
+                    axis_pressures: result.axis_pressures.clone(),
+                    normalization: result.normalization.clone(),
                 }
             })
             .collect();
@@ -669,6 +708,7 @@ impl ExperimentRunner {
             total_completion_tokens,
             total_patch_rejections: HashMap::new(),
             conversation_stats: None,
+            coupling: Some(coupling.report()),
         })
     }
 
@@ -791,6 +831,8 @@ impl ExperimentRunner {
                             kind: "time_block".to_string(),
                             content: new_content.clone(),
                             metadata: region_view.metadata.clone(),
+                            parent: region_view.parent.clone(),
+                            children: region_view.children.clone(),
                         };
                         let pressure_after =
                             self.measure_region_pressure(&temp_view, &ctx.sensor)?;
@@ -861,6 +903,8 @@ impl ExperimentRunner {
                 completion_tokens: response.completion_tokens,
                 patch_rejections: HashMap::new(),
                 messages_per_tick: None,
+                axis_pressures: HashMap::new(),
+                normalization: HashMap::new(),
             });
 
             // Check completion
@@ -920,6 +964,7 @@ impl ExperimentRunner {
             total_completion_tokens,
             total_patch_rejections,
             conversation_stats: None,
+            coupling: None,
         })
     }
 
@@ -1076,6 +1121,8 @@ impl ExperimentRunner {
                 completion_tokens: state.completion_tokens,
                 patch_rejections: HashMap::new(),
                 messages_per_tick: Some(messages_this_tick),
+                axis_pressures: HashMap::new(),
+                normalization: HashMap::new(),
             });
 
             // Check completion
@@ -1167,6 +1214,7 @@ impl ExperimentRunner {
                 consensus_rate,
                 avg_turns_to_consensus,
             }),
+            coupling: None,
         })
     }
 
@@ -1181,24 +1229,36 @@ impl ExperimentRunner {
                 weight: 1.0,
                 expr: "gap_ratio".to_string(),
                 kind_weights: HashMap::new(),
+                hard: false,
+                priority: 0,
+                normalization: self.config.normalization,
             },
             PressureAxisConfig {
                 name: "overlaps".to_string(),
                 weight: 2.0,
                 expr: "overlap_count".to_string(),
                 kind_weights: HashMap::new(),
+                hard: self.config.hard_overlaps,
+                priority: 0,
+                normalization: self.config.normalization,
             },
             PressureAxisConfig {
                 name: "utilization".to_string(),
                 weight: 0.5,
                 expr: "utilization_variance".to_string(),
                 kind_weights: HashMap::new(),
+                hard: false,
+                priority: 0,
+                normalization: self.config.normalization,
             },
             PressureAxisConfig {
                 name: "unscheduled".to_string(),
                 weight: 1.5,
                 expr: "unscheduled_count".to_string(),
                 kind_weights: HashMap::new(),
+                hard: false,
+                priority: 0,
+                normalization: self.config.normalization,
             },
         ];
 
@@ -1214,6 +1274,14 @@ impl ExperimentRunner {
                 u64::MAX
             },
             ema_alpha: 0.2,
+            // Same half-lives counted in ticks, independent of LLM latency
+            fitness_half_life: (self.config.tick_decay && self.config.decay_enabled)
+                .then_some(HalfLife::Ticks(5)),
+            confidence_half_life: (self.config.tick_decay && self.config.decay_enabled)
+                .then_some(HalfLife::Ticks(10)),
+            pressure_half_life: None,
+            axes: HashMap::new(),
+            kinds: HashMap::new(),
         };
 
         let activation = ActivationConfig {
@@ -1227,6 +1295,11 @@ impl ExperimentRunner {
 
         let selection = SelectionConfig {
             min_expected_improvement: 0.0,
+            acceptance: if self.config.pareto {
+                AcceptanceMode::Pareto
+            } else {
+                AcceptanceMode::Weighted
+            },
         };
 
         KernelConfig {
@@ -1237,6 +1310,16 @@ impl ExperimentRunner {
             max_ticks: self.config.max_ticks,
             tick_interval_ms,
             stable_threshold: 10,
+            mode: if self.config.tickless {
+                ExecutionMode::Async
+            } else {
+                ExecutionMode::Tick
+            },
+            stagnation: StagnationConfig {
+                patience: self.config.stagnation_patience,
+                ..StagnationConfig::default()
+            },
+            ..KernelConfig::default()
         }
     }
 
@@ -21,6 +21,7 @@ pub mod example_bank;
 pub mod experiment;
 pub mod generator;
 pub mod llm_actor;
+pub mod repair;
 pub mod results;
 pub mod sensors;
 pub mod vllm_client;
@@ -5,6 +5,8 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 use acton_reactive::prelude::*;
 use anyhow::Result;
@@ -13,7 +15,8 @@ use tokio::sync::{RwLock, Semaphore};
 use tracing::{debug, info, warn};
 
 use survival_kernel::messages::{
-    CoordinatorReady, PatchActorReady, PatchProposal, ProposeForRegion,
+    CoordinatorReady, DepartureReason, PatchActorGone, PatchActorHeartbeat, PatchActorReady,
+    PatchProposal, ProposeForRegion,
 };
 use survival_kernel::region::{Patch, PatchOp};
 
@@ -131,6 +134,9 @@ pub struct LlmActorConfig {
     pub band: SamplingBand,
     /// Whether to use random sampling within the band
     pub randomize_sampling: bool,
+    /// Broadcast a `PatchActorHeartbeat` this often (milliseconds, 0 = never),
+    /// so the coordinator's heartbeat timeout does not evict an idle actor
+    pub heartbeat_interval_ms: u64,
 }
 
 impl Default for LlmActorConfig {
@@ -142,6 +148,7 @@ impl Default for LlmActorConfig {
             max_tokens: 256, // Schedules need more tokens than Latin squares
             band: SamplingBand::Balanced,
             randomize_sampling: true,
+            heartbeat_interval_ms: 1_000,
         }
     }
 }
@@ -159,6 +166,8 @@ pub struct LlmActorState {
     pub example_bank: Option<Arc<RwLock<ExampleBank>>>,
     /// Shared artifact for negative pheromone queries
     pub artifact: Option<Arc<ScheduleArtifact>>,
+    /// Set once the actor is stopping, ending its heartbeats
+    pub stopping: Arc<AtomicBool>,
 }
 
 impl std::fmt::Debug for LlmActorState {
@@ -184,6 +193,7 @@ impl LlmActor {
         artifact: Arc<ScheduleArtifact>,
     ) -> ActorHandle {
         let mut actor = runtime.new_actor_with_name::<LlmActorState>(name.clone());
+        let heartbeat_interval = Duration::from_millis(config.heartbeat_interval_ms);
 
         actor.model.name = name;
         actor.model.config = Some(Arc::new(RwLock::new(config)));
@@ -211,6 +221,49 @@ impl LlmActor {
             })
         });
 
+        // Once started, keep telling the coordinator we are alive
+        actor.after_start(move |actor| {
+            let broker = actor.broker().clone();
+            let actor_ern = actor.handle().name().to_string();
+            let stopping = actor.model.stopping.clone();
+
+            async move {
+                if heartbeat_interval.is_zero() {
+                    return;
+                }
+                tokio::spawn(async move {
+                    let mut interval = tokio::time::interval(heartbeat_interval);
+                    loop {
+                        interval.tick().await;
+                        if stopping.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        broker
+                            .broadcast(PatchActorHeartbeat {
+                                actor_ern: actor_ern.clone(),
+                            })
+                            .await;
+                    }
+                });
+            }
+        });
+
+        // On stop, leave the coordinator's dispatch pool
+        actor.before_stop(|actor| {
+            let broker = actor.broker().clone();
+            let actor_ern = actor.handle().name().to_string();
+            actor.model.stopping.store(true, Ordering::Relaxed);
+
+            async move {
+                broker
+                    .broadcast(PatchActorGone {
+                        actor_ern,
+                        reason: DepartureReason::Stopped,
+                    })
+                    .await;
+            }
+        });
+
         // Handle model updates during escalation
         actor.act_on::<UpdateModel>(|actor, context| {
             let msg = context.message().clone();
@@ -261,6 +314,7 @@ impl LlmActor {
             let example_bank = actor.model.example_bank.clone();
             let artifact = actor.model.artifact.clone();
             let actor_name = actor.model.name.clone();
+            let actor_ern = actor.handle().name().to_string();
 
             Reply::pending(async move {
                 // Acquire semaphore permit for rate limiting
@@ -296,6 +350,8 @@ impl LlmActor {
                 let config_guard = config.as_ref().unwrap().read().await;
                 let result =
                     generate_schedule_patch(&config_guard, &msg, &examples, &rejected).await;
+                let model = Some(config_guard.model.clone());
+                let sampling_band = Some(format!("{:?}", sampling_band(&config_guard, &msg)));
 
                 drop(config_guard);
 
@@ -306,7 +362,11 @@ impl LlmActor {
                         broker
                             .broadcast(PatchProposal {
                                 correlation_id: msg.correlation_id.clone(),
+                                region_id: msg.region_id.clone(),
                                 actor_name,
+                                actor_ern,
+                                model,
+                                sampling_band,
                                 patches,
                                 prompt_tokens,
                                 completion_tokens,
@@ -318,7 +378,11 @@ impl LlmActor {
                         broker
                             .broadcast(PatchProposal {
                                 correlation_id: msg.correlation_id.clone(),
+                                region_id: msg.region_id.clone(),
                                 actor_name,
+                                actor_ern,
+                                model,
+                                sampling_band,
                                 patches: vec![],
                                 prompt_tokens: 0,
                                 completion_tokens: 0,
@@ -333,6 +397,15 @@ impl LlmActor {
     }
 }
 
+/// Sampling band a proposal for `msg` is generated with.
+fn sampling_band(config: &LlmActorConfig, msg: &ProposeForRegion) -> SamplingBand {
+    if msg.explore_seed.is_some() {
+        SamplingBand::Exploration
+    } else {
+        config.band
+    }
+}
+
 /// Generate a schedule patch using the LLM.
 async fn generate_schedule_patch(
     config: &LlmActorConfig,
@@ -466,9 +539,9 @@ Answer:"#,
         "Generate patch"
     );
 
-    // Determine sampling parameters
-    let sampling = if config.randomize_sampling {
-        SamplingConfig::random_in_band(config.band)
+    // Determine sampling parameters (stagnation kicks ask for exploration)
+    let sampling = if config.randomize_sampling || msg.explore_seed.is_some() {
+        SamplingConfig::random_in_band(sampling_band(config, msg))
     } else {
         config.sampling.clone()
     };
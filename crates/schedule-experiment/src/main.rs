@@ -13,6 +13,7 @@ use schedule_experiment::ScheduleGenerator;
 use schedule_experiment::experiment::{ExperimentRunner, ExperimentRunnerConfig, Strategy};
 use schedule_experiment::generator::ScheduleGeneratorConfig;
 use schedule_experiment::results::GridResults;
+use survival_kernel::Normalization;
 use survival_kernel::artifact::Artifact;
 
 #[derive(Parser)]
@@ -67,6 +68,28 @@ enum Commands {
         /// Output file for results (JSON)
         #[arg(short, long)]
         output: Option<PathBuf>,
+        /// Let regions act on their own pressure instead of a global tick barrier
+        #[arg(long)]
+        tickless: bool,
+        /// Repair near-miss proposals before evaluation
+        #[arg(long)]
+        repair_patches: bool,
+        /// Never accept a patch that adds an overlap
+        #[arg(long)]
+        hard_overlaps: bool,
+        /// Only accept patches that worsen no pressure axis
+        #[arg(long)]
+        pareto: bool,
+        /// Axis signal normalization: none, min_max, z_score, rank
+        #[arg(long, default_value = "none")]
+        normalize: String,
+        /// Count decay half-lives in ticks instead of wall-clock time
+        #[arg(long)]
+        tick_decay: bool,
+        /// Kick the highest-pressure regions after this many ticks without improvement (0 = never).
+        /// A kick moves or places one meeting in each kicked block and clears its inhibition
+        #[arg(long, default_value = "0")]
+        stagnation_patience: usize,
     },
 
     /// Run a grid of experiments.
@@ -126,6 +149,16 @@ fn parse_strategy(s: &str) -> Option<Strategy> {
     }
 }
 
+fn parse_normalization(s: &str) -> Option<Normalization> {
+    match s.to_lowercase().as_str() {
+        "none" => Some(Normalization::None),
+        "min_max" | "minmax" => Some(Normalization::MinMax),
+        "z_score" | "zscore" => Some(Normalization::ZScore),
+        "rank" => Some(Normalization::Rank),
+        _ => None,
+    }
+}
+
 fn parse_difficulty(s: &str) -> ScheduleGeneratorConfig {
     match s.to_lowercase().as_str() {
         "easy" => ScheduleGeneratorConfig::easy(),
@@ -169,6 +202,13 @@ async fn main() -> Result<()> {
             seed,
             max_ticks,
             output,
+            tickless,
+            repair_patches,
+            hard_overlaps,
+            pareto,
+            normalize,
+            tick_decay,
+            stagnation_patience,
         } => {
             let strategy = parse_strategy(&strategy).unwrap_or_else(|| {
                 eprintln!("Unknown strategy: {}. Using 'pressure_field'.", strategy);
@@ -192,6 +232,16 @@ async fn main() -> Result<()> {
                 model_chain,
                 generator_config,
                 max_ticks,
+                tickless,
+                repair_patches,
+                hard_overlaps,
+                pareto,
+                normalization: parse_normalization(&normalize).unwrap_or_else(|| {
+                    eprintln!("Unknown normalization: {}. Using 'none'.", normalize);
+                    Normalization::None
+                }),
+                tick_decay,
+                stagnation_patience,
                 ..Default::default()
             };
 
@@ -0,0 +1,243 @@
+//! Deterministic repairs for near-miss schedule block patches.
+//!
+//! Plugged into the kernel with `AsyncKernelBuilder::add_transformer`, these
+//! fix up LLM proposals that are almost right instead of letting the whole
+//! block be rejected. Each repair is recorded in the patch's provenance.
+//!
+//! Both operate on the block format parsed by
+//! `ScheduleArtifact::parse_block_schedule`:
+//!
+//! ```text
+//! Room A: 5 (10:00-11:00), 7 (11:00-12:00)
+//! Room B: [empty]
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use survival_kernel::artifact::Artifact;
+use survival_kernel::region::{Patch, PatchOp};
+use survival_kernel::transform::{PatchTransformer, Transformed};
+
+use crate::artifact::{MeetingId, TimeSlot, extract_meeting_id, extract_start_slot};
+
+/// Drops assignments of meetings that do not exist.
+#[derive(Debug, Clone)]
+pub struct DropUnknownMeetings {
+    /// Every meeting ID in the schedule
+    known: HashSet<MeetingId>,
+}
+
+impl DropUnknownMeetings {
+    /// Create a repair that keeps only the given meeting IDs.
+    pub fn new(known: impl IntoIterator<Item = MeetingId>) -> Self {
+        Self {
+            known: known.into_iter().collect(),
+        }
+    }
+}
+
+impl PatchTransformer for DropUnknownMeetings {
+    fn name(&self) -> &str {
+        "drop_unknown_meetings"
+    }
+
+    fn transform(
+        &self,
+        patch: &Patch,
+        _artifact: &dyn Artifact,
+        _earlier: &[Patch],
+    ) -> Transformed {
+        let mut dropped = Vec::new();
+        let rewritten = rewrite_entries(patch, |entry| match extract_meeting_id(entry) {
+            Some(id) if !self.known.contains(&id) => {
+                dropped.push(id);
+                None
+            }
+            _ => Some(entry.to_string()),
+        });
+        match rewritten {
+            Some(patch) if !dropped.is_empty() => Transformed::Rewritten {
+                patch,
+                note: format!("dropped unknown meetings {:?}", dropped),
+            },
+            _ => Transformed::Unchanged,
+        }
+    }
+}
+
+/// Moves meetings that start before or run past their block back inside it.
+#[derive(Debug, Clone)]
+pub struct SnapToBlock {
+    /// Duration in slots of every meeting
+    durations: HashMap<MeetingId, u8>,
+}
+
+impl SnapToBlock {
+    /// Create a repair from each meeting's duration in slots.
+    pub fn new(durations: impl IntoIterator<Item = (MeetingId, u8)>) -> Self {
+        Self {
+            durations: durations.into_iter().collect(),
+        }
+    }
+}
+
+impl PatchTransformer for SnapToBlock {
+    fn name(&self) -> &str {
+        "snap_to_block"
+    }
+
+    fn transform(&self, patch: &Patch, artifact: &dyn Artifact, _earlier: &[Patch]) -> Transformed {
+        let Ok(view) = artifact.read_region(patch.region.clone()) else {
+            return Transformed::Unchanged;
+        };
+        let slot_of = |key: &str| view.metadata.get(key)?.as_u64().map(|v| v as u8);
+        let (Some(day), Some(start_slot), Some(end_slot)) =
+            (slot_of("day"), slot_of("start_slot"), slot_of("end_slot"))
+        else {
+            return Transformed::Unchanged;
+        };
+
+        let mut snapped = Vec::new();
+        let rewritten = rewrite_entries(patch, |entry| {
+            let (Some(id), Some(slot)) = (extract_meeting_id(entry), extract_start_slot(entry))
+            else {
+                return Some(entry.to_string());
+            };
+            let Some(&duration) = self.durations.get(&id) else {
+                return Some(entry.to_string());
+            };
+            if duration > end_slot - start_slot {
+                return Some(entry.to_string());
+            }
+            let new_slot = slot.clamp(start_slot, end_slot - duration);
+            if new_slot == slot {
+                return Some(entry.to_string());
+            }
+            snapped.push(id);
+            Some(format!(
+                "{} ({}-{})",
+                id,
+                clock(day, new_slot),
+                clock(day, new_slot + duration)
+            ))
+        });
+        match rewritten {
+            Some(patch) if !snapped.is_empty() => Transformed::Rewritten {
+                patch,
+                note: format!("snapped meetings {:?} into the block", snapped),
+            },
+            _ => Transformed::Unchanged,
+        }
+    }
+}
+
+/// "HH:MM" for a slot.
+fn clock(day: u8, slot: u8) -> String {
+    TimeSlot::new(day, slot)
+        .format()
+        .split(' ')
+        .nth(1)
+        .unwrap_or("??:??")
+        .to_string()
+}
+
+/// Rewrite each meeting entry of a `Replace` patch (`None` drops the entry).
+///
+/// Returns `None` for other patch operations.
+fn rewrite_entries(patch: &Patch, mut edit: impl FnMut(&str) -> Option<String>) -> Option<Patch> {
+    let PatchOp::Replace(content) = &patch.op else {
+        return None;
+    };
+
+    let lines: Vec<String> = content
+        .lines()
+        .map(|line| {
+            let Some(colon_pos) = line.find(':') else {
+                return line.to_string();
+            };
+            let (room, entries) = (&line[..colon_pos], &line[colon_pos + 1..]);
+            if entries.to_lowercase().contains("empty") {
+                return line.to_string();
+            }
+            let kept: Vec<String> = entries
+                .split(',')
+                .map(str::trim)
+                .filter(|entry| !entry.is_empty())
+                .filter_map(&mut edit)
+                .collect();
+            if kept.is_empty() {
+                format!("{}: [empty]", room)
+            } else {
+                format!("{}: {}", room, kept.join(", "))
+            }
+        })
+        .collect();
+
+    Some(Patch {
+        op: PatchOp::Replace(lines.join("\n")),
+        ..patch.clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::{Meeting, Room, ScheduleArtifact};
+
+    fn artifact() -> ScheduleArtifact {
+        let rooms = vec![Room {
+            id: 0,
+            name: "A".to_string(),
+            capacity: 10,
+        }];
+        let meetings = vec![Meeting {
+            id: 1,
+            duration_slots: 2,
+            attendees: vec![1],
+            preferred_rooms: vec![0],
+            scheduled: None,
+        }];
+        // One day, 8:00-12:00 in 2-hour blocks
+        ScheduleArtifact::new(rooms, meetings, 1, 8, 4, "repair-test").unwrap()
+    }
+
+    fn replace(artifact: &ScheduleArtifact, content: &str) -> Patch {
+        Patch {
+            region: artifact.region_ids()[0].clone(),
+            op: PatchOp::Replace(content.to_string()),
+            rationale: String::new(),
+            expected_delta: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_unknown_meetings_are_dropped() {
+        let artifact = artifact();
+        let repair = DropUnknownMeetings::new([1]);
+        let patch = replace(&artifact, "Room A: 1 (08:00-09:00), 9 (09:00-10:00)");
+
+        let Transformed::Rewritten { patch, .. } = repair.transform(&patch, &artifact, &[]) else {
+            panic!("expected a rewrite");
+        };
+        assert_eq!(patch.op, PatchOp::Replace("Room A: 1 (08:00-09:00)".into()));
+
+        let clean = replace(&artifact, "Room A: 1 (08:00-09:00)");
+        assert!(matches!(
+            repair.transform(&clean, &artifact, &[]),
+            Transformed::Unchanged
+        ));
+    }
+
+    #[test]
+    fn test_meeting_overrunning_block_is_snapped_back() {
+        let artifact = artifact();
+        let repair = SnapToBlock::new([(1, 2)]);
+        // Block is 08:00-10:00; 09:30-10:30 overruns by one slot
+        let patch = replace(&artifact, "Room A: 1 (09:30-10:30)");
+
+        let Transformed::Rewritten { patch, .. } = repair.transform(&patch, &artifact, &[]) else {
+            panic!("expected a rewrite");
+        };
+        assert_eq!(patch.op, PatchOp::Replace("Room A: 1 (09:00-10:00)".into()));
+    }
+}
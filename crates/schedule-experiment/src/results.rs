@@ -15,6 +15,7 @@ use std::path::Path;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use survival_kernel::{CouplingReport, NormalizationStats};
 
 use crate::example_bank::ExampleBankStats;
 
@@ -105,6 +106,9 @@ pub struct ExperimentResult {
     /// Conversation statistics (for Conversation strategy only)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub conversation_stats: Option<ConversationStats>,
+    /// Measured inter-region coupling (pressure-field strategy only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coupling: Option<CouplingReport>,
 }
 
 /// Configuration for an experiment.
@@ -154,6 +158,12 @@ pub struct TickMetrics {
     /// Number of conversation messages exchanged (for Conversation strategy only)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub messages_per_tick: Option<usize>,
+    /// Measured pressure per axis (kernel strategies only)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub axis_pressures: HashMap<String, f64>,
+    /// Running statistics of each normalized axis (kernel strategies only)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub normalization: HashMap<String, NormalizationStats>,
 }
 
 /// Statistics about conversation-based coordination (AutoGen-style baseline).
@@ -392,6 +402,7 @@ mod tests {
                 total_completion_tokens: 50,
                 total_patch_rejections: HashMap::new(),
                 conversation_stats: None,
+                coupling: None,
             });
         }
 
@@ -444,6 +455,7 @@ mod tests {
                 total_completion_tokens: 0,
                 total_patch_rejections: HashMap::new(),
                 conversation_stats: None,
+                coupling: None,
             });
         }
 
@@ -492,6 +504,7 @@ mod tests {
                 total_completion_tokens: 0,
                 total_patch_rejections: HashMap::new(),
                 conversation_stats: None,
+                coupling: None,
             });
         }
 
@@ -540,6 +553,7 @@ mod tests {
                 total_completion_tokens: 0,
                 total_patch_rejections: HashMap::new(),
                 conversation_stats: None,
+                coupling: None,
             });
         }
 
@@ -589,6 +603,7 @@ mod tests {
                 total_completion_tokens: 0,
                 total_patch_rejections: HashMap::new(),
                 conversation_stats: None,
+                coupling: None,
             });
 
             results.add(ExperimentResult {
@@ -620,6 +635,7 @@ mod tests {
                 total_completion_tokens: 0,
                 total_patch_rejections: HashMap::new(),
                 conversation_stats: None,
+                coupling: None,
             });
         }
 
@@ -695,6 +711,8 @@ mod tests {
                 (PatchRejection::ParseFailure, 1),
             ]),
             messages_per_tick: None,
+            axis_pressures: HashMap::new(),
+            normalization: HashMap::new(),
         };
 
         // Verify serialization/deserialization preserves all fields
@@ -752,6 +770,7 @@ mod tests {
             total_completion_tokens: 256,
             total_patch_rejections: HashMap::from([(PatchRejection::DidNotReducePressure, 5)]),
             conversation_stats: None,
+            coupling: None,
         };
 
         let json = serde_json::to_string_pretty(&result).unwrap();
@@ -798,6 +817,7 @@ mod tests {
             total_completion_tokens: 0,
             total_patch_rejections: HashMap::new(),
             conversation_stats: None,
+            coupling: None,
         });
 
         results.compute_summary();
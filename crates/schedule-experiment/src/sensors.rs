@@ -327,6 +327,10 @@ impl Sensor for UnscheduledSensor {
 
         Ok(signals)
     }
+
+    fn is_global(&self) -> bool {
+        true
+    }
 }
 
 impl ScheduleSensor for UnscheduledSensor {
@@ -371,6 +375,11 @@ impl Sensor for CombinedScheduleSensor {
 
         Ok(signals)
     }
+
+    fn is_global(&self) -> bool {
+        // Includes the global unscheduled count
+        true
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,230 @@
+//! Tick-time benchmark for artifacts with tens of thousands of regions.
+//!
+//! Runs a synthetic artifact through the kernel twice, once with one message
+//! per region and once with batched messaging, and reports the mean tick time
+//! of each. Every region is re-measured every tick; about 1% of regions are
+//! above the activation threshold.
+//!
+//! ```text
+//! cargo run --release -p survival-kernel --example scale_benchmark -- [regions] [ticks]
+//! ```
+//!
+//! Actor spawn memory in the runtime grows faster than linearly (about 2 GB
+//! for 1,000 actors), so the default of 10,000 regions needs a large machine.
+//! Pass a smaller region count to try it locally.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use acton_reactive::prelude::*;
+use mti::prelude::*;
+use survival_kernel::config::{ActivationConfig, MeasurementConfig, MessagingConfig};
+use survival_kernel::{
+    Artifact, AsyncKernelBuilder, KernelConfig, Normalization, Patch, PatchOp, PressureAxisConfig,
+    RegionId, RegionView, Sensor, SensorsReady, Signals, Tick, TickComplete, TickResult,
+    WaitForSensors,
+};
+
+/// Artifact whose regions are independent lines of text.
+struct LinesArtifact {
+    ids: Vec<RegionId>,
+    lines: HashMap<RegionId, String>,
+}
+
+impl LinesArtifact {
+    fn new(regions: usize) -> Self {
+        let ids: Vec<RegionId> = (0..regions)
+            .map(|_| "line".create_type_id::<V7>())
+            .collect();
+        let lines = ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| {
+                let len = if i % 100 == 0 { 200 } else { 20 };
+                (id.clone(), "x".repeat(len))
+            })
+            .collect();
+        Self { ids, lines }
+    }
+}
+
+impl Artifact for LinesArtifact {
+    fn region_ids(&self) -> Vec<RegionId> {
+        self.ids.clone()
+    }
+
+    fn read_region(&self, id: RegionId) -> anyhow::Result<RegionView> {
+        let content = self
+            .lines
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Region not found: {}", id))?;
+        Ok(RegionView {
+            id,
+            kind: "line".to_string(),
+            content,
+            metadata: HashMap::new(),
+            parent: None,
+            children: Vec::new(),
+        })
+    }
+
+    fn apply_patch(&mut self, patch: Patch) -> anyhow::Result<()> {
+        if let PatchOp::Replace(content) = patch.op {
+            self.lines.insert(patch.region, content);
+        }
+        Ok(())
+    }
+}
+
+/// Pressure grows with line length.
+struct LengthSensor;
+
+impl Sensor for LengthSensor {
+    fn name(&self) -> &str {
+        "length"
+    }
+
+    fn measure(&self, region: &RegionView) -> anyhow::Result<Signals> {
+        Ok(HashMap::from([(
+            "length".to_string(),
+            region.content.len() as f64 / 100.0,
+        )]))
+    }
+
+    fn is_global(&self) -> bool {
+        // Defeat the content-hash cache so every tick runs the sensor
+        true
+    }
+}
+
+/// Forward every broadcast `M` to a channel.
+async fn forward<M>(runtime: &mut ActorRuntime, tx: tokio::sync::mpsc::Sender<M>)
+where
+    M: Clone + std::fmt::Debug + Send + Sync + 'static,
+{
+    #[derive(Debug)]
+    struct State<M> {
+        tx: Option<tokio::sync::mpsc::Sender<M>>,
+    }
+
+    impl<M> Default for State<M> {
+        fn default() -> Self {
+            Self { tx: None }
+        }
+    }
+
+    let mut actor = runtime.new_actor_with_name::<State<M>>("Forwarder".to_string());
+    actor.model.tx = Some(tx);
+    actor.handle().subscribe::<M>().await;
+    actor.act_on::<M>(|actor, context| {
+        let msg = context.message().clone();
+        let tx = actor.model.tx.clone();
+        Reply::pending(async move {
+            if let Some(tx) = tx {
+                let _ = tx.send(msg).await;
+            }
+        })
+    });
+    actor.start().await;
+}
+
+/// Run `ticks` ticks and return the mean tick time.
+async fn mean_tick_time(regions: usize, ticks: usize, messaging: MessagingConfig) -> Duration {
+    let config = KernelConfig {
+        tick_interval_ms: 0,
+        stable_threshold: 0,
+        pressure_axes: vec![PressureAxisConfig {
+            name: "length".to_string(),
+            weight: 1.0,
+            expr: "length".to_string(),
+            kind_weights: HashMap::new(),
+            hard: false,
+            priority: 0,
+            normalization: Normalization::None,
+        }],
+        activation: ActivationConfig {
+            min_total_pressure: 1.0,
+            inhibit_ms: 0,
+        },
+        measurement: MeasurementConfig {
+            full_remeasure_interval: 1,
+        },
+        messaging,
+        ..KernelConfig::default()
+    };
+
+    let mut runtime = ActonApp::launch_async().await;
+
+    let (sensors_tx, mut sensors_rx) = tokio::sync::mpsc::channel::<SensorsReady>(1);
+    let (tick_tx, mut tick_rx) = tokio::sync::mpsc::channel::<TickComplete>(16);
+    forward(&mut runtime, sensors_tx).await;
+    forward(&mut runtime, tick_tx).await;
+
+    let coordinator = AsyncKernelBuilder::new(config, Box::new(LinesArtifact::new(regions)))
+        .add_sensor(Box::new(LengthSensor))
+        .spawn(&mut runtime)
+        .await;
+    coordinator.send(WaitForSensors { expected_count: 1 }).await;
+    sensors_rx.recv().await;
+
+    let mut total = Duration::ZERO;
+    for _ in 0..ticks {
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+
+        let started = Instant::now();
+        coordinator.send(Tick { now_ms }).await;
+        let Some(TickComplete { result, .. }) = tick_rx.recv().await else {
+            break;
+        };
+        total += started.elapsed();
+
+        let TickResult {
+            evaluated,
+            total_pressure,
+            ..
+        } = result;
+        println!(
+            "  tick: {:>8.1} ms  evaluated={} pressure={:.1}",
+            started.elapsed().as_secs_f64() * 1000.0,
+            evaluated,
+            total_pressure
+        );
+    }
+
+    let _ = runtime.shutdown_all().await;
+    total / ticks.max(1) as u32
+}
+
+#[tokio::main]
+async fn main() {
+    let mut args = std::env::args().skip(1);
+    let regions: usize = args.next().and_then(|a| a.parse().ok()).unwrap_or(10_000);
+    let ticks: usize = args.next().and_then(|a| a.parse().ok()).unwrap_or(5);
+
+    println!("{regions} regions, {ticks} ticks");
+
+    println!("per-region messaging:");
+    let unbatched = mean_tick_time(regions, ticks, MessagingConfig::default()).await;
+
+    println!("batched messaging:");
+    let batched = mean_tick_time(
+        regions,
+        ticks,
+        MessagingConfig {
+            batch_size: 256,
+            coordinator_inbox_capacity: 4096,
+        },
+    )
+    .await;
+
+    println!(
+        "mean tick: per-region {:.1} ms, batched {:.1} ms ({:.1}x)",
+        unbatched.as_secs_f64() * 1000.0,
+        batched.as_secs_f64() * 1000.0,
+        unbatched.as_secs_f64() / batched.as_secs_f64()
+    );
+}
@@ -3,13 +3,43 @@
 //! Each region owns its state via a RegionActor, providing natural conflict
 //! resolution through mailbox serialization. The coordinator orchestrates:
 //! 1. Decay broadcast → RegionActors
-//! 2. Measurement → SensorActors → RegionActors
+//! 2. Measurement → SensorActors → RegionActors (every region, or only dirty
+//!    ones between full re-measures if `full_remeasure_interval` > 1)
 //! 3. Pressure query → RegionActors → Coordinator (finds high-pressure)
 //! 4. Proposals → PatchActors → Coordinator
 //! 5. Patch application → RegionActors (with validation)
 //! 6. TickComplete
+//!
+//! In async mode (`ExecutionMode::Async`) regions drive steps 1-5 themselves
+//! via `RequestProposal`; ticks only observe total pressure and report what
+//! was applied since the previous observation.
+//!
+//! In tick mode, regions whose pressure stays high (or neighbors whose
+//! pressure stays low) for long enough are split (or merged) by the artifact
+//! before step 1. The tick waits while the retired RegionActors hand back
+//! their state and actors for the new regions are spawned with it.
+//!
+//! Each pressure observation after a tick that applied patches samples how
+//! far the unpatched regions moved (see `crate::coupling`). With monitors
+//! enabled, accepted patches, total pressure and those samples are checked
+//! against the convergence assumptions (see `crate::monitor`).
+//!
+//! With pheromones enabled, every patch result leaves a deposit on its
+//! region, deposits evaporate each tick, and proposal requests carry the
+//! region's heaviest ones (see `crate::pheromone`).
+//!
+//! `SteerRegions` lets a human or supervisor freeze regions or bias the
+//! pressure they activate at (see `crate::region::Steering`). The coordinator
+//! tracks frozen regions too: stagnation kicks, migrants and patches to an
+//! enclosing or nested region leave them unchanged.
+//!
+//! `Pause`, `Resume` and `Step` gate ticks: a tick arriving while paused is
+//! held until released. `Shutdown` waits for the current tick, collects
+//! region states the same way, and stops the kernel's actors.
 
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use acton_reactive::prelude::*;
 use dashmap::DashMap;
@@ -19,18 +49,42 @@ use tracing::{debug, info, trace, warn};
 // RegionActor import used by AsyncKernelBuilder (spawns RegionActors externally)
 use std::collections::HashSet;
 
+use crate::actors::RegionSpawner;
 use crate::artifact::Artifact;
-use crate::config::KernelConfig;
-use crate::kernel::TickResult;
+use crate::config::{
+    AcceptanceMode, ExecutionMode, GranularityConfig, KernelConfig, PressureAxisConfig,
+    StagnationResponse,
+};
+use crate::constraint::{self, Rejection};
+use crate::coupling::{self, CouplingSample};
+use crate::kernel::{BestSoFar, TickResult};
 use crate::messages::{
-    ApplyDecay, ClaimManagerReady, CoordinatorReady, EvaluatePatch, EvaluatePatchResponse,
-    MeasureRegion, MeasurementResult, PatchActorReady, PatchActorsReady, PatchProposal,
-    PressureResponse, ProposeForRegion, QueryPressure, RegionApplyPatch, RegionPatchResult,
-    RegisterRegionActors, ResetClaims, SaveArtifact, SensorReady, SensorsReady, SetOutputDir, Tick,
-    TickComplete, ValidatePatch, ValidatePatchResponse, WaitForPatchActors, WaitForSensors,
+    ApplyDecay, BestSoFarReport, ClaimManagerReady, ConfigUpdated, CoordinatorReady,
+    DepartureReason, EvaluatePatch, EvaluatePatchResponse, ExportRegions, ImportRegions,
+    KernelComplete, KickRegion, MeasureRegion, MeasureRegions, MeasurementBatch, MeasurementFailed,
+    MeasurementResult, MigrantRegion, NoPatchProposed, PatchActorGone, PatchActorHeartbeat,
+    PatchActorReady, PatchActorsReady, PatchProposal, Pause, PressureResponse, PressureSummary,
+    ProposeForRegion, ProvenanceReport, QueryBestSoFar, QueryPressure, QueryProvenance,
+    RefreshContent, RegionApplyPatch, RegionExport, RegionPatchResult, RegionRetired, RegionWake,
+    RegionsImported, RegionsRestructured, RegisterRegionActors, RequestProposal, ResetClaims,
+    Resume, RetireRegion, SaveArtifact, SensorReady, SensorsReady, SetOutputDir, Shutdown,
+    SteerRegion, SteerRegions, SteeringUpdate, Step, StopReason, SweepPatchActors, Tick,
+    TickComplete, UpdateConfig, UpdatePressureAxes, ValidatePatch, ValidatePatchResponse,
+    WaitForPatchActors, WaitForSensors,
 };
+use crate::monitor::{self, AssumptionMonitor, AssumptionViolation};
+use crate::normalize::SignalNormalizer;
+use crate::pareto::{ParetoArchive, ParetoSnapshot};
+use crate::pheromone::{Pheromone, PheromoneKind, PheromoneStore};
 use crate::pressure::PressureVector;
-use crate::region::{Patch, RegionId, RegionView};
+use crate::region::{NeighborView, Patch, PatchOrigin, RegionId, RegionState, RegionView};
+use crate::reload::{ConfigChange, ConfigError, changed_sections, check_update};
+use crate::reputation::{ReputationTable, weight_of, weighted_assignment};
+use crate::stagnation::{Progress, StagnationKick, StagnationTracker, highest_pressure};
+use crate::transform::{PatchTransformer, run_chain};
+
+/// Future returned by message handlers (same shape as `Reply::pending`).
+type HandlerFuture = std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + Sync>>;
 
 /// Compute velocity (dP/dt) from pressure history.
 fn compute_velocity(current_pressure: f64, pressure_history: &[f64]) -> f64 {
@@ -51,10 +105,10 @@ fn compute_acceleration(current_velocity: f64, velocity_history: &[f64]) -> f64
 /// Tracks pending measurements for a tick.
 #[derive(Debug, Clone)]
 struct PendingMeasurements {
-    /// Expected number of responses (sensors × regions)
+    /// Expected number of results (sensors × regions)
     expected_count: usize,
-    /// Received measurement results
-    results: Vec<MeasurementResult>,
+    /// Number of (region, sensor) results received, single or batched
+    received: usize,
     /// Timestamp for this tick
     now_ms: u64,
 }
@@ -63,13 +117,13 @@ impl PendingMeasurements {
     fn new(expected_count: usize, now_ms: u64) -> Self {
         Self {
             expected_count,
-            results: Vec::new(),
+            received: 0,
             now_ms,
         }
     }
 
     fn is_complete(&self) -> bool {
-        self.results.len() >= self.expected_count
+        self.received >= self.expected_count
     }
 }
 
@@ -80,6 +134,12 @@ struct PendingPressureQueries {
     expected_count: usize,
     /// Received responses
     responses: Vec<PressureResponse>,
+    /// Received content-free summaries (batched messaging)
+    summaries: Vec<PressureSummary>,
+    /// Totals over all regions, when this query only fetches selected regions
+    totals: Option<PressureTotals>,
+    /// Effective (diffused) pressure per region, when computed from summaries
+    effective: Option<HashMap<RegionId, f64>>,
     /// Timestamp for this tick
     now_ms: u64,
 }
@@ -89,28 +149,62 @@ impl PendingPressureQueries {
         Self {
             expected_count,
             responses: Vec::new(),
+            summaries: Vec::new(),
+            totals: None,
+            effective: None,
             now_ms,
         }
     }
 
     fn is_complete(&self) -> bool {
-        self.responses.len() >= self.expected_count
+        self.responses.len() + self.summaries.len() >= self.expected_count
     }
 }
 
+/// Pressure totals over every region for one query.
+///
+/// Only outermost measured regions are summed (see `outermost_measured`), so
+/// nested regions are not counted twice.
+#[derive(Debug, Clone)]
+struct PressureTotals {
+    /// Sum of region pressures
+    total_pressure: f64,
+    /// Sum of region pressures per axis
+    axis_pressures: PressureVector,
+    /// Regions that answered
+    evaluated: usize,
+    /// Regions that were inhibited
+    skipped: usize,
+}
+
+/// A proposal request that has been dispatched but not yet answered.
+#[derive(Debug, Clone)]
+struct OutstandingProposal {
+    /// ERN of the patch actor the request was sent to
+    actor_ern: String,
+    /// When the request was sent (for proposal timeouts)
+    dispatched_at: Instant,
+    /// The request itself, kept so it can be re-dispatched
+    request: ProposeForRegion,
+}
+
 /// Tracks pending proposals for a tick.
 #[derive(Debug, Clone)]
 struct PendingProposals {
-    /// Expected number of responses (actors × high-pressure regions)
+    /// Expected number of responses (one per high-pressure region)
     expected_count: usize,
     /// Received proposals
     proposals: Vec<PatchProposal>,
+    /// Dispatched requests still awaiting an answer, by region
+    outstanding: HashMap<RegionId, OutstandingProposal>,
     /// Regions that were proposed for
     high_pressure_regions: Vec<(RegionId, RegionView, PressureVector)>,
     /// Timestamp for this tick
     now_ms: u64,
     /// Total pressure at time of proposal (for calculating final pressure after patches)
     total_pressure: f64,
+    /// Whether this answers a region's own `RequestProposal` (async mode)
+    autonomous: bool,
 }
 
 impl PendingProposals {
@@ -123,15 +217,36 @@ impl PendingProposals {
         Self {
             expected_count,
             proposals: Vec::new(),
+            outstanding: HashMap::new(),
             high_pressure_regions,
             now_ms,
             total_pressure,
+            autonomous: false,
         }
     }
 
+    /// Complete once every dispatched region has been answered (or abandoned).
+    ///
+    /// Counting outstanding regions rather than responses keeps late replies
+    /// to re-dispatched requests from completing the phase early.
     fn is_complete(&self) -> bool {
-        self.proposals.len() >= self.expected_count
+        self.outstanding.is_empty()
+    }
+}
+
+/// Take the request `proposal` answers off `pending`'s outstanding ones.
+///
+/// None if the region is not outstanding, or is outstanding with another
+/// actor: a late reply to a request that timed out and was re-dispatched.
+fn take_outstanding(
+    pending: &mut PendingProposals,
+    proposal: &PatchProposal,
+) -> Option<OutstandingProposal> {
+    let outstanding = pending.outstanding.get(&proposal.region_id)?;
+    if outstanding.actor_ern != proposal.actor_ern {
+        return None;
     }
+    pending.outstanding.remove(&proposal.region_id)
 }
 
 /// Tracks pending patch applications.
@@ -151,6 +266,8 @@ struct PendingPatches {
     prompt_tokens: u32,
     /// Total completion tokens from LLM actors this tick
     completion_tokens: u32,
+    /// Time of the tick the patches were sent in
+    now_ms: u64,
 }
 
 impl PendingPatches {
@@ -159,6 +276,67 @@ impl PendingPatches {
     }
 }
 
+/// Consecutive ticks a region's pressure has stayed high or low.
+#[derive(Debug, Clone, Copy, Default)]
+struct PressureStreak {
+    /// Ticks at or above `GranularityConfig::split_pressure`
+    high: usize,
+    /// Ticks at or below `GranularityConfig::merge_pressure`
+    low: usize,
+}
+
+/// A change to region boundaries.
+#[derive(Debug, Clone, PartialEq)]
+enum Restructure {
+    /// Split one region into several
+    Split(RegionId),
+    /// Merge neighboring regions into one
+    Merge(Vec<RegionId>),
+}
+
+/// A split or merge waiting for retired regions to hand back their state.
+#[derive(Debug, Clone)]
+struct PendingRestructure {
+    /// Correlation ID sent with `RetireRegion`
+    correlation_id: String,
+    /// Regions replaced by the split or merge
+    retired: Vec<RegionId>,
+    /// Retired regions that have not answered yet
+    awaiting: HashSet<RegionId>,
+    /// States handed back so far
+    states: HashMap<RegionId, RegionState>,
+    /// New regions and the retired regions each one replaces
+    created: Vec<(RegionId, Vec<RegionId>)>,
+    /// Tick to resume once the new actors are registered
+    tick: Tick,
+}
+
+/// A shutdown waiting for every region to hand back its state.
+#[derive(Debug, Clone)]
+struct PendingShutdown {
+    /// Correlation ID sent with `RetireRegion`
+    correlation_id: String,
+    /// Directory to persist the artifact and region states to
+    save_to: Option<std::path::PathBuf>,
+    /// Regions that have not answered yet
+    awaiting: HashSet<RegionId>,
+    /// States handed back so far
+    states: HashMap<RegionId, RegionState>,
+}
+
+/// Work done by autonomous regions since the last observation (async mode).
+#[derive(Debug, Clone, Default)]
+struct AsyncWindow {
+    /// Patches applied to the artifact
+    applied: Vec<Patch>,
+    /// Patch results that were rejected
+    rejected: usize,
+    /// Total prompt tokens from LLM actors
+    prompt_tokens: u32,
+    /// Total completion tokens from LLM actors
+    completion_tokens: u32,
+}
+
 /// Actor state for KernelCoordinator.
 pub struct KernelCoordinatorState {
     /// Kernel configuration
@@ -167,12 +345,31 @@ pub struct KernelCoordinatorState {
     artifact: Option<Box<dyn Artifact>>,
     /// Handles to RegionActors (one per region)
     region_actors: DashMap<RegionId, ActorHandle>,
-    /// Registered sensor IDs (sensors self-register via SensorReady broadcast)
-    registered_sensors: HashSet<String>,
+    /// Registered sensor handles by ERN (sensors self-register via SensorReady broadcast)
+    registered_sensors: HashMap<String, ActorHandle>,
     /// Registered patch actor IDs (patch actors self-register via PatchActorReady broadcast)
     registered_patch_actors: HashSet<String>,
-    /// Patch actor handles for round-robin dispatch (ordered for deterministic assignment)
-    patch_actor_handles: Vec<ActorHandle>,
+    /// Patch actor handles for round-robin dispatch, keyed by ERN
+    /// (ordered for deterministic assignment)
+    patch_actor_handles: Vec<(String, ActorHandle)>,
+    /// Requests dispatched to the least-loaded actor so far (rotates ties)
+    dispatch_turn: usize,
+    /// Last time each patch actor was heard from (registration, heartbeat, proposal)
+    patch_actor_last_seen: HashMap<String, Instant>,
+    /// Patch actor names by ERN, learned from their proposals
+    patch_actor_names: HashMap<String, String>,
+    /// Track record of each patch actor, by actor name
+    reputation: ReputationTable,
+    /// Whether a SweepPatchActors message is already scheduled
+    sweep_scheduled: bool,
+    /// Whether RegionWake has been broadcast (async mode)
+    regions_awake: bool,
+    /// Work done since the last observation (async mode)
+    async_window: AsyncWindow,
+    /// Regions patched (or coupled to a patch) since they were last measured
+    dirty_regions: HashSet<RegionId>,
+    /// Regions with a patch sent but not yet resolved (async mode)
+    patches_in_flight: HashSet<RegionId>,
     /// Pending measurement requests by correlation ID
     pending_measurements: DashMap<String, PendingMeasurements>,
     /// Pending pressure queries by correlation ID
@@ -197,6 +394,67 @@ pub struct KernelCoordinatorState {
     pending_sensor_waits: Vec<(usize, tokio::sync::oneshot::Sender<usize>)>,
     /// Handle to ClaimManager for stigmergic column/value claims
     claim_manager: Option<ActorHandle>,
+    /// Spawns RegionActors for split and merged regions
+    region_spawner: Option<RegionSpawner>,
+    /// High/low pressure streak per region (granularity rules)
+    pressure_streaks: HashMap<RegionId, PressureStreak>,
+    /// Split or merge in progress; ticks wait for it
+    restructure: Option<PendingRestructure>,
+    /// Tick number that last restructured (at most once per tick)
+    restructured_tick: usize,
+    /// Chain every proposed patch runs through before selection
+    transformers: Vec<Arc<dyn PatchTransformer>>,
+    /// Per-axis pressure totals from the latest pressure query
+    axis_pressures: PressureVector,
+    /// Non-dominated states seen so far (Pareto acceptance mode)
+    pareto_archive: ParetoArchive,
+    /// Snapshot admitted to the archive this tick, reported with its result
+    pareto_snapshot: Option<ParetoSnapshot>,
+    /// Signal normalizer shared with the RegionActors (for reporting)
+    normalizer: Arc<SignalNormalizer>,
+    /// Latest total pressure of each region (picks regions to perturb)
+    region_pressures: HashMap<RegionId, f64>,
+    /// Best pressure so far and ticks since it improved
+    stagnation: StagnationTracker,
+    /// Lowest total pressure reached so far, with its tick and source
+    best_so_far: Option<BestSoFar>,
+    /// Snapshot of the artifact at `best_so_far` (if the artifact supports it)
+    best_artifact: Option<Box<dyn Artifact>>,
+    /// Seeds for the next proposal of kicked regions
+    explore_seeds: HashMap<RegionId, u64>,
+    /// Stagnation kicks made this tick, reported with its result
+    kicks: Vec<StagnationKick>,
+    /// Regions frozen through `SteerRegions`: never kicked, migrated into or
+    /// changed by a patch to an enclosing or nested region
+    frozen_regions: HashSet<RegionId>,
+    /// Ticks left before pausing (`None` runs freely, `Some(0)` is paused)
+    steps_remaining: Option<usize>,
+    /// Tick held back while paused
+    parked_tick: Option<Tick>,
+    /// Whether a tick has started and not yet completed
+    tick_in_flight: bool,
+    /// Shutdown waiting for the current tick to complete
+    shutdown_requested: Option<Shutdown>,
+    /// Shutdown collecting region states
+    shutdown: Option<PendingShutdown>,
+    /// Whether shutdown has begun (later ticks are ignored)
+    stopped: bool,
+    /// Config updates to report in the next TickResult
+    config_changes: Vec<ConfigChange>,
+    /// Tick and regions patched since the last pressure observation (tick mode)
+    coupling_sources: Option<(usize, Vec<RegionId>)>,
+    /// Coupling samples to report in the next TickResult
+    coupling_samples: Vec<CouplingSample>,
+    /// Last patch time of each region (inhibition monitor)
+    monitor: AssumptionMonitor,
+    /// Assumption violations to report in the next TickResult
+    violations: Vec<AssumptionViolation>,
+    /// Deposits left by patch results
+    pheromones: PheromoneStore,
+    /// Accepted deposits shown with each region's latest proposal request
+    pheromones_offered: HashMap<RegionId, Vec<u64>>,
+    /// Axes already warned about as missing from the artifact's signal deltas
+    unreported_axes: HashSet<String>,
 }
 
 impl Default for KernelCoordinatorState {
@@ -205,9 +463,18 @@ impl Default for KernelCoordinatorState {
             config: None,
             artifact: None,
             region_actors: DashMap::new(),
-            registered_sensors: HashSet::new(),
+            registered_sensors: HashMap::new(),
             registered_patch_actors: HashSet::new(),
             patch_actor_handles: Vec::new(),
+            dispatch_turn: 0,
+            patch_actor_last_seen: HashMap::new(),
+            patch_actor_names: HashMap::new(),
+            reputation: ReputationTable::new(),
+            sweep_scheduled: false,
+            regions_awake: false,
+            async_window: AsyncWindow::default(),
+            dirty_regions: HashSet::new(),
+            patches_in_flight: HashSet::new(),
             pending_measurements: DashMap::new(),
             pending_pressure_queries: DashMap::new(),
             pending_proposals: DashMap::new(),
@@ -220,6 +487,36 @@ impl Default for KernelCoordinatorState {
             pending_actor_waits: Vec::new(),
             pending_sensor_waits: Vec::new(),
             claim_manager: None,
+            region_spawner: None,
+            pressure_streaks: HashMap::new(),
+            restructure: None,
+            restructured_tick: 0,
+            transformers: Vec::new(),
+            axis_pressures: PressureVector::new(),
+            pareto_archive: ParetoArchive::new(),
+            pareto_snapshot: None,
+            normalizer: Arc::default(),
+            region_pressures: HashMap::new(),
+            stagnation: StagnationTracker::new(),
+            best_so_far: None,
+            best_artifact: None,
+            explore_seeds: HashMap::new(),
+            kicks: Vec::new(),
+            frozen_regions: HashSet::new(),
+            steps_remaining: None,
+            parked_tick: None,
+            tick_in_flight: false,
+            shutdown_requested: None,
+            shutdown: None,
+            stopped: false,
+            config_changes: Vec::new(),
+            coupling_sources: None,
+            coupling_samples: Vec::new(),
+            monitor: AssumptionMonitor::new(),
+            violations: Vec::new(),
+            pheromones: PheromoneStore::new(),
+            pheromones_offered: HashMap::new(),
+            unreported_axes: HashSet::new(),
         }
     }
 }
@@ -259,6 +556,15 @@ impl Clone for KernelCoordinatorState {
             registered_sensors: self.registered_sensors.clone(),
             registered_patch_actors: self.registered_patch_actors.clone(),
             patch_actor_handles: self.patch_actor_handles.clone(),
+            dispatch_turn: self.dispatch_turn,
+            patch_actor_last_seen: self.patch_actor_last_seen.clone(),
+            patch_actor_names: self.patch_actor_names.clone(),
+            reputation: self.reputation.clone(),
+            sweep_scheduled: self.sweep_scheduled,
+            regions_awake: self.regions_awake,
+            async_window: self.async_window.clone(),
+            dirty_regions: self.dirty_regions.clone(),
+            patches_in_flight: self.patches_in_flight.clone(),
             pending_measurements,
             pending_pressure_queries,
             pending_proposals,
@@ -271,6 +577,36 @@ impl Clone for KernelCoordinatorState {
             pending_actor_waits: Vec::new(), // Can't clone oneshot::Sender
             pending_sensor_waits: Vec::new(), // Can't clone oneshot::Sender
             claim_manager: self.claim_manager.clone(),
+            region_spawner: self.region_spawner.clone(),
+            pressure_streaks: self.pressure_streaks.clone(),
+            restructure: self.restructure.clone(),
+            restructured_tick: self.restructured_tick,
+            transformers: self.transformers.clone(),
+            axis_pressures: self.axis_pressures.clone(),
+            pareto_archive: self.pareto_archive.clone(),
+            pareto_snapshot: self.pareto_snapshot.clone(),
+            normalizer: self.normalizer.clone(),
+            region_pressures: self.region_pressures.clone(),
+            stagnation: self.stagnation.clone(),
+            best_so_far: self.best_so_far.clone(),
+            best_artifact: None, // Can't clone trait object
+            explore_seeds: self.explore_seeds.clone(),
+            kicks: self.kicks.clone(),
+            frozen_regions: self.frozen_regions.clone(),
+            steps_remaining: self.steps_remaining,
+            parked_tick: self.parked_tick.clone(),
+            tick_in_flight: self.tick_in_flight,
+            shutdown_requested: self.shutdown_requested.clone(),
+            shutdown: self.shutdown.clone(),
+            stopped: self.stopped,
+            config_changes: self.config_changes.clone(),
+            coupling_sources: self.coupling_sources.clone(),
+            coupling_samples: self.coupling_samples.clone(),
+            monitor: self.monitor.clone(),
+            violations: self.violations.clone(),
+            pheromones: self.pheromones.clone(),
+            pheromones_offered: self.pheromones_offered.clone(),
+            unreported_axes: self.unreported_axes.clone(),
         }
     }
 }
@@ -308,6 +644,9 @@ impl std::fmt::Debug for KernelCoordinatorState {
 /// 5. On PatchProposal: send RegionApplyPatch to target RegionActors
 /// 6. On RegionPatchResult: update artifact, send TickComplete
 ///
+/// `QueryProvenance` is forwarded to the region's actor, which answers with a
+/// `ProvenanceReport` broadcast.
+///
 /// Sensors and patch actors register themselves by broadcasting `SensorReady`
 /// and `PatchActorReady` on start. The coordinator subscribes to these messages.
 pub struct KernelCoordinator {
@@ -315,12 +654,22 @@ pub struct KernelCoordinator {
     pub config: KernelConfig,
     /// The artifact being coordinated
     pub artifact: Box<dyn Artifact>,
+    /// Patch transformers, run in order on every proposed patch
+    pub transformers: Vec<Arc<dyn PatchTransformer>>,
+    /// Signal normalizer the RegionActors share; its statistics are reported
+    /// in every `TickResult`
+    pub normalizer: Arc<SignalNormalizer>,
 }
 
 impl KernelCoordinator {
     /// Create a new KernelCoordinator.
     pub fn new(config: KernelConfig, artifact: Box<dyn Artifact>) -> Self {
-        Self { config, artifact }
+        Self {
+            normalizer: Arc::new(SignalNormalizer::new(&config.pressure_axes)),
+            config,
+            artifact,
+            transformers: Vec::new(),
+        }
     }
 
     /// Spawn this coordinator.
@@ -328,20 +677,45 @@ impl KernelCoordinator {
     /// Sensors and patch actors should be spawned separately.
     /// They will self-register via broker broadcasts.
     pub async fn spawn(self, runtime: &mut ActorRuntime) -> ActorHandle {
-        let mut actor =
-            runtime.new_actor_with_name::<KernelCoordinatorState>("KernelCoordinator".to_string());
+        let mut actor = match self.config.messaging.coordinator_inbox_capacity {
+            0 => runtime
+                .new_actor_with_name::<KernelCoordinatorState>("KernelCoordinator".to_string()),
+            capacity => {
+                let config = ActorConfig::new(
+                    Ern::with_root("KernelCoordinator").expect("valid actor name"),
+                    None,
+                    None,
+                )
+                .expect("valid actor config")
+                .with_inbox_capacity(capacity);
+                runtime.new_actor_with_config::<KernelCoordinatorState>(config)
+            }
+        };
 
         // Set initial state
         actor.model.config = Some(self.config.clone());
         actor.model.artifact = Some(self.artifact);
+        actor.model.transformers = self.transformers;
+        actor.model.normalizer = self.normalizer;
 
         // Subscribe to actor registration and response broadcasts BEFORE starting
         actor.handle().subscribe::<SensorReady>().await;
         actor.handle().subscribe::<MeasurementResult>().await;
+        actor.handle().subscribe::<MeasurementBatch>().await;
+        actor.handle().subscribe::<MeasurementFailed>().await;
         actor.handle().subscribe::<PatchActorReady>().await;
+        actor.handle().subscribe::<PatchActorGone>().await;
+        actor.handle().subscribe::<PatchActorHeartbeat>().await;
         actor.handle().subscribe::<PatchProposal>().await;
         actor.handle().subscribe::<PressureResponse>().await;
+        actor.handle().subscribe::<PressureSummary>().await;
         actor.handle().subscribe::<ClaimManagerReady>().await;
+        actor.handle().subscribe::<Pause>().await;
+        actor.handle().subscribe::<Resume>().await;
+        actor.handle().subscribe::<Step>().await;
+        actor.handle().subscribe::<Shutdown>().await;
+        actor.handle().subscribe::<UpdateConfig>().await;
+        actor.handle().subscribe::<SteerRegions>().await;
 
         // Configure handlers before starting
         configure_handlers(&mut actor);
@@ -365,7 +739,10 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
         // Get ERN from message payload (broker broadcasts don't preserve sender in envelope)
         let sender_ern = &context.message().sensor_ern;
 
-        actor.model.registered_sensors.insert(sender_ern.clone());
+        actor
+            .model
+            .registered_sensors
+            .insert(sender_ern.clone(), context.message().handle.clone());
 
         let current_count = actor.model.registered_sensors.len();
         debug!(
@@ -406,8 +783,15 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
             .insert(sender_ern.clone())
         {
             // Store handle for round-robin dispatch
-            actor.model.patch_actor_handles.push(msg.handle.clone());
+            actor
+                .model
+                .patch_actor_handles
+                .push((sender_ern.clone(), msg.handle.clone()));
         }
+        actor
+            .model
+            .patch_actor_last_seen
+            .insert(sender_ern.clone(), Instant::now());
 
         let current_count = actor.model.registered_patch_actors.len();
         debug!(
@@ -439,6 +823,110 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
         Reply::ready()
     });
 
+    // Handle patch actor departure - stop dispatching to it and rebalance its work
+    actor.mutate_on::<PatchActorGone>(|actor, context| {
+        let msg = context.message().clone();
+
+        // Already removed (e.g. the echo of our own eviction broadcast)
+        if deregister_patch_actor(&mut actor.model, &msg.actor_ern).is_none() {
+            return Reply::ready();
+        }
+
+        info!(
+            patch_actor_ern = %msg.actor_ern,
+            reason = ?msg.reason,
+            remaining = actor.model.patch_actor_handles.len(),
+            "Patch actor deregistered"
+        );
+
+        let (sends, completed) =
+            reassign_outstanding(&mut actor.model, |o| o.actor_ern == msg.actor_ern);
+        finish_completed_proposals(actor, sends, completed)
+    });
+
+    // Handle patch actor heartbeat - refresh liveness
+    actor.mutate_on::<PatchActorHeartbeat>(|actor, context| {
+        let actor_ern = &context.message().actor_ern;
+        match actor.model.patch_actor_last_seen.get_mut(actor_ern) {
+            Some(seen) => *seen = Instant::now(),
+            None => trace!(patch_actor_ern = %actor_ern, "Heartbeat from unregistered patch actor"),
+        }
+        Reply::ready()
+    });
+
+    // Handle SweepPatchActors - evict silent actors and re-dispatch overdue proposals
+    actor.mutate_on::<SweepPatchActors>(|actor, _context| {
+        actor.model.sweep_scheduled = false;
+
+        let Some(membership) = actor.model.config.as_ref().map(|c| c.membership.clone()) else {
+            return Reply::ready();
+        };
+
+        let now = Instant::now();
+        let mut sends = Vec::new();
+        let mut completed = Vec::new();
+        let mut evicted = Vec::new();
+
+        if membership.heartbeat_timeout_ms > 0 {
+            let timeout = Duration::from_millis(membership.heartbeat_timeout_ms);
+            let silent: Vec<String> = actor
+                .model
+                .patch_actor_last_seen
+                .iter()
+                .filter(|(_, seen)| now.duration_since(**seen) > timeout)
+                .map(|(ern, _)| ern.clone())
+                .collect();
+
+            for actor_ern in silent {
+                let Some(handle) = deregister_patch_actor(&mut actor.model, &actor_ern) else {
+                    continue;
+                };
+                warn!(
+                    patch_actor_ern = %actor_ern,
+                    timeout_ms = membership.heartbeat_timeout_ms,
+                    "Patch actor missed heartbeat deadline, evicting"
+                );
+                let (s, c) = reassign_outstanding(&mut actor.model, |o| o.actor_ern == actor_ern);
+                sends.extend(s);
+                completed.extend(c);
+                evicted.push((actor_ern, handle));
+            }
+        }
+
+        if membership.proposal_timeout_ms > 0 {
+            let timeout = Duration::from_millis(membership.proposal_timeout_ms);
+            let (s, c) = reassign_outstanding(&mut actor.model, |o| {
+                now.duration_since(o.dispatched_at) > timeout
+            });
+            sends.extend(s);
+            completed.extend(c);
+        }
+
+        // Keep sweeping while any proposal phase is still waiting
+        if actor.model.pending_proposals.len() > completed.len() {
+            schedule_sweep(actor);
+        }
+
+        let broker = actor.broker().clone();
+        let finish = finish_completed_proposals(actor, sends, completed);
+        Reply::pending(async move {
+            for (actor_ern, handle) in evicted {
+                // Let a supervisor restart it, and stop the old instance so a
+                // late reply cannot race its replacement
+                broker
+                    .broadcast(PatchActorGone {
+                        actor_ern,
+                        reason: DepartureReason::TimedOut,
+                    })
+                    .await;
+                tokio::spawn(async move {
+                    let _ = handle.stop().await;
+                });
+            }
+            finish.await;
+        })
+    });
+
     // Handle RegionActor registration
     actor.mutate_on::<RegisterRegionActors>(|actor, context| {
         let msg = context.message();
@@ -449,6 +937,9 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
                 .region_actors
                 .insert(region_id.clone(), handle.clone());
         }
+        if let Some(spawner) = &msg.spawner {
+            actor.model.region_spawner = Some(spawner.clone());
+        }
         trace!(
             regions = actor.model.region_actors.len(),
             "Registered region actors"
@@ -456,6 +947,73 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
         Reply::ready()
     });
 
+    // Handle RegionRetired - collect state; spawn replacements once all are in
+    actor.mutate_on::<RegionRetired>(|actor, context| {
+        let msg = context.message().clone();
+        if let Some(shutdown) = actor.model.shutdown.as_mut()
+            && shutdown.correlation_id == msg.correlation_id
+        {
+            if !shutdown.awaiting.remove(&msg.region_id) {
+                return Reply::ready();
+            }
+            shutdown.states.insert(msg.region_id, msg.state);
+            if !shutdown.awaiting.is_empty() {
+                return Reply::ready();
+            }
+            return Reply::pending(finish_shutdown(actor));
+        }
+        let Some(restructure) = actor.model.restructure.as_mut() else {
+            warn!(region = %msg.region_id, "Received retired region with no restructure pending");
+            return Reply::ready();
+        };
+        if restructure.correlation_id != msg.correlation_id
+            || !restructure.awaiting.remove(&msg.region_id)
+        {
+            return Reply::ready();
+        }
+        restructure.states.insert(msg.region_id, msg.state);
+        if !restructure.awaiting.is_empty() {
+            return Reply::ready();
+        }
+        finish_restructure(actor)
+    });
+
+    // Handle RegionsRestructured - swap in the new actors and resume the tick
+    actor.mutate_on::<RegionsRestructured>(|actor, context| {
+        let msg = context.message().clone();
+        let Some(restructure) = actor.model.restructure.take() else {
+            return Reply::ready();
+        };
+
+        for region_id in &msg.retired {
+            actor.model.region_actors.remove(region_id);
+            actor.model.dirty_regions.remove(region_id);
+        }
+        for (region_id, handle) in msg.actors {
+            // New regions and their neighbors need measuring
+            if let Some(artifact) = actor.model.artifact.as_ref() {
+                actor
+                    .model
+                    .dirty_regions
+                    .extend(artifact.neighbors(&region_id).into_iter().map(|(id, _)| id));
+            }
+            actor.model.dirty_regions.insert(region_id.clone());
+            actor.model.region_actors.insert(region_id, handle);
+        }
+
+        info!(
+            retired = msg.retired.len(),
+            created = restructure.created.len(),
+            regions = actor.model.region_actors.len(),
+            "Regions restructured"
+        );
+
+        let handle = actor.handle().clone();
+        Reply::pending(async move {
+            handle.send(restructure.tick).await;
+        })
+    });
+
     // Handle ClaimManager registration
     actor.mutate_on::<ClaimManagerReady>(|actor, context| {
         let handle = context.message().handle.clone();
@@ -494,15 +1052,17 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
                 "Waiting for patch actors to register"
             );
 
+            // Wait off the mailbox: registrations must still be handled meanwhile
             let broker = actor.broker().clone();
-            Reply::pending(async move {
+            tokio::spawn(async move {
                 // Wait for the oneshot to be triggered when enough actors register
                 if let Ok(registered_count) = rx.await {
                     broker
                         .broadcast(PatchActorsReady { registered_count })
                         .await;
                 }
-            })
+            });
+            Reply::ready()
         }
     });
 
@@ -536,13 +1096,15 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
                 "Waiting for sensors to register"
             );
 
+            // Wait off the mailbox: registrations must still be handled meanwhile
             let broker = actor.broker().clone();
-            Reply::pending(async move {
+            tokio::spawn(async move {
                 // Wait for the oneshot to be triggered when enough sensors register
                 if let Ok(registered_count) = rx.await {
                     broker.broadcast(SensorsReady { registered_count }).await;
                 }
-            })
+            });
+            Reply::ready()
         }
     });
 
@@ -550,9 +1112,38 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
     actor.mutate_on::<Tick>(|actor, context| {
         let now_ms = context.message().now_ms;
 
+        if actor.model.stopped {
+            return Reply::ready();
+        }
+        // A tick resumed after restructuring can meet a deferred shutdown
+        if actor.model.shutdown_requested.is_some() {
+            return Reply::pending(begin_shutdown(actor));
+        }
+        if actor.model.steps_remaining == Some(0) {
+            actor.model.parked_tick = Some(context.message().clone());
+            return Reply::ready();
+        }
+
+        // Split or merge regions first; the tick resumes once new actors are registered
+        if let Some(restructure) = actor.model.restructure.as_mut() {
+            restructure.tick = context.message().clone();
+            return Reply::ready();
+        }
+        if let Some(retire) = begin_restructure(actor, context.message().clone()) {
+            return retire;
+        }
+
         // Increment tick counter
         actor.model.current_tick += 1;
+        actor.model.tick_in_flight = true;
+        if let Some(steps) = actor.model.steps_remaining.as_mut() {
+            *steps -= 1;
+        }
         let tick_num = actor.model.current_tick;
+        evaporate_pheromones(&mut actor.model);
+
+        // Escape a pressure plateau before this tick measures anything
+        let kick = kick_if_stagnant(&mut actor.model, now_ms);
 
         let Some(config) = actor.model.config.as_ref() else {
             warn!("KernelCoordinator: config not initialized");
@@ -570,20 +1161,53 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
             "Tick started"
         );
 
+        // Async mode: regions act on their own; a tick only observes pressure
+        if config.mode == ExecutionMode::Async {
+            let wake = (!actor.model.regions_awake).then_some(RegionWake {
+                now_ms,
+                decay: config.decay.clone(),
+                min_total_pressure: config.activation.min_total_pressure,
+                interval_ms: config.tick_interval_ms,
+            });
+            actor.model.regions_awake = true;
+
+            let query = begin_pressure_query(&mut actor.model, now_ms);
+
+            let broker = actor.broker().clone();
+            return Reply::pending(async move {
+                if let Some(kick) = kick {
+                    kick.await;
+                }
+                if let Some(wake) = wake {
+                    broker.broadcast(wake).await;
+                }
+                broker.broadcast(ResetClaims).await;
+                broker.broadcast(query).await;
+            });
+        }
+
         // Phase 1: Broadcast ApplyDecay to all RegionActors
         let decay_msg = ApplyDecay {
             now_ms,
-            fitness_half_life_ms: config.decay.fitness_half_life_ms,
-            confidence_half_life_ms: config.decay.confidence_half_life_ms,
+            tick: tick_num,
+            decay: config.decay.clone(),
         };
 
         // Generate correlation ID for measurement phase
         let correlation_id = "tick".create_type_id::<V7>().to_string();
 
+        // Periodically re-measure everything so global signals stay fresh;
+        // otherwise only dirty regions are measured and the rest keep their signals
+        let interval = config.measurement.full_remeasure_interval;
+        let batch_size = config.messaging.batch_size;
+        let full = tick_num == 1 || (interval > 0 && (tick_num - 1) % interval == 0);
+        let dirty = std::mem::take(&mut actor.model.dirty_regions);
+
         // Collect regions for broadcast
         let region_data: Vec<_> = artifact
             .region_ids()
             .iter()
+            .filter(|rid| full || dirty.contains(*rid))
             .filter_map(|rid| {
                 artifact
                     .read_region(rid.clone())
@@ -592,28 +1216,56 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
             })
             .collect();
 
+        // Neighbor views for neighbor-aware sensors
+        let mut neighbors: HashMap<RegionId, Vec<NeighborView>> = HashMap::new();
+        for (rid, _) in &region_data {
+            let views: Vec<NeighborView> = artifact
+                .neighbors(rid)
+                .into_iter()
+                .filter_map(|(nid, weight)| {
+                    let view = artifact.read_region(nid).ok()?;
+                    Some(NeighborView { view, weight })
+                })
+                .collect();
+            if !views.is_empty() {
+                neighbors.insert(rid.clone(), views);
+            }
+        }
+
         let sensor_count = actor.model.registered_sensors.len();
         let expected_count = sensor_count * region_data.len();
 
-        // Track pending measurements
-        actor.model.pending_measurements.insert(
-            correlation_id.clone(),
-            PendingMeasurements::new(expected_count, now_ms),
-        );
-
         trace!(
             correlation_id = %correlation_id,
             regions = region_data.len(),
+            full,
             sensors = sensor_count,
             expected = expected_count,
             "Starting tick: decay + measurement phase"
         );
 
+        // Nothing to measure: go straight to the pressure query
+        let query = if expected_count == 0 {
+            Some(begin_pressure_query(&mut actor.model, now_ms))
+        } else {
+            // Track pending measurements
+            actor.model.pending_measurements.insert(
+                correlation_id.clone(),
+                PendingMeasurements::new(expected_count, now_ms),
+            );
+            None
+        };
+
         // Get broker for broadcasting
         let broker = actor.broker().clone();
 
         // Broadcast decay and measurements via broker
         Reply::pending(async move {
+            // Refresh and kick regions before they are measured or decayed
+            if let Some(kick) = kick {
+                kick.await;
+            }
+
             // Phase 0: Reset claims for stigmergic coordination
             // Must happen before proposals so all agents start with a clean slate
             broker.broadcast(ResetClaims).await;
@@ -624,15 +1276,38 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
 
             // Broadcast MeasureRegion to all sensors via broker
             // Sensors subscribe to MeasureRegion and respond with MeasurementResult
-            for (rid, region_view) in region_data {
-                let msg = MeasureRegion {
-                    correlation_id: correlation_id.clone(),
-                    region_id: rid,
-                    region_view,
-                    now_ms,
-                };
+            if batch_size > 0 {
+                let mut regions = region_data.into_iter().peekable();
+                while regions.peek().is_some() {
+                    let batch: Vec<_> = regions.by_ref().take(batch_size).collect();
+                    let batch_neighbors = batch
+                        .iter()
+                        .filter_map(|(rid, _)| Some((rid.clone(), neighbors.get(rid)?.clone())))
+                        .collect();
+                    let msg = MeasureRegions {
+                        correlation_id: correlation_id.clone(),
+                        regions: batch,
+                        neighbors: batch_neighbors,
+                        now_ms,
+                    };
+                    broker.broadcast(msg).await;
+                }
+            } else {
+                for (rid, region_view) in region_data {
+                    let msg = MeasureRegion {
+                        correlation_id: correlation_id.clone(),
+                        neighbors: neighbors.remove(&rid).unwrap_or_default(),
+                        region_id: rid,
+                        region_view,
+                        now_ms,
+                    };
+
+                    broker.broadcast(msg).await;
+                }
+            }
 
-                broker.broadcast(msg).await;
+            if let Some(query) = query {
+                broker.broadcast(query).await;
             }
         })
     });
@@ -651,62 +1326,78 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
             return Reply::ready();
         };
 
-        // Store result
-        pending.results.push(result.clone());
+        // Count result
+        pending.received += 1;
+        drop(pending); // Release the lock before routing
 
         // Route measurement to the target RegionActor
-        if let Some(region_handle) = actor.model.region_actors.get(&region_id) {
-            let handle = region_handle.clone();
-            let result_clone = result;
-            tokio::spawn(async move {
-                handle.send(result_clone).await;
-            });
-        }
+        let routes: Vec<_> = actor
+            .model
+            .region_actors
+            .get(&region_id)
+            .map(|handle| (handle.value().clone(), result))
+            .into_iter()
+            .collect();
 
-        // Check if all measurements received
-        if !pending.is_complete() {
+        route_measurements(actor, &correlation_id, routes)
+    });
+
+    // Handle MeasurementFailed - count it; the region keeps its signals
+    actor.mutate_on::<MeasurementFailed>(|actor, context| {
+        let correlation_id = context.message().correlation_id.clone();
+
+        let Some(mut pending) = actor.model.pending_measurements.get_mut(&correlation_id) else {
+            warn!(
+                correlation_id = %correlation_id,
+                "Received measurement failure for unknown correlation ID"
+            );
             return Reply::ready();
-        }
-        drop(pending); // Release the lock before removing
+        };
+        pending.received += 1;
+        drop(pending);
 
-        let (_, pending) = actor
-            .model
-            .pending_measurements
-            .remove(&correlation_id)
-            .unwrap();
-        let now_ms = pending.now_ms;
+        route_measurements(actor, &correlation_id, Vec::new())
+    });
 
-        trace!(
-            correlation_id = %correlation_id,
-            results = pending.results.len(),
-            "Measurement phase complete, starting pressure query"
-        );
+    // Handle MeasurementBatch - route each result to its RegionActor
+    actor.mutate_on::<MeasurementBatch>(|actor, context| {
+        let batch = context.message().clone();
+        let correlation_id = batch.correlation_id.clone();
 
-        // Start pressure query phase
-        let query_correlation_id = "query".create_type_id::<V7>().to_string();
-        let expected_count = actor.model.region_actors.len();
+        let Some(mut pending) = actor.model.pending_measurements.get_mut(&correlation_id) else {
+            warn!(
+                correlation_id = %correlation_id,
+                "Received measurement batch for unknown correlation ID"
+            );
+            return Reply::ready();
+        };
 
-        actor.model.pending_pressure_queries.insert(
-            query_correlation_id.clone(),
-            PendingPressureQueries::new(expected_count, now_ms),
-        );
+        // Count results, failed measurements included
+        pending.received += batch.results.len() + batch.failed.len();
+        drop(pending); // Release the lock before routing
 
-        // Broadcast QueryPressure to all region actors via broker
-        let broker = actor.broker().clone();
+        let routes: Vec<_> = batch
+            .results
+            .into_iter()
+            .filter_map(|(region_id, signals)| {
+                let handle = actor.model.region_actors.get(&region_id)?.value().clone();
+                let result = MeasurementResult {
+                    correlation_id: correlation_id.clone(),
+                    region_id,
+                    sensor_name: batch.sensor_name.clone(),
+                    signals,
+                };
+                Some((handle, result))
+            })
+            .collect();
 
-        Reply::pending(async move {
-            let msg = QueryPressure {
-                correlation_id: query_correlation_id,
-                now_ms,
-            };
-            broker.broadcast(msg).await;
-        })
+        route_measurements(actor, &correlation_id, routes)
     });
 
-    // Handle PressureResponse - find high-pressure regions and start proposals
-    actor.mutate_on::<PressureResponse>(|actor, context| {
-        let response = context.message().clone();
-        let correlation_id = response.correlation_id.clone();
+    // Handle PressureSummary - select regions, then fetch full state for just those
+    actor.mutate_on::<PressureSummary>(|actor, context| {
+        let summary = context.message().clone();
+        let correlation_id = summary.correlation_id.clone();
 
         let Some(mut pending) = actor
             .model
@@ -715,13 +1406,13 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
         else {
             warn!(
                 correlation_id = %correlation_id,
-                "Received pressure response for unknown correlation ID"
+                "Received pressure summary for unknown correlation ID"
             );
             return Reply::ready();
         };
 
-        // Store response
-        pending.responses.push(response);
+        // Store summary
+        pending.summaries.push(summary);
 
         // Check if all queries complete
         if !pending.is_complete() {
@@ -736,26 +1427,219 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
             .unwrap();
         let now_ms = pending.now_ms;
 
+        let counted = outermost_measured(
+            pending
+                .summaries
+                .iter()
+                .map(|s| (&s.region_id, s.parent.as_ref(), !s.pressures.is_empty())),
+        );
+        let counted = || {
+            pending
+                .summaries
+                .iter()
+                .filter(|s| counted.contains(&s.region_id))
+        };
+        let totals = PressureTotals {
+            total_pressure: counted().map(|s| s.total_pressure).sum(),
+            axis_pressures: sum_axes(counted().map(|s| &s.pressures)),
+            evaluated: pending.summaries.len(),
+            skipped: pending.summaries.iter().filter(|s| s.is_inhibited).count(),
+        };
+        record_axis_totals(&mut actor.model, &totals);
+        let pressures = pending
+            .summaries
+            .iter()
+            .map(|s| (s.region_id.clone(), s.total_pressure))
+            .collect();
+        record_region_pressures(&mut actor.model, pressures);
+
         let Some(config) = actor.model.config.as_ref() else {
             return Reply::ready();
         };
 
-        // Find high-pressure, non-inhibited regions
-        let threshold = config.activation.min_total_pressure;
+        if config.mode == ExecutionMode::Async {
+            return complete_observation(actor, totals);
+        }
 
-        let high_pressure_regions: Vec<_> = pending
-            .responses
+        update_pressure_streaks(
+            &mut actor.model.pressure_streaks,
+            &config.granularity,
+            pending
+                .summaries
+                .iter()
+                .map(|s| (s.region_id.clone(), s.total_pressure)),
+        );
+
+        // Only regions that could receive proposals need their content and state
+        let threshold = config.activation.min_total_pressure;
+        let effective = effective_pressures(
+            &actor.model,
+            pending.summaries.iter().map(|s| {
+                let measured = !s.pressures.is_empty();
+                (
+                    s.region_id.clone(),
+                    measured.then_some(s.steered_pressure),
+                    s.parent.clone(),
+                )
+            }),
+        );
+        let selected: Vec<ActorHandle> = pending
+            .summaries
             .iter()
-            .filter(|r| !r.is_inhibited && r.total_pressure >= threshold)
-            .map(|r| {
+            .filter(|s| !s.is_inhibited && effective[&s.region_id] >= threshold)
+            .filter_map(|s| actor.model.region_actors.get(&s.region_id))
+            .map(|h| h.value().clone())
+            .collect();
+
+        trace!(
+            selected = selected.len(),
+            total_pressure = %totals.total_pressure,
+            "Pressure summaries complete"
+        );
+
+        if selected.is_empty() {
+            return complete_quiet_tick(actor, totals);
+        }
+
+        let fetch_correlation_id = "fetch".create_type_id::<V7>().to_string();
+        let mut fetch = PendingPressureQueries::new(selected.len(), now_ms);
+        fetch.totals = Some(totals);
+        fetch.effective = Some(effective);
+        actor
+            .model
+            .pending_pressure_queries
+            .insert(fetch_correlation_id.clone(), fetch);
+
+        Reply::pending(async move {
+            for handle in selected {
+                handle
+                    .send(QueryPressure {
+                        correlation_id: fetch_correlation_id.clone(),
+                        now_ms,
+                        summary_only: false,
+                    })
+                    .await;
+            }
+        })
+    });
+
+    // Handle PressureResponse - find high-pressure regions and start proposals
+    actor.mutate_on::<PressureResponse>(|actor, context| {
+        let response = context.message().clone();
+        let correlation_id = response.correlation_id.clone();
+
+        let Some(mut pending) = actor
+            .model
+            .pending_pressure_queries
+            .get_mut(&correlation_id)
+        else {
+            warn!(
+                correlation_id = %correlation_id,
+                "Received pressure response for unknown correlation ID"
+            );
+            return Reply::ready();
+        };
+
+        // Store response
+        pending.responses.push(response);
+
+        // Check if all queries complete
+        if !pending.is_complete() {
+            return Reply::ready();
+        }
+        drop(pending); // Release the lock before removing
+
+        let (_, pending) = actor
+            .model
+            .pending_pressure_queries
+            .remove(&correlation_id)
+            .unwrap();
+        let now_ms = pending.now_ms;
+
+        // Totals come from the summary phase when only selected regions were fetched
+        let totals = match pending.totals.clone() {
+            Some(totals) => totals,
+            None => {
+                let counted = outermost_measured(pending.responses.iter().map(|r| {
+                    (
+                        &r.region_id,
+                        r.view.parent.as_ref(),
+                        !r.state.pressure_ema.is_empty(),
+                    )
+                }));
+                let counted = || {
+                    pending
+                        .responses
+                        .iter()
+                        .filter(|r| counted.contains(&r.region_id))
+                };
+                let totals = PressureTotals {
+                    total_pressure: counted().map(|r| r.total_pressure).sum(),
+                    axis_pressures: sum_axes(counted().map(|r| &r.state.pressure_ema)),
+                    evaluated: pending.responses.len(),
+                    skipped: pending.responses.iter().filter(|r| r.is_inhibited).count(),
+                };
+                record_axis_totals(&mut actor.model, &totals);
+                let pressures = pending
+                    .responses
+                    .iter()
+                    .map(|r| (r.region_id.clone(), r.total_pressure))
+                    .collect();
+                record_region_pressures(&mut actor.model, pressures);
+                totals
+            }
+        };
+
+        let Some(config) = actor.model.config.as_ref() else {
+            return Reply::ready();
+        };
+
+        if config.mode == ExecutionMode::Async {
+            return complete_observation(actor, totals);
+        }
+
+        // Fetches of selected regions were already counted from their summaries
+        if pending.totals.is_none() {
+            update_pressure_streaks(
+                &mut actor.model.pressure_streaks,
+                &config.granularity,
+                pending
+                    .responses
+                    .iter()
+                    .map(|r| (r.region_id.clone(), r.total_pressure)),
+            );
+        }
+
+        // Find high-pressure, non-inhibited regions (by diffused, steered pressure)
+        let threshold = config.activation.min_total_pressure;
+        let effective = pending.effective.clone().unwrap_or_else(|| {
+            effective_pressures(
+                &actor.model,
+                pending.responses.iter().map(|r| {
+                    let measured = !r.state.pressure_ema.is_empty();
+                    (
+                        r.region_id.clone(),
+                        measured.then_some(r.steered_pressure),
+                        r.view.parent.clone(),
+                    )
+                }),
+            )
+        });
+        let is_active = |r: &PressureResponse| {
+            !r.is_inhibited && effective.get(&r.region_id).copied().unwrap_or(0.0) >= threshold
+        };
+
+        let high_pressure_regions: Vec<_> = pending
+            .responses
+            .iter()
+            .filter(|r| is_active(r))
+            .map(|r| {
                 let pressures: PressureVector = r.state.pressure_ema.clone();
                 (r.region_id.clone(), r.view.clone(), pressures)
             })
             .collect();
 
-        let total_pressure: f64 = pending.responses.iter().map(|r| r.total_pressure).sum();
-        let evaluated = pending.responses.len();
-        let skipped = pending.responses.iter().filter(|r| r.is_inhibited).count();
+        let total_pressure = totals.total_pressure;
 
         trace!(
             high_pressure = high_pressure_regions.len(),
@@ -765,48 +1649,7 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
 
         if high_pressure_regions.is_empty() {
             // No regions to propose for - complete tick immediately
-
-            // Compute derivatives
-            let velocity = compute_velocity(total_pressure, &actor.model.pressure_history);
-            let acceleration = compute_acceleration(velocity, &actor.model.velocity_history);
-
-            // Update history
-            actor.model.pressure_history.push(total_pressure);
-            actor.model.velocity_history.push(velocity);
-
-            let result = TickResult {
-                applied: Vec::new(),
-                evaluated,
-                skipped,
-                total_pressure,
-                velocity,
-                acceleration,
-                prompt_tokens: 0,
-                completion_tokens: 0,
-                is_complete: false,
-            };
-
-            actor.model.stable_ticks += 1;
-
-            info!(
-                tick = actor.model.current_tick,
-                pressure = format!("{:.2}", total_pressure),
-                velocity = format!("{:.3}", velocity),
-                acceleration = format!("{:.3}", acceleration),
-                applied = 0,
-                "Tick complete - stable (no high-pressure regions)"
-            );
-
-            // Broadcast TickComplete for TickActor to receive
-            let broker = actor.broker().clone();
-            return Reply::pending(async move {
-                broker
-                    .broadcast(TickComplete {
-                        result,
-                        is_complete: false, // No patches means not complete yet
-                    })
-                    .await;
-            });
+            return complete_quiet_tick(actor, totals);
         }
 
         // Generate correlation ID for proposal phase
@@ -816,29 +1659,18 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
         // sent to one agent, so we expect one response per region
         let expected_count = high_pressure_regions.len();
 
-        // Track pending proposals
-        actor.model.pending_proposals.insert(
-            proposal_correlation_id.clone(),
-            PendingProposals::new(
-                expected_count,
-                high_pressure_regions.clone(),
-                now_ms,
-                total_pressure,
-            ),
-        );
-
-        trace!(
-            correlation_id = %proposal_correlation_id,
-            regions = high_pressure_regions.len(),
-            expected_proposals = expected_count,
-            "Starting proposal phase (round-robin)"
+        let mut pending_proposals = PendingProposals::new(
+            expected_count,
+            high_pressure_regions.clone(),
+            now_ms,
+            total_pressure,
         );
 
         // Collect proposal data including signals from pressure response
         let proposal_data: Vec<_> = pending
             .responses
             .into_iter()
-            .filter(|r| !r.is_inhibited && r.total_pressure >= threshold)
+            .filter(|r| is_active(r))
             .map(|r| {
                 let pressures: PressureVector = r.state.pressure_ema.clone();
                 (r.region_id, r.view, r.signals, pressures, r.state)
@@ -854,33 +1686,126 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
 
         if handle_count == 0 {
             warn!("No patch actors registered, skipping proposal phase");
-            return Reply::ready();
+            return finish_proposal_phase(actor, pending_proposals);
         }
 
-        // Round-robin dispatch: each region goes to exactly one agent
-        Reply::pending(async move {
-            for (i, (rid, view, signals, pressures, state)) in proposal_data.into_iter().enumerate()
-            {
-                // Deterministic assignment: region i goes to agent i % handle_count
-                let agent_idx = i % handle_count;
-                let handle = &handles[agent_idx];
-
-                let msg = ProposeForRegion {
-                    correlation_id: proposal_correlation_id.clone(),
-                    region_id: rid,
-                    region_view: view,
-                    signals,
-                    pressures,
-                    state,
-                    claim_manager: claim_manager.clone(),
-                };
+        // Deterministic round-robin: each region goes to exactly one agent
+        // (weighted by reputation when configured)
+        let assignment = if config.reputation.weighted_dispatch {
+            let weights: Vec<f64> = handles
+                .iter()
+                .map(|(ern, _)| patch_actor_weight(&actor.model, ern))
+                .collect();
+            weighted_assignment(&weights, proposal_data.len())
+        } else {
+            (0..proposal_data.len()).map(|i| i % handle_count).collect()
+        };
+        let dispatched_at = Instant::now();
+        let mut sends = Vec::with_capacity(proposal_data.len());
+        for ((rid, view, signals, pressures, state), i) in proposal_data.into_iter().zip(assignment)
+        {
+            let (actor_ern, handle) = &handles[i];
+
+            let msg = ProposeForRegion {
+                correlation_id: proposal_correlation_id.clone(),
+                region_id: rid.clone(),
+                region_view: view,
+                signals,
+                pressures,
+                state,
+                claim_manager: claim_manager.clone(),
+                explore_seed: actor.model.explore_seeds.remove(&rid),
+                pheromones: offer_pheromones(&mut actor.model, &rid),
+            };
+
+            pending_proposals.outstanding.insert(
+                rid,
+                OutstandingProposal {
+                    actor_ern: actor_ern.clone(),
+                    dispatched_at,
+                    request: msg.clone(),
+                },
+            );
+            sends.push((handle.clone(), msg));
+        }
 
+        trace!(
+            correlation_id = %proposal_correlation_id,
+            regions = expected_count,
+            patch_actors = handle_count,
+            "Starting proposal phase (round-robin)"
+        );
+
+        // Track pending proposals
+        actor
+            .model
+            .pending_proposals
+            .insert(proposal_correlation_id, pending_proposals);
+        schedule_sweep(actor);
+
+        Reply::pending(async move {
+            for (handle, msg) in sends {
                 // Direct send instead of broadcast
                 handle.send(msg).await;
             }
         })
     });
 
+    // Handle RequestProposal - async mode: a region asks for its own proposal
+    actor.mutate_on::<RequestProposal>(|actor, context| {
+        let msg = context.message().clone();
+        let correlation_id = "request".create_type_id::<V7>().to_string();
+        let total_pressure: f64 = msg.pressures.values().sum();
+
+        let mut pending = PendingProposals::new(
+            1,
+            vec![(
+                msg.region_id.clone(),
+                msg.region_view.clone(),
+                msg.pressures.clone(),
+            )],
+            msg.now_ms,
+            total_pressure,
+        );
+        pending.autonomous = true;
+
+        let Some((actor_ern, handle)) = least_loaded_patch_actor(&actor.model, "") else {
+            debug!(region = %msg.region_id, "No patch actors registered, declining request");
+            return finish_proposal_phase(actor, pending);
+        };
+        actor.model.dispatch_turn += 1;
+
+        let request = ProposeForRegion {
+            correlation_id: correlation_id.clone(),
+            region_id: msg.region_id.clone(),
+            region_view: msg.region_view,
+            signals: msg.signals,
+            pressures: msg.pressures,
+            state: msg.state,
+            claim_manager: actor.model.claim_manager.clone(),
+            explore_seed: actor.model.explore_seeds.remove(&msg.region_id),
+            pheromones: offer_pheromones(&mut actor.model, &msg.region_id),
+        };
+        pending.outstanding.insert(
+            msg.region_id,
+            OutstandingProposal {
+                actor_ern,
+                dispatched_at: Instant::now(),
+                request: request.clone(),
+            },
+        );
+
+        actor
+            .model
+            .pending_proposals
+            .insert(correlation_id, pending);
+        schedule_sweep(actor);
+
+        Reply::pending(async move {
+            handle.send(request).await;
+        })
+    });
+
     // Handle PatchProposal - collect and start patch application
     actor.mutate_on::<PatchProposal>(|actor, context| {
         let proposal = context.message().clone();
@@ -894,6 +1819,29 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
             return Reply::ready();
         };
 
+        let Some(outstanding) = take_outstanding(&mut pending, &proposal) else {
+            debug!(
+                correlation_id = %correlation_id,
+                region = %proposal.region_id,
+                patch_actor_ern = %proposal.actor_ern,
+                "Ignoring proposal from an actor the request is not outstanding with"
+            );
+            return Reply::ready();
+        };
+
+        // Answering a request counts as a heartbeat for the actor it was sent to
+        if let Some(seen) = actor
+            .model
+            .patch_actor_last_seen
+            .get_mut(&outstanding.actor_ern)
+        {
+            *seen = Instant::now();
+        }
+        actor
+            .model
+            .patch_actor_names
+            .insert(outstanding.actor_ern, proposal.actor_name.clone());
+
         // Store proposal
         pending.proposals.push(proposal);
 
@@ -908,151 +1856,8 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
             .pending_proposals
             .remove(&correlation_id)
             .unwrap();
-        let now_ms = pending.now_ms;
-
-        trace!(
-            correlation_id = %correlation_id,
-            proposals = pending.proposals.len(),
-            "Proposal phase complete"
-        );
-
-        let Some(config) = actor.model.config.as_ref() else {
-            return Reply::ready();
-        };
-
-        // Aggregate token counts before consuming proposals
-        let (prompt_tokens, completion_tokens) =
-            pending.proposals.iter().fold((0u32, 0u32), |(pt, ct), p| {
-                (pt + p.prompt_tokens, ct + p.completion_tokens)
-            });
-
-        // Group patches by region and select best patch for each eligible region
-        let all_patches: Vec<(f64, Patch)> = pending
-            .proposals
-            .into_iter()
-            .flat_map(|p| p.patches)
-            .collect();
-
-        // Keep only the highest-scored patch per region
-        let mut best_per_region: HashMap<RegionId, (f64, Patch)> = HashMap::new();
-        for (score, patch) in all_patches {
-            best_per_region
-                .entry(patch.region.clone())
-                .and_modify(|existing| {
-                    if score > existing.0 {
-                        *existing = (score, patch.clone());
-                    }
-                })
-                .or_insert((score, patch));
-        }
-
-        // Each eligible region gets its best patch
-        let top_patches: Vec<_> = best_per_region.into_values().collect();
-
-        if top_patches.is_empty() {
-            // No patches to apply - calculate total pressure from high_pressure_regions
-            let total_pressure: f64 = pending
-                .high_pressure_regions
-                .iter()
-                .map(|(_rid, _, pressures)| pressures.values().sum::<f64>())
-                .sum();
-
-            // Compute derivatives
-            let velocity = compute_velocity(total_pressure, &actor.model.pressure_history);
-            let acceleration = compute_acceleration(velocity, &actor.model.velocity_history);
-
-            // Update history
-            actor.model.pressure_history.push(total_pressure);
-            actor.model.velocity_history.push(velocity);
-
-            let result = TickResult {
-                applied: Vec::new(),
-                evaluated: actor.model.region_actors.len(),
-                skipped: 0,
-                total_pressure,
-                velocity,
-                acceleration,
-                prompt_tokens,
-                completion_tokens,
-                is_complete: false,
-            };
-
-            actor.model.stable_ticks += 1;
-
-            info!(
-                tick = actor.model.current_tick,
-                pressure = format!("{:.2}", total_pressure),
-                velocity = format!("{:.3}", velocity),
-                acceleration = format!("{:.3}", acceleration),
-                applied = 0,
-                prompt_tokens,
-                completion_tokens,
-                "Tick complete - stable (no patches proposed)"
-            );
-
-            // Broadcast TickComplete for TickActor to receive
-            let broker = actor.broker().clone();
-            return Reply::pending(async move {
-                broker
-                    .broadcast(TickComplete {
-                        result,
-                        is_complete: false, // No patches means not complete yet
-                    })
-                    .await;
-            });
-        }
-
-        // Generate correlation ID for patch application
-        let patch_correlation_id = "patch".create_type_id::<V7>().to_string();
-        let expected_count = top_patches.len();
-
-        // Track pending patch results
-        actor.model.pending_patches.insert(
-            patch_correlation_id.clone(),
-            PendingPatches {
-                expected_count,
-                results: Vec::new(),
-                last_total_pressure: pending.total_pressure,
-                evaluated_count: actor.model.region_actors.len(),
-                skipped_count: 0,
-                prompt_tokens,
-                completion_tokens,
-            },
-        );
-
-        let inhibit_ms = config.activation.inhibit_ms;
-        let min_improvement = config.selection.min_expected_improvement;
-        let region_actors: HashMap<RegionId, ActorHandle> = actor
-            .model
-            .region_actors
-            .iter()
-            .map(|e| (e.key().clone(), e.value().clone()))
-            .collect();
-        let current_tick = actor.model.current_tick;
-
-        trace!(
-            correlation_id = %patch_correlation_id,
-            patches = top_patches.len(),
-            "Sending patches to RegionActors for validation"
-        );
 
-        // Send patches to RegionActors
-        Reply::pending(async move {
-            for (_, patch) in top_patches {
-                let rid = patch.region.clone();
-                if let Some(region_handle) = region_actors.get(&rid) {
-                    let msg = RegionApplyPatch {
-                        correlation_id: patch_correlation_id.clone(),
-                        patch,
-                        now_ms,
-                        tick: current_tick,
-                        inhibit_ms,
-                        min_expected_improvement: min_improvement,
-                    };
-                    region_handle.send(msg).await;
-                }
-            }
-        })
+        finish_proposal_phase(actor, pending)
     });
 
     // Handle RegionPatchResult - collect results and complete tick
@@ -1074,18 +1879,59 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
                 expected_delta: HashMap::new(),
             };
 
-            let (still_valid, actual_delta) = artifact.evaluate_patch(&patch);
+            let (axes, mode) = acceptance_rules(actor.model.config.as_ref());
+            let (still_valid, actual_delta, rejection) =
+                match frozen_in_lineage(&actor.model, &result.region_id) {
+                    Some(frozen) => (false, 0.0, Some(Rejection::Frozen(frozen))),
+                    None => constraint::evaluate(artifact.as_ref(), axes, mode, &patch),
+                };
 
             if !still_valid {
                 debug!(
                     region = %result.region_id,
                     original_delta = result.pressure_delta,
                     actual_delta = actual_delta,
+                    rejection = ?rejection,
                     "Patch rejected on re-evaluation - conflict with prior patch"
                 );
                 result.success = false;
                 result.pressure_delta = actual_delta;
+                result.rejection = Some(rejection.unwrap_or(Rejection::NoImprovement {
+                    delta: actual_delta,
+                }));
+            }
+        }
+
+        warn_unreported_axes(&mut actor.model, result.rejection.as_ref());
+        actor
+            .model
+            .reputation
+            .entry(result.origin.actor_name.clone())
+            .or_default()
+            .record(result.success, result.expected_delta, result.pressure_delta);
+        deposit_pheromone(&mut actor.model, &result);
+
+        let is_async = actor
+            .model
+            .config
+            .as_ref()
+            .is_some_and(|c| c.mode == ExecutionMode::Async);
+
+        // Async mode: results from autonomous regions apply immediately
+        if is_async && !actor.model.pending_patches.contains_key(&correlation_id) {
+            actor.model.patches_in_flight.remove(&result.region_id);
+            let refreshes = apply_result_to_artifact(&mut actor.model, &result);
+            check_patches(&mut actor.model, std::slice::from_ref(&result), None);
+            if result.success {
+                actor
+                    .model
+                    .async_window
+                    .applied
+                    .push(applied_patch(&result));
+            } else {
+                actor.model.async_window.rejected += 1;
             }
+            return Reply::pending(send_refreshes(refreshes));
         }
 
         let Some(mut pending) = actor.model.pending_patches.get_mut(&correlation_id) else {
@@ -1104,33 +1950,11 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
         drop(pending); // Release the lock before potentially modifying artifact
 
         // If patch was successful (including re-evaluation), update the artifact
-        if result.success
-            && let (Some(artifact), Some(new_content)) =
-                (actor.model.artifact.as_mut(), &result.new_content)
-        {
-            // Create a patch to update the artifact
-            let patch = Patch {
-                region: result.region_id.clone(),
-                op: crate::region::PatchOp::Replace(new_content.clone()),
-                rationale: format!("Validated patch (δ={:.3})", result.pressure_delta),
-                expected_delta: HashMap::new(),
-            };
-
-            if let Err(e) = artifact.apply_patch(patch.clone()) {
-                warn!(
-                    region = %result.region_id,
-                    error = %e,
-                    "Failed to apply validated patch to artifact"
-                );
-            } else {
-                // Notify artifact of successful patch (for learning callbacks)
-                artifact.on_patch_applied(&patch);
-            }
-        }
+        let refreshes = apply_result_to_artifact(&mut actor.model, &result);
 
         // Check if all results received
         if !is_complete {
-            return Reply::ready();
+            return Reply::pending(send_refreshes(refreshes));
         }
 
         let (_, pending) = actor.model.pending_patches.remove(&correlation_id).unwrap();
@@ -1140,15 +1964,17 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
             .results
             .iter()
             .filter(|r| r.success)
-            .map(|r| Patch {
-                region: r.region_id.clone(),
-                op: crate::region::PatchOp::Replace(r.new_content.clone().unwrap_or_default()),
-                rationale: format!("δ={:.3}", r.pressure_delta),
-                expected_delta: HashMap::new(),
-            })
+            .map(applied_patch)
             .collect();
 
         let rejected_count = pending.results.iter().filter(|r| !r.success).count();
+        check_patches(&mut actor.model, &pending.results, Some(pending.now_ms));
+
+        // The next observation shows how these patches moved other regions
+        actor.model.coupling_sources = Some((
+            actor.model.current_tick,
+            applied.iter().map(|p| p.region.clone()).collect(),
+        ));
 
         // Track stability
         if applied.is_empty() {
@@ -1165,13 +1991,8 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
             .map(|r| r.pressure_delta)
             .sum();
 
-        // Use actual artifact pressure if available, otherwise fall back to EMA-based
-        let new_pressure = actor
-            .model
-            .artifact
-            .as_ref()
-            .and_then(|a| a.total_pressure())
-            .unwrap_or(pending.last_total_pressure - total_delta);
+        let new_pressure =
+            tick_total_pressure(&actor.model, pending.last_total_pressure - total_delta);
 
         // Get previous tick's final pressure for display (before updating history)
         // This matches the comparison used for velocity calculation
@@ -1182,8 +2003,10 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
         let acceleration = compute_acceleration(velocity, &actor.model.velocity_history);
 
         // Update history
+        let violations = check_tick(&mut actor.model, new_pressure);
         actor.model.pressure_history.push(new_pressure);
         actor.model.velocity_history.push(velocity);
+        let best_so_far = record_best_so_far(&mut actor.model, new_pressure);
 
         // Check if artifact is complete
         let artifact_complete = actor
@@ -1198,11 +2021,20 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
             evaluated: pending.evaluated_count,
             skipped: pending.skipped_count,
             total_pressure: new_pressure,
+            axis_pressures: actor.model.axis_pressures.clone(),
             velocity,
             acceleration,
             prompt_tokens: pending.prompt_tokens,
             completion_tokens: pending.completion_tokens,
             is_complete: artifact_complete,
+            reputation: actor.model.reputation.clone(),
+            pareto_snapshot: actor.model.pareto_snapshot.take(),
+            normalization: actor.model.normalizer.stats(),
+            kicks: std::mem::take(&mut actor.model.kicks),
+            best_so_far,
+            config_changes: std::mem::take(&mut actor.model.config_changes),
+            coupling: std::mem::take(&mut actor.model.coupling_samples),
+            violations,
         };
 
         info!(
@@ -1225,40 +2057,211 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
 
         // Broadcast TickComplete for external tick loop to receive
         let broker = actor.broker().clone();
+        let shutdown = end_tick(actor);
         Reply::pending(async move {
+            send_refreshes(refreshes).await;
             broker
                 .broadcast(TickComplete {
                     result: tick_result,
                     is_complete: artifact_complete,
                 })
                 .await;
+            if let Some(shutdown) = shutdown {
+                shutdown.await;
+            }
         })
     });
 
-    // Handle SaveArtifact - write the current artifact state to a file
-    actor.act_on::<SaveArtifact>(|actor, context| {
-        let msg = context.message();
-
-        if let Some(artifact) = actor.model.artifact.as_ref() {
-            if let Some(source) = artifact.source() {
-                match std::fs::write(&msg.path, &source) {
-                    Ok(_) => {
-                        info!(path = %msg.path.display(), bytes = source.len(), "Artifact saved");
-                    }
-                    Err(e) => {
-                        warn!(path = %msg.path.display(), error = %e, "Failed to save artifact");
-                    }
+    // Handle SteerRegions - forward external steering to the regions' actors
+    actor.mutate_on::<SteerRegions>(|actor, context| {
+        let msg = context.message().clone();
+        steer_frozen(&mut actor.model.frozen_regions, &msg);
+        let targets: Vec<ActorHandle> = msg
+            .regions
+            .iter()
+            .filter_map(|id| {
+                let handle = actor.model.region_actors.get(id).map(|h| h.value().clone());
+                if handle.is_none() {
+                    warn!(region = %id, "SteerRegions: unknown region");
                 }
-            } else {
-                warn!("Artifact does not support source() method");
-            }
-        } else {
-            warn!("No artifact to save");
+                handle
+            })
+            .collect();
+        info!(regions = targets.len(), update = ?msg.update, "Steering regions");
+        Reply::pending(async move {
+            for handle in targets {
+                handle
+                    .send(SteerRegion {
+                        update: msg.update.clone(),
+                    })
+                    .await;
+            }
+        })
+    });
+
+    // Handle UpdateConfig - hot-swap the config and push new axes to regions
+    actor.mutate_on::<UpdateConfig>(|actor, context| {
+        let msg = context.message().clone();
+        let result = update_config(&mut actor.model, msg.config);
+        let axes: Vec<(ActorHandle, UpdatePressureAxes)> = match &result {
+            Ok(change) if change.changed.iter().any(|c| c == "pressure_axes") => actor
+                .model
+                .region_actors
+                .iter()
+                .map(|entry| {
+                    let update = UpdatePressureAxes {
+                        axes: change.config.pressure_axes.clone(),
+                    };
+                    (entry.value().clone(), update)
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        let reply = ConfigUpdated {
+            correlation_id: msg.correlation_id,
+            result,
+        };
+        let broker = actor.broker().clone();
+        Reply::pending(async move {
+            for (handle, update) in axes {
+                handle.send(update).await;
+            }
+            broker.broadcast(reply).await;
+        })
+    });
+
+    // Handle Pause - hold the next tick until Resume or Step
+    actor.mutate_on::<Pause>(|actor, _context| {
+        actor.model.steps_remaining = Some(0);
+        info!(tick = actor.model.current_tick, "Kernel paused");
+        Reply::ready()
+    });
+
+    // Handle Resume - tick freely again
+    actor.mutate_on::<Resume>(|actor, _context| {
+        actor.model.steps_remaining = None;
+        info!(tick = actor.model.current_tick, "Kernel resumed");
+        release_parked_tick(actor)
+    });
+
+    // Handle Step - run n more ticks, then pause
+    actor.mutate_on::<Step>(|actor, context| {
+        actor.model.steps_remaining = Some(context.message().n);
+        debug!(steps = context.message().n, "Kernel stepping");
+        release_parked_tick(actor)
+    });
+
+    // Handle Shutdown - stop once the current tick completes
+    actor.mutate_on::<Shutdown>(|actor, context| {
+        if actor.model.stopped {
+            return Reply::ready();
+        }
+        actor.model.shutdown_requested = Some(context.message().clone());
+        if actor.model.tick_in_flight || actor.model.restructure.is_some() {
+            info!(
+                tick = actor.model.current_tick,
+                "Shutdown requested; finishing current tick"
+            );
+            return Reply::ready();
+        }
+        Reply::pending(begin_shutdown(actor))
+    });
+
+    // Handle SaveArtifact - write the current artifact state to a file
+    actor.act_on::<SaveArtifact>(|actor, context| {
+        let msg = context.message();
+
+        if let Some(artifact) = actor.model.artifact.as_ref() {
+            if let Some(source) = artifact.source() {
+                match std::fs::write(&msg.path, &source) {
+                    Ok(_) => {
+                        info!(path = %msg.path.display(), bytes = source.len(), "Artifact saved");
+                    }
+                    Err(e) => {
+                        warn!(path = %msg.path.display(), error = %e, "Failed to save artifact");
+                    }
+                }
+            } else {
+                warn!("Artifact does not support source() method");
+            }
+        } else {
+            warn!("No artifact to save");
         }
 
         Reply::ready()
     });
 
+    // Handle QueryBestSoFar - report the lowest-pressure state seen so far
+    actor.act_on::<QueryBestSoFar>(|actor, context| {
+        let report = BestSoFarReport {
+            correlation_id: context.message().correlation_id.clone(),
+            best: actor.model.best_so_far.clone(),
+        };
+        let broker = actor.broker().clone();
+        Reply::pending(async move {
+            broker.broadcast(report).await;
+        })
+    });
+
+    // Handle ExportRegions - island model: offer our lowest-pressure regions
+    actor.act_on::<ExportRegions>(|actor, context| {
+        let export = export_regions(&actor.model, context.message());
+        let broker = actor.broker().clone();
+        Reply::pending(async move {
+            broker.broadcast(export).await;
+        })
+    });
+
+    // Handle ImportRegions - island model: adopt migrants that improve the artifact
+    actor.mutate_on::<ImportRegions>(|actor, context| {
+        let msg = context.message();
+        let (accepted, refreshes) = import_regions(&mut actor.model, &msg.migrants);
+        if !accepted.is_empty() {
+            info!(
+                accepted = accepted.len(),
+                offered = msg.migrants.len(),
+                "Imported migrant regions"
+            );
+        }
+        let report = RegionsImported {
+            correlation_id: msg.correlation_id.clone(),
+            accepted,
+        };
+        let broker = actor.broker().clone();
+        Reply::pending(async move {
+            send_refreshes(refreshes).await;
+            broker.broadcast(report).await;
+        })
+    });
+
+    // Handle QueryProvenance - the region actor owns the history, so forward it
+    actor.act_on::<QueryProvenance>(|actor, context| {
+        let msg = context.message().clone();
+        let handle = actor
+            .model
+            .region_actors
+            .get(&msg.region_id)
+            .map(|h| h.value().clone());
+
+        let Some(handle) = handle else {
+            warn!(region = %msg.region_id, "QueryProvenance: unknown region");
+            let broker = actor.broker().clone();
+            return Reply::pending(async move {
+                broker
+                    .broadcast(ProvenanceReport {
+                        correlation_id: msg.correlation_id,
+                        region_id: msg.region_id,
+                        records: Vec::new(),
+                    })
+                    .await;
+            });
+        };
+
+        Reply::pending(async move {
+            handle.send(msg).await;
+        })
+    });
+
     // Handle SetOutputDir - configure validation artifact output directory
     actor.mutate_on::<SetOutputDir>(|actor, context| {
         let path = context.message().path.clone();
@@ -1391,14 +2394,18 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
             }
         };
 
-        // Use artifact's evaluate_patch for clone-based validation
-        let (should_accept, pressure_delta) = if let Some(artifact) = actor.model.artifact.as_ref()
-        {
-            artifact.evaluate_patch(&msg.patch)
-        } else {
-            warn!("EvaluatePatch: artifact not initialized");
-            (false, 0.0)
-        };
+        // Use artifact's evaluate_patch for clone-based validation, then
+        // enforce hard and prioritized axes
+        let (axes, mode) = acceptance_rules(actor.model.config.as_ref());
+        let (should_accept, pressure_delta, rejection) =
+            if let Some(frozen) = frozen_in_lineage(&actor.model, &region_id) {
+                (false, 0.0, Some(Rejection::Frozen(frozen)))
+            } else if let Some(artifact) = actor.model.artifact.as_ref() {
+                constraint::evaluate(artifact.as_ref(), axes, mode, &msg.patch)
+            } else {
+                warn!("EvaluatePatch: artifact not initialized");
+                (false, 0.0, None)
+            };
 
         let response = EvaluatePatchResponse {
             correlation_id: msg.correlation_id,
@@ -1406,6 +2413,7 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
             should_accept,
             pressure_delta,
             new_content,
+            rejection,
         };
 
         if let Some(handle) = region_actors.get(&region_id).cloned() {
@@ -1418,3 +2426,2795 @@ fn configure_handlers(actor: &mut ManagedActor<Idle, KernelCoordinatorState>) {
         }
     });
 }
+
+/// Select the best patch per region and send them to RegionActors.
+///
+/// Called once every dispatched region has been answered or abandoned.
+fn finish_proposal_phase(
+    actor: &mut ManagedActor<Started, KernelCoordinatorState>,
+    pending: PendingProposals,
+) -> HandlerFuture {
+    let now_ms = pending.now_ms;
+
+    trace!(
+        expected = pending.expected_count,
+        proposals = pending.proposals.len(),
+        "Proposal phase complete"
+    );
+
+    let Some(config) = actor.model.config.as_ref() else {
+        return Reply::ready();
+    };
+
+    // Aggregate token counts before consuming proposals
+    let (prompt_tokens, completion_tokens) =
+        pending.proposals.iter().fold((0u32, 0u32), |(pt, ct), p| {
+            (pt + p.prompt_tokens, ct + p.completion_tokens)
+        });
+
+    if pending.autonomous {
+        actor.model.async_window.prompt_tokens += prompt_tokens;
+        actor.model.async_window.completion_tokens += completion_tokens;
+        return answer_region_request(actor, pending);
+    }
+
+    // Group patches by region and select best patch for each eligible region
+    let reputation = config
+        .reputation
+        .rescale_scores
+        .then_some(&actor.model.reputation);
+    let all_patches: Vec<(f64, Patch, PatchOrigin)> = pending
+        .proposals
+        .into_iter()
+        .flat_map(|p| {
+            proposal_patches(
+                p,
+                reputation,
+                &actor.model.transformers,
+                actor.model.artifact.as_deref(),
+            )
+        })
+        .collect();
+
+    // Keep only the highest-scored patch per region
+    let mut best_per_region: HashMap<RegionId, (f64, Patch, PatchOrigin)> = HashMap::new();
+    for (score, patch, origin) in all_patches {
+        best_per_region
+            .entry(patch.region.clone())
+            .and_modify(|existing| {
+                if score > existing.0 {
+                    *existing = (score, patch.clone(), origin.clone());
+                }
+            })
+            .or_insert((score, patch, origin));
+    }
+
+    // Each eligible region gets its best patch, unless a higher-scored patch
+    // targets an enclosing or nested region
+    let top_patches = match actor.model.artifact.as_deref() {
+        Some(artifact) => drop_nested_conflicts(artifact, best_per_region.into_values().collect()),
+        None => best_per_region.into_values().collect(),
+    };
+
+    if top_patches.is_empty() {
+        // No patches to apply - pressure is what the query phase measured over
+        // every region, inhibited and quiet ones included
+        let total_pressure = tick_total_pressure(&actor.model, pending.total_pressure);
+
+        // Compute derivatives
+        let velocity = compute_velocity(total_pressure, &actor.model.pressure_history);
+        let acceleration = compute_acceleration(velocity, &actor.model.velocity_history);
+
+        // Update history
+        let violations = check_tick(&mut actor.model, total_pressure);
+        actor.model.pressure_history.push(total_pressure);
+        actor.model.velocity_history.push(velocity);
+        let best_so_far = record_best_so_far(&mut actor.model, total_pressure);
+
+        let result = TickResult {
+            applied: Vec::new(),
+            evaluated: actor.model.region_actors.len(),
+            skipped: 0,
+            total_pressure,
+            axis_pressures: actor.model.axis_pressures.clone(),
+            velocity,
+            acceleration,
+            prompt_tokens,
+            completion_tokens,
+            is_complete: false,
+            reputation: actor.model.reputation.clone(),
+            pareto_snapshot: actor.model.pareto_snapshot.take(),
+            normalization: actor.model.normalizer.stats(),
+            kicks: std::mem::take(&mut actor.model.kicks),
+            best_so_far,
+            config_changes: std::mem::take(&mut actor.model.config_changes),
+            coupling: std::mem::take(&mut actor.model.coupling_samples),
+            violations,
+        };
+
+        actor.model.stable_ticks += 1;
+
+        info!(
+            tick = actor.model.current_tick,
+            pressure = format!("{:.2}", total_pressure),
+            velocity = format!("{:.3}", velocity),
+            acceleration = format!("{:.3}", acceleration),
+            applied = 0,
+            prompt_tokens,
+            completion_tokens,
+            "Tick complete - stable (no patches proposed)"
+        );
+
+        // Broadcast TickComplete for TickActor to receive
+        let broker = actor.broker().clone();
+        let shutdown = end_tick(actor);
+        return Reply::pending(async move {
+            broker
+                .broadcast(TickComplete {
+                    result,
+                    is_complete: false, // No patches means not complete yet
+                })
+                .await;
+            if let Some(shutdown) = shutdown {
+                shutdown.await;
+            }
+        });
+    }
+
+    // Generate correlation ID for patch application
+    let patch_correlation_id = "patch".create_type_id::<V7>().to_string();
+    let expected_count = top_patches.len();
+
+    // Track pending patch results
+    actor.model.pending_patches.insert(
+        patch_correlation_id.clone(),
+        PendingPatches {
+            expected_count,
+            results: Vec::new(),
+            last_total_pressure: pending.total_pressure,
+            evaluated_count: actor.model.region_actors.len(),
+            skipped_count: 0,
+            prompt_tokens,
+            completion_tokens,
+            now_ms,
+        },
+    );
+
+    let inhibit_ms = config.activation.inhibit_ms;
+    let min_improvement = config.selection.min_expected_improvement;
+    let region_actors: HashMap<RegionId, ActorHandle> = actor
+        .model
+        .region_actors
+        .iter()
+        .map(|e| (e.key().clone(), e.value().clone()))
+        .collect();
+    let current_tick = actor.model.current_tick;
+
+    trace!(
+        correlation_id = %patch_correlation_id,
+        patches = top_patches.len(),
+        "Sending patches to RegionActors for validation"
+    );
+
+    // Send patches to RegionActors
+    Reply::pending(async move {
+        for (_, patch, origin) in top_patches {
+            let rid = patch.region.clone();
+            if let Some(region_handle) = region_actors.get(&rid) {
+                let msg = RegionApplyPatch {
+                    correlation_id: patch_correlation_id.clone(),
+                    patch,
+                    origin,
+                    now_ms,
+                    tick: current_tick,
+                    inhibit_ms,
+                    min_expected_improvement: min_improvement,
+                };
+                region_handle.send(msg).await;
+            }
+        }
+    })
+}
+
+/// Flatten a proposal into its patches, each tagged with who proposed it.
+///
+/// Each patch first runs through the transformer chain; vetoed patches are
+/// dropped and rewrites are noted in the origin. With a reputation table,
+/// scores are scaled by the proposer's weight.
+fn proposal_patches(
+    proposal: PatchProposal,
+    reputation: Option<&ReputationTable>,
+    transformers: &[Arc<dyn PatchTransformer>],
+    artifact: Option<&dyn Artifact>,
+) -> Vec<(f64, Patch, PatchOrigin)> {
+    let weight = reputation.map_or(1.0, |table| weight_of(table, &proposal.actor_name));
+    let origin = PatchOrigin {
+        actor_name: proposal.actor_name,
+        model: proposal.model,
+        sampling_band: proposal.sampling_band,
+        transforms: Vec::new(),
+    };
+
+    let mut passed: Vec<Patch> = Vec::new();
+    let mut patches = Vec::with_capacity(proposal.patches.len());
+    for (score, patch) in proposal.patches {
+        let (patch, transforms) = match artifact {
+            Some(artifact) if !transformers.is_empty() => {
+                match run_chain(transformers, artifact, patch, &passed) {
+                    Ok(transformed) => transformed,
+                    Err(veto) => {
+                        debug!(
+                            actor = %origin.actor_name,
+                            transformer = %veto.transformer,
+                            reason = %veto.reason,
+                            "Patch vetoed by transformer"
+                        );
+                        continue;
+                    }
+                }
+            }
+            _ => (patch, Vec::new()),
+        };
+        passed.push(patch.clone());
+        let origin = PatchOrigin {
+            transforms,
+            ..origin.clone()
+        };
+        patches.push((score * weight, patch, origin));
+    }
+    patches
+}
+
+/// Route the best patch for a region's own request back to it (async mode).
+///
+/// Only patches targeting the requesting region are considered; otherwise the
+/// region is told nothing was proposed so it can ask again on its next wake.
+fn answer_region_request(
+    actor: &mut ManagedActor<Started, KernelCoordinatorState>,
+    pending: PendingProposals,
+) -> HandlerFuture {
+    let Some(config) = actor.model.config.as_ref() else {
+        return Reply::ready();
+    };
+    let Some((region_id, _, _)) = pending.high_pressure_regions.into_iter().next() else {
+        return Reply::ready();
+    };
+    let Some(region_handle) = actor
+        .model
+        .region_actors
+        .get(&region_id)
+        .map(|h| h.value().clone())
+    else {
+        warn!(region = %region_id, "No actor handle for region");
+        return Reply::ready();
+    };
+
+    let reputation = config
+        .reputation
+        .rescale_scores
+        .then_some(&actor.model.reputation);
+    let best = pending
+        .proposals
+        .into_iter()
+        .flat_map(|p| {
+            proposal_patches(
+                p,
+                reputation,
+                &actor.model.transformers,
+                actor.model.artifact.as_deref(),
+            )
+        })
+        .filter(|(_, patch, _)| patch.region == region_id)
+        .max_by(|a, b| a.0.total_cmp(&b.0));
+
+    // A patch to an enclosing or nested region is still in flight
+    let conflicting = actor.model.artifact.as_deref().is_some_and(|artifact| {
+        lineage(artifact, &region_id)
+            .iter()
+            .any(|id| actor.model.patches_in_flight.contains(id))
+    });
+
+    let (Some((_, patch, origin)), false) = (best, conflicting) else {
+        return Reply::pending(async move {
+            region_handle.send(NoPatchProposed { region_id }).await;
+        });
+    };
+    actor.model.patches_in_flight.insert(region_id);
+
+    let msg = RegionApplyPatch {
+        correlation_id: "patch".create_type_id::<V7>().to_string(),
+        patch,
+        origin,
+        now_ms: pending.now_ms,
+        tick: actor.model.current_tick,
+        inhibit_ms: config.activation.inhibit_ms,
+        min_expected_improvement: config.selection.min_expected_improvement,
+    };
+    Reply::pending(async move {
+        region_handle.send(msg).await;
+    })
+}
+
+/// Report pressure and the work done since the previous observation (async mode).
+fn complete_observation(
+    actor: &mut ManagedActor<Started, KernelCoordinatorState>,
+    totals: PressureTotals,
+) -> HandlerFuture {
+    let window = std::mem::take(&mut actor.model.async_window);
+
+    let total_pressure = tick_total_pressure(&actor.model, totals.total_pressure);
+
+    // Compute derivatives
+    let velocity = compute_velocity(total_pressure, &actor.model.pressure_history);
+    let acceleration = compute_acceleration(velocity, &actor.model.velocity_history);
+
+    // Update history
+    let violations = check_tick(&mut actor.model, total_pressure);
+    actor.model.pressure_history.push(total_pressure);
+    actor.model.velocity_history.push(velocity);
+    let best_so_far = record_best_so_far(&mut actor.model, total_pressure);
+
+    // Track stability
+    if window.applied.is_empty() {
+        actor.model.stable_ticks += 1;
+    } else {
+        actor.model.stable_ticks = 0;
+    }
+
+    let artifact_complete = actor
+        .model
+        .artifact
+        .as_ref()
+        .map(|a| a.is_complete())
+        .unwrap_or(false);
+
+    info!(
+        tick = actor.model.current_tick,
+        pressure = format!("{:.2}", total_pressure),
+        velocity = format!("{:.3}", velocity),
+        acceleration = format!("{:.3}", acceleration),
+        applied = window.applied.len(),
+        rejected = window.rejected,
+        prompt_tokens = window.prompt_tokens,
+        completion_tokens = window.completion_tokens,
+        is_complete = artifact_complete,
+        "Observation complete"
+    );
+
+    let result = TickResult {
+        applied: window.applied,
+        evaluated: totals.evaluated,
+        skipped: totals.skipped,
+        total_pressure,
+        axis_pressures: actor.model.axis_pressures.clone(),
+        velocity,
+        acceleration,
+        prompt_tokens: window.prompt_tokens,
+        completion_tokens: window.completion_tokens,
+        is_complete: artifact_complete,
+        reputation: actor.model.reputation.clone(),
+        pareto_snapshot: actor.model.pareto_snapshot.take(),
+        normalization: actor.model.normalizer.stats(),
+        kicks: std::mem::take(&mut actor.model.kicks),
+        best_so_far,
+        config_changes: std::mem::take(&mut actor.model.config_changes),
+        coupling: std::mem::take(&mut actor.model.coupling_samples),
+        violations,
+    };
+
+    let broker = actor.broker().clone();
+    let shutdown = end_tick(actor);
+    Reply::pending(async move {
+        broker
+            .broadcast(TickComplete {
+                result,
+                is_complete: artifact_complete,
+            })
+            .await;
+        if let Some(shutdown) = shutdown {
+            shutdown.await;
+        }
+    })
+}
+
+/// Track a pressure query over all region actors and build its broadcast.
+///
+/// With batched messaging the query asks for summaries only.
+fn begin_pressure_query(model: &mut KernelCoordinatorState, now_ms: u64) -> QueryPressure {
+    let correlation_id = "query".create_type_id::<V7>().to_string();
+    model.pending_pressure_queries.insert(
+        correlation_id.clone(),
+        PendingPressureQueries::new(model.region_actors.len(), now_ms),
+    );
+    let summary_only = model
+        .config
+        .as_ref()
+        .is_some_and(|c| c.messaging.batch_size > 0);
+    QueryPressure {
+        correlation_id,
+        now_ms,
+        summary_only,
+    }
+}
+
+/// Send measurements to their RegionActors, then start the pressure query
+/// once every measurement for `correlation_id` has arrived.
+///
+/// Sends are awaited in order so full region mailboxes slow the coordinator
+/// down instead of piling up spawned tasks.
+fn route_measurements(
+    actor: &mut ManagedActor<Started, KernelCoordinatorState>,
+    correlation_id: &str,
+    routes: Vec<(ActorHandle, MeasurementResult)>,
+) -> HandlerFuture {
+    let complete = actor
+        .model
+        .pending_measurements
+        .get(correlation_id)
+        .is_some_and(|p| p.is_complete());
+
+    let query = if complete {
+        let (_, pending) = actor
+            .model
+            .pending_measurements
+            .remove(correlation_id)
+            .unwrap();
+
+        trace!(
+            correlation_id = %correlation_id,
+            results = pending.received,
+            "Measurement phase complete, starting pressure query"
+        );
+
+        // Start pressure query phase
+        Some(begin_pressure_query(&mut actor.model, pending.now_ms))
+    } else {
+        None
+    };
+
+    // Broadcast QueryPressure to all region actors via broker
+    let broker = actor.broker().clone();
+
+    Reply::pending(async move {
+        for (handle, result) in routes {
+            handle.send(result).await;
+        }
+        if let Some(query) = query {
+            broker.broadcast(query).await;
+        }
+    })
+}
+
+/// Warn the first time the artifact leaves an axis out of its signal deltas.
+///
+/// Patches are rejected while it does, since the axis cannot be checked.
+fn warn_unreported_axes(model: &mut KernelCoordinatorState, rejection: Option<&Rejection>) {
+    let Some(Rejection::MissingSignals(axes)) = rejection else {
+        return;
+    };
+    for axis in axes {
+        if model.unreported_axes.insert(axis.clone()) {
+            warn!(
+                axis = %axis,
+                "Artifact reports no signal delta for this axis; rejecting patches it cannot check"
+            );
+        }
+    }
+}
+
+/// Pressure axes and acceptance mode that validated patches are judged by.
+fn acceptance_rules(config: Option<&KernelConfig>) -> (&[PressureAxisConfig], AcceptanceMode) {
+    config.map_or((&[], AcceptanceMode::Weighted), |c| {
+        (&c.pressure_axes[..], c.selection.acceptance)
+    })
+}
+
+/// Sum per-axis region pressures.
+fn sum_axes<'a>(pressures: impl Iterator<Item = &'a PressureVector>) -> PressureVector {
+    let mut totals = PressureVector::new();
+    for region in pressures {
+        for (axis, value) in region {
+            *totals.entry(axis.clone()).or_default() += value;
+        }
+    }
+    totals
+}
+
+/// Keep the latest per-axis totals and, in Pareto mode, offer the measured
+/// state to the archive.
+///
+/// The artifact source is only read when the state would be admitted.
+fn record_axis_totals(model: &mut KernelCoordinatorState, totals: &PressureTotals) {
+    model.axis_pressures.clone_from(&totals.axis_pressures);
+
+    let pareto = model
+        .config
+        .as_ref()
+        .is_some_and(|c| c.selection.acceptance == AcceptanceMode::Pareto);
+    if !pareto || !model.pareto_archive.admits(&totals.axis_pressures) {
+        return;
+    }
+    let snapshot = ParetoSnapshot {
+        tick: model.current_tick,
+        axis_pressures: totals.axis_pressures.clone(),
+        source: model.artifact.as_ref().and_then(|a| a.source()),
+    };
+    model.pareto_archive.offer(snapshot.clone());
+    model.pareto_snapshot = Some(snapshot);
+}
+
+/// Swap in `config` if it is a safe update of the running one.
+///
+/// Accepted changes are queued for the next TickResult.
+fn update_config(
+    model: &mut KernelCoordinatorState,
+    config: KernelConfig,
+) -> Result<ConfigChange, ConfigError> {
+    let current = model.config.clone().unwrap_or_default();
+    if let Err(e) = check_update(&current, &config) {
+        warn!(error = %e, "Config update rejected");
+        return Err(e);
+    }
+    let change = ConfigChange {
+        tick: model.current_tick,
+        changed: changed_sections(&current, &config),
+        config: config.clone(),
+    };
+    info!(tick = change.tick, changed = ?change.changed, "Config updated");
+    if let Some(spawner) = model.region_spawner.as_mut() {
+        spawner.set_pressure_axes(config.pressure_axes.clone());
+    }
+    model.config = Some(config);
+    model.config_changes.push(change.clone());
+    Ok(change)
+}
+
+/// Mark the current tick complete.
+///
+/// Returns the shutdown to run after `TickComplete`, if one was requested
+/// during the tick.
+fn end_tick(actor: &mut ManagedActor<Started, KernelCoordinatorState>) -> Option<HandlerFuture> {
+    actor.model.tick_in_flight = false;
+    if actor.model.shutdown_requested.is_some() {
+        Some(begin_shutdown(actor))
+    } else {
+        None
+    }
+}
+
+/// Re-send a tick held back while paused, if stepping or running allows it.
+fn release_parked_tick(actor: &mut ManagedActor<Started, KernelCoordinatorState>) -> HandlerFuture {
+    let tick = if actor.model.steps_remaining == Some(0) {
+        None
+    } else {
+        actor.model.parked_tick.take()
+    };
+    let handle = actor.handle().clone();
+    Box::pin(async move {
+        if let Some(tick) = tick {
+            handle.send(tick).await;
+        }
+    })
+}
+
+/// Stop ticking and ask every region for its state.
+///
+/// Finishes at once when there are no regions; otherwise `finish_shutdown`
+/// runs once the last `RegionRetired` arrives.
+fn begin_shutdown(actor: &mut ManagedActor<Started, KernelCoordinatorState>) -> HandlerFuture {
+    let model = &mut actor.model;
+    let Some(request) = model.shutdown_requested.take() else {
+        return Box::pin(async {});
+    };
+    model.stopped = true;
+    model.parked_tick = None;
+
+    let correlation_id = "shutdown".create_type_id::<V7>().to_string();
+    let regions: Vec<(RegionId, ActorHandle)> = model
+        .region_actors
+        .iter()
+        .map(|entry| (entry.key().clone(), entry.value().clone()))
+        .collect();
+    info!(
+        tick = model.current_tick,
+        regions = regions.len(),
+        "Kernel shutting down"
+    );
+    model.shutdown = Some(PendingShutdown {
+        correlation_id: correlation_id.clone(),
+        save_to: request.save_to,
+        awaiting: regions.iter().map(|(id, _)| id.clone()).collect(),
+        states: HashMap::new(),
+    });
+
+    if regions.is_empty() {
+        return finish_shutdown(actor);
+    }
+    Box::pin(async move {
+        for (_, handle) in regions {
+            handle
+                .send(RetireRegion {
+                    correlation_id: correlation_id.clone(),
+                })
+                .await;
+        }
+    })
+}
+
+/// Persist the artifact and region states, stop the region, sensor and claim
+/// actors, and broadcast `KernelComplete`.
+fn finish_shutdown(actor: &mut ManagedActor<Started, KernelCoordinatorState>) -> HandlerFuture {
+    let model = &mut actor.model;
+    let Some(shutdown) = model.shutdown.take() else {
+        return Box::pin(async {});
+    };
+
+    if let Some(dir) = &shutdown.save_to {
+        let source = model.artifact.as_ref().and_then(|a| a.source());
+        if let Err(e) = persist_shutdown_state(dir, source.as_deref(), &shutdown.states) {
+            warn!(path = %dir.display(), error = %e, "Failed to persist kernel state");
+        } else {
+            info!(path = %dir.display(), regions = shutdown.states.len(), "Kernel state saved");
+        }
+    }
+
+    let mut handles: Vec<ActorHandle> = model
+        .region_actors
+        .iter()
+        .map(|entry| entry.value().clone())
+        .collect();
+    model.region_actors.clear();
+    handles.extend(model.registered_sensors.drain().map(|(_, handle)| handle));
+    handles.extend(model.claim_manager.take());
+
+    let complete = KernelComplete {
+        reason: StopReason::Requested,
+        ticks_executed: model.current_tick,
+        final_pressure: model.pressure_history.last().copied().unwrap_or(0.0),
+    };
+    let broker = actor.broker().clone();
+    Box::pin(async move {
+        // Stopping waits for each actor's mailbox to drain, so do it off this handler
+        tokio::spawn(async move {
+            for handle in handles {
+                let _ = handle.stop().await;
+            }
+        });
+        broker.broadcast(complete).await;
+    })
+}
+
+/// Write `artifact.txt` (if the artifact has a source) and `regions.json`.
+fn persist_shutdown_state(
+    dir: &std::path::Path,
+    source: Option<&str>,
+    states: &HashMap<RegionId, RegionState>,
+) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    if let Some(source) = source {
+        std::fs::write(dir.join("artifact.txt"), source)?;
+    }
+    let states: std::collections::BTreeMap<String, &RegionState> = states
+        .iter()
+        .map(|(id, state)| (id.to_string(), state))
+        .collect();
+    let json = serde_json::to_string_pretty(&states).map_err(std::io::Error::other)?;
+    std::fs::write(dir.join("regions.json"), json)
+}
+
+/// Store the latest region pressures, sampling coupling against the previous ones.
+///
+/// Sources are the regions patched since the previous observation: the last
+/// tick's applied patches in tick mode, the current window in async mode.
+/// Observations after a stagnation kick are not sampled, since the kick
+/// changed regions no patch accounts for.
+fn record_region_pressures(model: &mut KernelCoordinatorState, pressures: HashMap<RegionId, f64>) {
+    let is_async = model
+        .config
+        .as_ref()
+        .is_some_and(|c| c.mode == ExecutionMode::Async);
+    let sources = if is_async {
+        let mut regions: Vec<RegionId> = Vec::new();
+        for patch in &model.async_window.applied {
+            if !regions.contains(&patch.region) {
+                regions.push(patch.region.clone());
+            }
+        }
+        Some((model.current_tick, regions))
+    } else {
+        model.coupling_sources.take()
+    };
+    if let Some((tick, sources)) = sources
+        && !sources.is_empty()
+        && model.kicks.is_empty()
+    {
+        model.coupling_samples.extend(coupling::observe(
+            tick,
+            &sources,
+            &model.region_pressures,
+            &pressures,
+        ));
+    }
+    model.region_pressures = pressures;
+}
+
+/// Leave a pheromone for a patch result (see `crate::pheromone`).
+///
+/// An accepted patch also reinforces the accepted deposits its proposer was
+/// shown for the region.
+fn deposit_pheromone(model: &mut KernelCoordinatorState, result: &RegionPatchResult) {
+    let Some(config) = model.config.as_ref().map(|c| &c.pheromones) else {
+        return;
+    };
+    if !config.enabled {
+        return;
+    }
+    let Some(content) = result.new_content.clone() else {
+        return;
+    };
+    let kind = if result.success {
+        for id in model
+            .pheromones_offered
+            .remove(&result.region_id)
+            .unwrap_or_default()
+        {
+            model.pheromones.reinforce(id, config);
+        }
+        PheromoneKind::Accepted
+    } else {
+        PheromoneKind::Rejected
+    };
+    model.pheromones.deposit(
+        result.region_id.clone(),
+        kind,
+        content,
+        result.pressure_delta,
+        model.current_tick,
+        config,
+    );
+}
+
+/// Evaporate all pheromones once per tick.
+fn evaporate_pheromones(model: &mut KernelCoordinatorState) {
+    if let Some(config) = model.config.as_ref().filter(|c| c.pheromones.enabled) {
+        model.pheromones.evaporate(&config.pheromones);
+    }
+}
+
+/// Pheromones to send with a proposal request for `region`, accepted first.
+///
+/// Remembers the accepted ones so a patch accepted there can reinforce them.
+fn offer_pheromones(model: &mut KernelCoordinatorState, region: &RegionId) -> Vec<Pheromone> {
+    let Some(config) = model.config.as_ref().filter(|c| c.pheromones.enabled) else {
+        return Vec::new();
+    };
+    let max = config.pheromones.per_proposal;
+    let accepted = model.pheromones.query(region, PheromoneKind::Accepted, max);
+    model
+        .pheromones_offered
+        .insert(region.clone(), accepted.iter().map(|p| p.id).collect());
+    let rejected = model.pheromones.query(region, PheromoneKind::Rejected, max);
+    accepted.into_iter().chain(rejected).collect()
+}
+
+/// Check accepted patches against the monitored assumptions (see `crate::monitor`).
+fn check_patches(
+    model: &mut KernelCoordinatorState,
+    results: &[RegionPatchResult],
+    now_ms: Option<u64>,
+) {
+    let Some(config) = model.config.as_ref() else {
+        return;
+    };
+    let violations = model.monitor.check_patches(
+        results,
+        now_ms,
+        config.activation.inhibit_ms,
+        &config.monitor,
+    );
+    model.violations.extend(violations);
+}
+
+/// Finish this tick's assumption checks and return its violations.
+///
+/// Must run before `total_pressure` joins the history. Pressure is only
+/// required to be non-increasing under weighted acceptance, and not across
+/// a stagnation kick or a config update.
+fn check_tick(model: &mut KernelCoordinatorState, total_pressure: f64) -> Vec<AssumptionViolation> {
+    let mut violations = std::mem::take(&mut model.violations);
+    let Some(config) = model.config.as_ref() else {
+        return violations;
+    };
+    let greedy = config.selection.acceptance == AcceptanceMode::Weighted;
+    if greedy
+        && model.kicks.is_empty()
+        && model.config_changes.is_empty()
+        && let Some(&previous) = model.pressure_history.last()
+    {
+        violations.extend(monitor::check_pressure(
+            previous,
+            total_pressure,
+            &config.monitor,
+        ));
+    }
+    violations.extend(monitor::check_coupling(
+        &model.coupling_samples,
+        &config.monitor,
+    ));
+    for violation in &violations {
+        warn!(
+            tick = model.current_tick,
+            ?violation,
+            "Convergence assumption violated"
+        );
+    }
+    violations
+}
+
+/// Total pressure recorded for a tick.
+///
+/// The artifact's own total when it reports one, otherwise `region_sum` (the
+/// summed region pressure EMAs). Every tick of a run takes the same branch, so
+/// `pressure_history`, best-so-far, stagnation and the monitors compare like
+/// with like.
+fn tick_total_pressure(model: &KernelCoordinatorState, region_sum: f64) -> f64 {
+    model
+        .artifact
+        .as_ref()
+        .and_then(|a| a.total_pressure())
+        .unwrap_or(region_sum)
+}
+
+/// Remember the artifact if `total_pressure` is the lowest seen so far.
+///
+/// Returns the new best, if this tick set one.
+fn record_best_so_far(
+    model: &mut KernelCoordinatorState,
+    total_pressure: f64,
+) -> Option<BestSoFar> {
+    if model
+        .best_so_far
+        .as_ref()
+        .is_some_and(|best| total_pressure >= best.total_pressure)
+    {
+        return None;
+    }
+    let artifact = model.artifact.as_ref()?;
+    let best = BestSoFar {
+        tick: model.current_tick,
+        total_pressure,
+        source: artifact.source(),
+    };
+    model.best_artifact = artifact.snapshot();
+    model.best_so_far = Some(best.clone());
+    Some(best)
+}
+
+/// Complete a tick in which no region was eligible for proposals.
+fn complete_quiet_tick(
+    actor: &mut ManagedActor<Started, KernelCoordinatorState>,
+    totals: PressureTotals,
+) -> HandlerFuture {
+    let total_pressure = tick_total_pressure(&actor.model, totals.total_pressure);
+
+    // Compute derivatives
+    let velocity = compute_velocity(total_pressure, &actor.model.pressure_history);
+    let acceleration = compute_acceleration(velocity, &actor.model.velocity_history);
+
+    // Update history
+    let violations = check_tick(&mut actor.model, total_pressure);
+    actor.model.pressure_history.push(total_pressure);
+    actor.model.velocity_history.push(velocity);
+    let best_so_far = record_best_so_far(&mut actor.model, total_pressure);
+
+    let result = TickResult {
+        applied: Vec::new(),
+        evaluated: totals.evaluated,
+        skipped: totals.skipped,
+        total_pressure,
+        axis_pressures: actor.model.axis_pressures.clone(),
+        velocity,
+        acceleration,
+        prompt_tokens: 0,
+        completion_tokens: 0,
+        is_complete: false,
+        reputation: actor.model.reputation.clone(),
+        pareto_snapshot: actor.model.pareto_snapshot.take(),
+        normalization: actor.model.normalizer.stats(),
+        kicks: std::mem::take(&mut actor.model.kicks),
+        best_so_far,
+        config_changes: std::mem::take(&mut actor.model.config_changes),
+        coupling: std::mem::take(&mut actor.model.coupling_samples),
+        violations,
+    };
+
+    actor.model.stable_ticks += 1;
+
+    info!(
+        tick = actor.model.current_tick,
+        pressure = format!("{:.2}", total_pressure),
+        velocity = format!("{:.3}", velocity),
+        acceleration = format!("{:.3}", acceleration),
+        applied = 0,
+        "Tick complete - stable (no high-pressure regions)"
+    );
+
+    // Broadcast TickComplete for TickActor to receive
+    let broker = actor.broker().clone();
+    let shutdown = end_tick(actor);
+    Reply::pending(async move {
+        broker
+            .broadcast(TickComplete {
+                result,
+                is_complete: false, // No patches means not complete yet
+            })
+            .await;
+        if let Some(shutdown) = shutdown {
+            shutdown.await;
+        }
+    })
+}
+
+/// Apply a successful, re-evaluated patch result to the artifact.
+///
+/// Marks the region and the regions coupled, adjacent, enclosing or nested
+/// to it dirty for re-measurement. Returns content refreshes for the actors
+/// of enclosing and nested regions, whose content changed along with it.
+fn apply_result_to_artifact(
+    model: &mut KernelCoordinatorState,
+    result: &RegionPatchResult,
+) -> Vec<(ActorHandle, RefreshContent)> {
+    if !result.success {
+        return Vec::new();
+    }
+    let Some(new_content) = &result.new_content else {
+        return Vec::new();
+    };
+
+    // Create a patch to update the artifact
+    let patch = Patch {
+        region: result.region_id.clone(),
+        op: crate::region::PatchOp::Replace(new_content.clone()),
+        rationale: format!("Validated patch (δ={:.3})", result.pressure_delta),
+        expected_delta: HashMap::new(),
+    };
+    apply_validated_patch(model, patch).unwrap_or_default()
+}
+
+/// Apply an accepted patch to the artifact and mark affected regions dirty.
+///
+/// Returns None if the artifact rejects the patch, otherwise the content
+/// refreshes for the actors of enclosing and nested regions (not the patched
+/// region's own actor).
+fn apply_validated_patch(
+    model: &mut KernelCoordinatorState,
+    patch: Patch,
+) -> Option<Vec<(ActorHandle, RefreshContent)>> {
+    let artifact = model.artifact.as_mut()?;
+    let region_id = patch.region.clone();
+    // Coupling can change with the patch, so take it from both sides: a
+    // meeting moved out of a block still affects the block it left
+    let coupled_before = artifact.coupled_regions(&region_id);
+
+    if let Err(e) = artifact.apply_patch(patch.clone()) {
+        warn!(
+            region = %region_id,
+            error = %e,
+            "Failed to apply validated patch to artifact"
+        );
+        return None;
+    }
+
+    // Notify artifact of successful patch (for learning callbacks)
+    artifact.on_patch_applied(&patch);
+
+    model.dirty_regions.insert(region_id.clone());
+    model.dirty_regions.extend(coupled_before);
+    model
+        .dirty_regions
+        .extend(artifact.coupled_regions(&region_id));
+    model
+        .dirty_regions
+        .extend(artifact.neighbors(&region_id).into_iter().map(|(id, _)| id));
+
+    let related = lineage(artifact.as_ref(), &region_id);
+    let refreshes = related
+        .iter()
+        .filter_map(|id| region_refresh(&model.region_actors, artifact.as_ref(), id))
+        .collect();
+    model.dirty_regions.extend(related);
+    Some(refreshes)
+}
+
+/// Content refresh for the actor of `id`, read from `artifact`.
+fn region_refresh(
+    region_actors: &DashMap<RegionId, ActorHandle>,
+    artifact: &dyn Artifact,
+    id: &RegionId,
+) -> Option<(ActorHandle, RefreshContent)> {
+    let handle = region_actors.get(id)?.value().clone();
+    let view = artifact.read_region(id.clone()).ok()?;
+    let refresh = RefreshContent {
+        new_content: view.content,
+        metadata: view.metadata,
+        parent: view.parent,
+        children: view.children,
+    };
+    Some((handle, refresh))
+}
+
+/// The lowest-pressure regions and current totals, for other islands.
+fn export_regions(model: &KernelCoordinatorState, request: &ExportRegions) -> RegionExport {
+    let mut regions = Vec::new();
+    if let Some(artifact) = model.artifact.as_ref() {
+        let mut ranked: Vec<(RegionId, f64)> = artifact
+            .region_ids()
+            .into_iter()
+            .filter_map(|id| {
+                let pressure = *model.region_pressures.get(&id)?;
+                Some((id, pressure))
+            })
+            .collect();
+        ranked.sort_by(|a, b| a.1.total_cmp(&b.1));
+        regions = ranked
+            .into_iter()
+            .take(request.count)
+            .filter_map(|(id, pressure)| {
+                let view = artifact.read_region(id).ok()?;
+                Some(MigrantRegion { view, pressure })
+            })
+            .collect();
+    }
+
+    RegionExport {
+        correlation_id: request.correlation_id.clone(),
+        regions,
+        total_pressure: model.pressure_history.last().copied().unwrap_or(0.0),
+        source: model.artifact.as_ref().and_then(|a| a.source()),
+    }
+}
+
+/// Apply the migrants that improve the artifact; returns them and the refreshes.
+///
+/// A migrant is applied only if its region exists here with different
+/// content and the artifact (plus the pressure axes) accepts replacing it
+/// as an improvement.
+fn import_regions(
+    model: &mut KernelCoordinatorState,
+    migrants: &[MigrantRegion],
+) -> (Vec<RegionId>, Vec<(ActorHandle, RefreshContent)>) {
+    let mut accepted = Vec::new();
+    let mut refreshes = Vec::new();
+
+    for migrant in migrants {
+        let Some(artifact) = model.artifact.as_ref() else {
+            break;
+        };
+        let id = &migrant.view.id;
+        let Ok(current) = artifact.read_region(id.clone()) else {
+            continue;
+        };
+        if current.content == migrant.view.content || frozen_in_lineage(model, id).is_some() {
+            continue;
+        }
+
+        let patch = Patch {
+            region: id.clone(),
+            op: crate::region::PatchOp::Replace(migrant.view.content.clone()),
+            rationale: format!("Migrated region (pressure {:.3})", migrant.pressure),
+            expected_delta: HashMap::new(),
+        };
+        let (axes, mode) = acceptance_rules(model.config.as_ref());
+        let (should_accept, pressure_delta, _) =
+            constraint::evaluate(artifact.as_ref(), axes, mode, &patch);
+        if !should_accept || pressure_delta <= 0.0 {
+            continue;
+        }
+
+        let Some(applied) = apply_validated_patch(model, patch) else {
+            continue;
+        };
+        refreshes.extend(applied);
+        // Unlike a validated patch, the region's own actor has not seen it
+        if let Some(refresh) = model
+            .artifact
+            .as_ref()
+            .and_then(|a| region_refresh(&model.region_actors, a.as_ref(), id))
+        {
+            refreshes.push(refresh);
+        }
+        debug!(region = %id, delta = %pressure_delta, "Migrant region accepted");
+        accepted.push(id.clone());
+    }
+
+    (accepted, refreshes)
+}
+
+/// Update each region's run of high- and low-pressure ticks.
+fn update_pressure_streaks(
+    streaks: &mut HashMap<RegionId, PressureStreak>,
+    config: &GranularityConfig,
+    pressures: impl Iterator<Item = (RegionId, f64)>,
+) {
+    if config.split_after_ticks == 0 && config.merge_after_ticks == 0 {
+        return;
+    }
+    for (region_id, pressure) in pressures {
+        let streak = streaks.entry(region_id).or_default();
+        streak.high = if pressure >= config.split_pressure {
+            streak.high + 1
+        } else {
+            0
+        };
+        streak.low = if pressure <= config.merge_pressure {
+            streak.low + 1
+        } else {
+            0
+        };
+    }
+}
+
+/// Regions due to be split or merged, in artifact order.
+///
+/// A region splits once its pressure has stayed at or above `split_pressure`
+/// for `split_after_ticks` ticks, i.e. patching is not bringing it down. It
+/// merges with the first neighbor under the same parent once both have
+/// stayed at or below `merge_pressure` for `merge_after_ticks` ticks. Each
+/// region takes part in at most one change; regions with a frozen region in
+/// their lineage take part in none.
+fn plan_restructures(
+    artifact: &dyn Artifact,
+    config: &GranularityConfig,
+    streaks: &HashMap<RegionId, PressureStreak>,
+    frozen: &HashSet<RegionId>,
+) -> Vec<Restructure> {
+    let streak = |id: &RegionId| streaks.get(id).copied().unwrap_or_default();
+    let is_frozen = |id: &RegionId| frozen_related(artifact, frozen, id).is_some();
+    let should_split =
+        |id: &RegionId| config.split_after_ticks > 0 && streak(id).high >= config.split_after_ticks;
+    let should_merge =
+        |id: &RegionId| config.merge_after_ticks > 0 && streak(id).low >= config.merge_after_ticks;
+    let parent_of = |id: &RegionId| artifact.read_region(id.clone()).ok().map(|v| v.parent);
+
+    let mut claimed: HashSet<RegionId> = HashSet::new();
+    let mut plans = Vec::new();
+    for id in artifact.region_ids() {
+        if claimed.contains(&id) || is_frozen(&id) {
+            continue;
+        }
+        if should_split(&id) {
+            claimed.insert(id.clone());
+            plans.push(Restructure::Split(id));
+            continue;
+        }
+        if !should_merge(&id) {
+            continue;
+        }
+        let Some(parent) = parent_of(&id) else {
+            continue;
+        };
+        let partner = artifact.neighbors(&id).into_iter().find_map(|(nid, _)| {
+            (nid != id
+                && !claimed.contains(&nid)
+                && should_merge(&nid)
+                && !is_frozen(&nid)
+                && parent_of(&nid).as_ref() == Some(&parent))
+            .then_some(nid)
+        });
+        if let Some(partner) = partner {
+            claimed.insert(id.clone());
+            claimed.insert(partner.clone());
+            plans.push(Restructure::Merge(vec![id, partner]));
+        }
+    }
+    plans
+}
+
+/// Apply due splits and merges to the artifact and ask the retired regions
+/// for their state. Returns `None` (and the tick proceeds) if nothing changed.
+fn begin_restructure(
+    actor: &mut ManagedActor<Started, KernelCoordinatorState>,
+    tick: Tick,
+) -> Option<HandlerFuture> {
+    let model = &mut actor.model;
+    let next_tick = model.current_tick + 1;
+    let (Some(config), Some(artifact)) = (model.config.as_ref(), model.artifact.as_mut()) else {
+        return None;
+    };
+    if config.mode != ExecutionMode::Tick
+        || model.region_spawner.is_none()
+        || model.restructured_tick == next_tick
+    {
+        return None;
+    }
+
+    let plans = plan_restructures(
+        artifact.as_ref(),
+        &config.granularity,
+        &model.pressure_streaks,
+        &model.frozen_regions,
+    );
+    let mut created: Vec<(RegionId, Vec<RegionId>)> = Vec::new();
+    for plan in plans {
+        match plan {
+            Restructure::Split(id) => {
+                let new_ids = artifact.split_region(&id).unwrap_or_default();
+                created.extend(new_ids.into_iter().map(|new_id| (new_id, vec![id.clone()])));
+            }
+            Restructure::Merge(ids) => {
+                if let Some(new_id) = artifact.merge_regions(&ids) {
+                    created.push((new_id, ids));
+                }
+            }
+        }
+    }
+    if created.is_empty() {
+        return None;
+    }
+
+    let mut retired: Vec<RegionId> = Vec::new();
+    for (_, sources) in &created {
+        for id in sources {
+            if !retired.contains(id) {
+                retired.push(id.clone());
+            }
+        }
+    }
+    for id in &retired {
+        model.pressure_streaks.remove(id);
+    }
+
+    let correlation_id = "restructure".create_type_id::<V7>().to_string();
+    let retirements: Vec<(RegionId, ActorHandle)> = retired
+        .iter()
+        .filter_map(|id| Some((id.clone(), model.region_actors.get(id)?.value().clone())))
+        .collect();
+
+    debug!(
+        retired = retired.len(),
+        created = created.len(),
+        "Restructuring regions"
+    );
+
+    model.restructured_tick = next_tick;
+    model.restructure = Some(PendingRestructure {
+        correlation_id: correlation_id.clone(),
+        retired,
+        awaiting: retirements.iter().map(|(id, _)| id.clone()).collect(),
+        states: HashMap::new(),
+        created,
+        tick,
+    });
+
+    if retirements.is_empty() {
+        return Some(finish_restructure(actor));
+    }
+    Some(Box::pin(async move {
+        for (_, handle) in retirements {
+            handle
+                .send(RetireRegion {
+                    correlation_id: correlation_id.clone(),
+                })
+                .await;
+        }
+    }))
+}
+
+/// Stop the retired actors and spawn actors for the new regions, carrying
+/// over the retired regions' state. Sends `RegionsRestructured` when done.
+fn finish_restructure(actor: &mut ManagedActor<Started, KernelCoordinatorState>) -> HandlerFuture {
+    let model = &actor.model;
+    let (Some(restructure), Some(artifact), Some(spawner)) = (
+        model.restructure.as_ref(),
+        model.artifact.as_ref(),
+        model.region_spawner.clone(),
+    ) else {
+        return Reply::ready();
+    };
+
+    let now_ms = restructure.tick.now_ms;
+    // The held tick has not started yet, so decay was last applied now
+    let decay_tick = model.current_tick;
+    let spawns: Vec<(RegionView, RegionState)> = restructure
+        .created
+        .iter()
+        .filter_map(|(id, sources)| {
+            let view = artifact.read_region(id.clone()).ok()?;
+            let states: Vec<RegionState> = sources
+                .iter()
+                .filter_map(|source| restructure.states.get(source).cloned())
+                .collect();
+            let state = if states.is_empty() {
+                RegionState::new(now_ms)
+            } else {
+                RegionState::merged(&states)
+            };
+            Some((view, state))
+        })
+        .collect();
+
+    // Enclosing regions whose children changed
+    let parents: HashSet<RegionId> = spawns
+        .iter()
+        .filter_map(|(view, _)| view.parent.clone())
+        .filter(|id| !restructure.retired.contains(id))
+        .collect();
+    let refreshes: Vec<(ActorHandle, RefreshContent)> = parents
+        .into_iter()
+        .filter_map(|id| {
+            let handle = model.region_actors.get(&id)?.value().clone();
+            let view = artifact.read_region(id).ok()?;
+            let refresh = RefreshContent {
+                new_content: view.content,
+                metadata: view.metadata,
+                parent: view.parent,
+                children: view.children,
+            };
+            Some((handle, refresh))
+        })
+        .collect();
+
+    let retired = restructure.retired.clone();
+    let retired_handles: Vec<ActorHandle> = retired
+        .iter()
+        .filter_map(|id| Some(model.region_actors.get(id)?.value().clone()))
+        .collect();
+    let coordinator = actor.handle().clone();
+
+    Box::pin(async move {
+        for (handle, refresh) in refreshes {
+            handle.send(refresh).await;
+        }
+        // Actor spawn futures need not be Sync, so drive them on their own task
+        tokio::spawn(async move {
+            for handle in retired_handles {
+                let _ = handle.stop().await;
+            }
+            let mut actors = HashMap::new();
+            for (view, state) in spawns {
+                let region_id = view.id.clone();
+                actors.insert(
+                    region_id,
+                    spawner.spawn(view, Some(state), decay_tick).await,
+                );
+            }
+            coordinator
+                .send(RegionsRestructured { retired, actors })
+                .await;
+        });
+    })
+}
+
+/// Kick regions once total pressure has stagnated (see `crate::stagnation`).
+///
+/// Feeds the previous tick's pressure to the tracker. On a kick, perturbs
+/// the artifact or restores the best-so-far snapshot, marks changed regions dirty, queues exploration
+/// seeds, and returns the content refreshes and `KickRegion` messages to send
+/// before the tick measures anything.
+fn kick_if_stagnant(model: &mut KernelCoordinatorState, now_ms: u64) -> Option<HandlerFuture> {
+    let config = model.config.as_ref()?.stagnation.clone();
+    if config.patience == 0 {
+        return None;
+    }
+    let pressure = *model.pressure_history.last()?;
+    if model.stagnation.observe(pressure, &config) != Progress::Stagnant {
+        return None;
+    }
+
+    let tick = model.current_tick;
+    let seed = model.stagnation.next_seed(tick);
+
+    // Restart only if the best snapshot still has the current regions and
+    // leaves frozen ones as they are
+    let unchanged = |a: &dyn Artifact, id: &RegionId| {
+        let content = |a: &dyn Artifact| a.read_region(id.clone()).ok().map(|v| v.content);
+        model
+            .artifact
+            .as_ref()
+            .is_some_and(|current| content(current.as_ref()) == content(a))
+    };
+    let restored = match (config.response, &model.best_artifact) {
+        (StagnationResponse::Restart, Some(best)) => best.snapshot().filter(|a| {
+            let ids = a.region_ids();
+            ids.len() == model.region_actors.len()
+                && ids.iter().all(|id| model.region_actors.contains_key(id))
+                && model
+                    .frozen_regions
+                    .iter()
+                    .all(|id| unchanged(a.as_ref(), id))
+        }),
+        _ => None,
+    };
+
+    let (response, regions, perturbed) = match restored {
+        Some(mut restored) => {
+            restored.on_restored();
+            let regions: Vec<RegionId> = restored
+                .region_ids()
+                .into_iter()
+                .filter(|id| !model.frozen_regions.contains(id))
+                .collect();
+            model.artifact = Some(restored);
+            (StagnationResponse::Restart, regions, Vec::new())
+        }
+        None => {
+            // Frozen regions, and regions whose change would reach one, sit out
+            let candidates: Vec<RegionId> = model
+                .artifact
+                .as_ref()?
+                .region_ids()
+                .into_iter()
+                .filter(|id| frozen_in_lineage(model, id).is_none())
+                .collect();
+            let regions =
+                highest_pressure(&candidates, &model.region_pressures, config.perturb_regions);
+            let artifact = model.artifact.as_mut()?;
+            let mut perturbed = Vec::new();
+            for (i, id) in regions.iter().enumerate() {
+                let Some(patch) = artifact.perturb_region(id, seed.wrapping_add(i as u64)) else {
+                    continue;
+                };
+                model.dirty_regions.extend(artifact.coupled_regions(id));
+                match artifact.apply_patch(patch) {
+                    Ok(()) => perturbed.push(id.clone()),
+                    Err(e) => warn!(region = %id, error = %e, "Failed to apply perturbation"),
+                }
+            }
+            (StagnationResponse::Perturb, regions, perturbed)
+        }
+    };
+
+    // Re-measure changed regions and refresh their actors' content
+    let artifact = model.artifact.as_ref()?;
+    let mut changed: HashSet<RegionId> = perturbed.iter().cloned().collect();
+    for id in &perturbed {
+        changed.extend(lineage(artifact.as_ref(), id));
+    }
+    if response == StagnationResponse::Restart {
+        changed.extend(regions.iter().cloned());
+    }
+    let mut refreshes = Vec::new();
+    for id in artifact.region_ids() {
+        if !changed.contains(&id) {
+            continue;
+        }
+        model.dirty_regions.extend(artifact.coupled_regions(&id));
+        model
+            .dirty_regions
+            .extend(artifact.neighbors(&id).into_iter().map(|(nid, _)| nid));
+        refreshes.extend(region_refresh(&model.region_actors, artifact.as_ref(), &id));
+    }
+    model.dirty_regions.extend(changed);
+    // Kicked regions are freed from inhibition
+    model.monitor.release(&regions);
+
+    let mut handles = Vec::with_capacity(regions.len());
+    for (i, id) in regions.iter().enumerate() {
+        model
+            .explore_seeds
+            .insert(id.clone(), seed.wrapping_add(i as u64));
+        if let Some(handle) = model.region_actors.get(id) {
+            handles.push(handle.value().clone());
+        }
+    }
+
+    let best_pressure = model.stagnation.best().unwrap_or(pressure);
+    info!(
+        tick,
+        ?response,
+        regions = regions.len(),
+        perturbed = perturbed.len(),
+        best_pressure = %best_pressure,
+        pressure = %pressure,
+        "Pressure stagnated, kicking regions"
+    );
+    model.kicks.push(StagnationKick {
+        tick,
+        response,
+        regions,
+        perturbed,
+        best_pressure,
+        seed,
+    });
+
+    Some(Box::pin(async move {
+        send_refreshes(refreshes).await;
+        for handle in handles {
+            handle.send(KickRegion { now_ms }).await;
+        }
+    }))
+}
+
+/// Send content refreshes in order.
+fn send_refreshes(refreshes: Vec<(ActorHandle, RefreshContent)>) -> HandlerFuture {
+    Box::pin(async move {
+        for (handle, refresh) in refreshes {
+            handle.send(refresh).await;
+        }
+    })
+}
+
+/// Track which regions a steering update freezes or unfreezes.
+fn steer_frozen(frozen: &mut HashSet<RegionId>, msg: &SteerRegions) {
+    match msg.update {
+        SteeringUpdate::Freeze { .. } => frozen.extend(msg.regions.iter().cloned()),
+        SteeringUpdate::Unfreeze => {
+            for id in &msg.regions {
+                frozen.remove(id);
+            }
+        }
+        SteeringUpdate::Bias { .. } => {}
+    }
+}
+
+/// A frozen region that a patch to `id` would change: `id` itself or a
+/// region enclosing or nested inside it.
+fn frozen_in_lineage(model: &KernelCoordinatorState, id: &RegionId) -> Option<RegionId> {
+    if model.frozen_regions.contains(id) {
+        return Some(id.clone());
+    }
+    let artifact = model.artifact.as_ref()?;
+    frozen_related(artifact.as_ref(), &model.frozen_regions, id)
+}
+
+/// `id` or a region of its lineage, if any of them is in `frozen`.
+fn frozen_related(
+    artifact: &dyn Artifact,
+    frozen: &HashSet<RegionId>,
+    id: &RegionId,
+) -> Option<RegionId> {
+    if frozen.is_empty() {
+        return None;
+    }
+    if frozen.contains(id) {
+        return Some(id.clone());
+    }
+    lineage(artifact, id)
+        .into_iter()
+        .find(|r| frozen.contains(r))
+}
+
+/// All regions enclosing `id` or nested inside it (excluding `id`).
+fn lineage(artifact: &dyn Artifact, id: &RegionId) -> HashSet<RegionId> {
+    let mut related = HashSet::new();
+    let Ok(view) = artifact.read_region(id.clone()) else {
+        return related;
+    };
+
+    // Ancestors
+    let mut parent = view.parent.clone();
+    while let Some(pid) = parent {
+        if pid == *id || !related.insert(pid.clone()) {
+            break;
+        }
+        parent = artifact.read_region(pid).ok().and_then(|v| v.parent);
+    }
+
+    // Descendants
+    let mut stack = view.children;
+    while let Some(cid) = stack.pop() {
+        if cid != *id
+            && related.insert(cid.clone())
+            && let Ok(child) = artifact.read_region(cid)
+        {
+            stack.extend(child.children);
+        }
+    }
+    related
+}
+
+/// Keep the highest-scored patches such that no two target regions where one
+/// encloses the other.
+fn drop_nested_conflicts(
+    artifact: &dyn Artifact,
+    mut patches: Vec<(f64, Patch, PatchOrigin)>,
+) -> Vec<(f64, Patch, PatchOrigin)> {
+    patches.sort_by(|a, b| b.0.total_cmp(&a.0));
+    let mut claimed: HashSet<RegionId> = HashSet::new();
+    patches
+        .into_iter()
+        .filter(|(_, patch, _)| {
+            if claimed.contains(&patch.region) {
+                debug!(region = %patch.region, "Dropping patch nested in a better-scored patch");
+                return false;
+            }
+            claimed.insert(patch.region.clone());
+            claimed.extend(lineage(artifact, &patch.region));
+            true
+        })
+        .collect()
+}
+
+/// Effective pressure of each region, used to select regions for proposals.
+///
+/// `pressures` holds each region's own pressure, `None` if nothing measured
+/// it. A measured region's pressure already covers the regions nested inside
+/// it and is used as is; an unmeasured one takes the pressure of the
+/// outermost measured regions inside it. With diffusion enabled, each region
+/// then gains `diffusion.rate` times its neighbors' weighted pressure (see
+/// `Artifact::neighbors`).
+fn effective_pressures(
+    model: &KernelCoordinatorState,
+    pressures: impl Iterator<Item = (RegionId, Option<f64>, Option<RegionId>)>,
+) -> HashMap<RegionId, f64> {
+    let mut parents: HashMap<RegionId, RegionId> = HashMap::new();
+    let mut own: HashMap<RegionId, Option<f64>> = HashMap::new();
+    for (id, pressure, parent) in pressures {
+        if let Some(parent) = parent {
+            parents.insert(id.clone(), parent);
+        }
+        own.insert(id, pressure);
+    }
+
+    // Add each measured region's pressure to the unmeasured regions enclosing
+    // it, up to the first measured one
+    let mut aggregated: HashMap<RegionId, f64> = own
+        .iter()
+        .map(|(id, pressure)| (id.clone(), pressure.unwrap_or(0.0)))
+        .collect();
+    for (id, pressure) in &own {
+        let Some(pressure) = pressure else {
+            continue;
+        };
+        let mut current = id;
+        for _ in 0..parents.len() {
+            let Some(parent) = parents.get(current) else {
+                break;
+            };
+            match (own.get(parent), aggregated.get_mut(parent)) {
+                (Some(None), Some(total)) => *total += pressure,
+                _ => break,
+            }
+            current = parent;
+        }
+    }
+    let own = aggregated;
+
+    let rate = model.config.as_ref().map_or(0.0, |c| c.diffusion.rate);
+    let Some(artifact) = model.artifact.as_ref().filter(|_| rate > 0.0) else {
+        return own;
+    };
+
+    own.iter()
+        .map(|(id, pressure)| {
+            let from_neighbors: f64 = artifact
+                .neighbors(id)
+                .iter()
+                .filter_map(|(nid, weight)| Some(weight * own.get(nid)?))
+                .sum();
+            (id.clone(), pressure + rate * from_neighbors)
+        })
+        .collect()
+}
+
+/// Measured regions not nested inside another measured region.
+///
+/// Each item is a region, its parent, and whether it has been measured. A
+/// measured region already covers the regions inside it, so only these are
+/// summed into tick totals.
+fn outermost_measured<'a>(
+    regions: impl Iterator<Item = (&'a RegionId, Option<&'a RegionId>, bool)>,
+) -> HashSet<RegionId> {
+    let mut parents: HashMap<&RegionId, &RegionId> = HashMap::new();
+    let mut measured: HashSet<&RegionId> = HashSet::new();
+    for (id, parent, is_measured) in regions {
+        if let Some(parent) = parent {
+            parents.insert(id, parent);
+        }
+        if is_measured {
+            measured.insert(id);
+        }
+    }
+
+    measured
+        .iter()
+        .filter(|id| {
+            let mut current = **id;
+            for _ in 0..parents.len() {
+                let Some(parent) = parents.get(current) else {
+                    break;
+                };
+                if measured.contains(parent) {
+                    return false;
+                }
+                current = parent;
+            }
+            true
+        })
+        .map(|id| (*id).clone())
+        .collect()
+}
+
+/// The patch reported in `TickResult::applied` for a successful result.
+fn applied_patch(result: &RegionPatchResult) -> Patch {
+    Patch {
+        region: result.region_id.clone(),
+        op: crate::region::PatchOp::Replace(result.new_content.clone().unwrap_or_default()),
+        rationale: format!("δ={:.3}", result.pressure_delta),
+        expected_delta: HashMap::new(),
+    }
+}
+
+/// Remove a patch actor from round-robin dispatch.
+///
+/// Returns the actor's handle if it was registered.
+fn deregister_patch_actor(
+    model: &mut KernelCoordinatorState,
+    actor_ern: &str,
+) -> Option<ActorHandle> {
+    if !model.registered_patch_actors.remove(actor_ern) {
+        return None;
+    }
+    model.patch_actor_last_seen.remove(actor_ern);
+    let idx = model
+        .patch_actor_handles
+        .iter()
+        .position(|(ern, _)| ern == actor_ern)?;
+    Some(model.patch_actor_handles.remove(idx).1)
+}
+
+/// Reputation weight of the patch actor registered as `ern` (1.0 until it
+/// has proposed something).
+fn patch_actor_weight(model: &KernelCoordinatorState, ern: &str) -> f64 {
+    model
+        .patch_actor_names
+        .get(ern)
+        .map_or(1.0, |name| weight_of(&model.reputation, name))
+}
+
+/// Pick the registered patch actor with the fewest outstanding requests,
+/// skipping `exclude`. With weighted dispatch, load is divided by reputation.
+/// Ties go to actors in turn, so an idle actor cannot take every request.
+fn least_loaded_patch_actor(
+    model: &KernelCoordinatorState,
+    exclude: &str,
+) -> Option<(String, ActorHandle)> {
+    let mut load: HashMap<String, usize> = HashMap::new();
+    for pending in model.pending_proposals.iter() {
+        for outstanding in pending.outstanding.values() {
+            *load.entry(outstanding.actor_ern.clone()).or_default() += 1;
+        }
+    }
+
+    let weighted = model
+        .config
+        .as_ref()
+        .is_some_and(|c| c.reputation.weighted_dispatch);
+    let cost = |ern: &String| {
+        let load = load.get(ern).copied().unwrap_or(0) as f64;
+        if weighted {
+            (load + 1.0) / patch_actor_weight(model, ern)
+        } else {
+            load
+        }
+    };
+
+    let handles = &model.patch_actor_handles;
+    let start = model.dispatch_turn % handles.len().max(1);
+    handles[start..]
+        .iter()
+        .chain(&handles[..start])
+        .filter(|(ern, _)| ern != exclude)
+        .min_by(|a, b| cost(&a.0).total_cmp(&cost(&b.0)))
+        .cloned()
+}
+
+/// Hand outstanding proposal requests matching `should_move` to other actors.
+///
+/// Regions that no other actor can take are answered with an empty proposal
+/// so the tick can still finish. Returns the requests to send and the
+/// correlation IDs whose proposal phase is now complete.
+fn reassign_outstanding(
+    model: &mut KernelCoordinatorState,
+    should_move: impl Fn(&OutstandingProposal) -> bool,
+) -> (Vec<(ActorHandle, ProposeForRegion)>, Vec<String>) {
+    let mut sends = Vec::new();
+    let mut completed = Vec::new();
+
+    let correlation_ids: Vec<String> = model
+        .pending_proposals
+        .iter()
+        .map(|e| e.key().clone())
+        .collect();
+
+    for correlation_id in correlation_ids {
+        let moving: Vec<(RegionId, OutstandingProposal)> =
+            match model.pending_proposals.get(&correlation_id) {
+                Some(pending) => pending
+                    .outstanding
+                    .iter()
+                    .filter(|(_, o)| should_move(o))
+                    .map(|(rid, o)| (rid.clone(), o.clone()))
+                    .collect(),
+                None => continue,
+            };
+        if moving.is_empty() {
+            continue;
+        }
+
+        for (region_id, previous) in moving {
+            let target = least_loaded_patch_actor(model, &previous.actor_ern);
+            let Some(mut pending) = model.pending_proposals.get_mut(&correlation_id) else {
+                continue;
+            };
+
+            match target {
+                Some((actor_ern, handle)) => {
+                    debug!(
+                        region = %region_id,
+                        from = %previous.actor_ern,
+                        to = %actor_ern,
+                        "Re-dispatching proposal request"
+                    );
+                    pending.outstanding.insert(
+                        region_id,
+                        OutstandingProposal {
+                            actor_ern,
+                            dispatched_at: Instant::now(),
+                            request: previous.request.clone(),
+                        },
+                    );
+                    sends.push((handle, previous.request));
+                }
+                None => {
+                    debug!(
+                        region = %region_id,
+                        from = %previous.actor_ern,
+                        "No patch actor available, abandoning proposal request"
+                    );
+                    pending.outstanding.remove(&region_id);
+                    pending.proposals.push(PatchProposal {
+                        correlation_id: correlation_id.clone(),
+                        region_id,
+                        actor_name: "KernelCoordinator".to_string(),
+                        actor_ern: "KernelCoordinator".to_string(),
+                        model: None,
+                        sampling_band: None,
+                        patches: Vec::new(),
+                        prompt_tokens: 0,
+                        completion_tokens: 0,
+                    });
+                }
+            }
+        }
+
+        if model
+            .pending_proposals
+            .get(&correlation_id)
+            .is_some_and(|p| p.is_complete())
+        {
+            completed.push(correlation_id);
+        }
+    }
+
+    (sends, completed)
+}
+
+/// Send re-dispatched requests, then finish every completed proposal phase.
+fn finish_completed_proposals(
+    actor: &mut ManagedActor<Started, KernelCoordinatorState>,
+    sends: Vec<(ActorHandle, ProposeForRegion)>,
+    completed: Vec<String>,
+) -> HandlerFuture {
+    let mut follow_ups = Vec::new();
+    for correlation_id in completed {
+        if let Some((_, pending)) = actor.model.pending_proposals.remove(&correlation_id) {
+            follow_ups.push(finish_proposal_phase(actor, pending));
+        }
+    }
+
+    Reply::pending(async move {
+        for (handle, msg) in sends {
+            handle.send(msg).await;
+        }
+        for follow_up in follow_ups {
+            follow_up.await;
+        }
+    })
+}
+
+/// Schedule a `SweepPatchActors` message if liveness or proposal timeouts are enabled.
+fn schedule_sweep(actor: &mut ManagedActor<Started, KernelCoordinatorState>) {
+    if actor.model.sweep_scheduled {
+        return;
+    }
+    let Some(config) = actor.model.config.as_ref() else {
+        return;
+    };
+
+    // Sweep at half the shortest timeout so deadlines are missed by at most 50%
+    let Some(timeout_ms) = [
+        config.membership.heartbeat_timeout_ms,
+        config.membership.proposal_timeout_ms,
+    ]
+    .into_iter()
+    .filter(|ms| *ms > 0)
+    .min() else {
+        return;
+    };
+
+    actor.model.sweep_scheduled = true;
+    let handle = actor.handle().clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis((timeout_ms / 2).max(1))).await;
+        handle.send(SweepPatchActors).await;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::region::RegionState;
+    use uuid::Uuid;
+
+    fn test_region_id(name: &str) -> RegionId {
+        let v5_uuid = Uuid::new_v5(&Uuid::NAMESPACE_DNS, name.as_bytes());
+        let prefix = TypeIdPrefix::try_from("test").expect("test is valid prefix");
+        MagicTypeId::new(prefix, TypeIdSuffix::from(v5_uuid))
+    }
+
+    fn register(model: &mut KernelCoordinatorState, ern: &str) {
+        model.registered_patch_actors.insert(ern.to_string());
+        model
+            .patch_actor_handles
+            .push((ern.to_string(), ActorHandle::default()));
+        model
+            .patch_actor_last_seen
+            .insert(ern.to_string(), Instant::now());
+    }
+
+    fn pending_for(region_id: &RegionId, actor_ern: &str) -> PendingProposals {
+        let view = RegionView {
+            id: region_id.clone(),
+            kind: "test".to_string(),
+            content: String::new(),
+            metadata: HashMap::new(),
+            parent: None,
+            children: Vec::new(),
+        };
+        let request = ProposeForRegion {
+            correlation_id: "propose-1".to_string(),
+            region_id: region_id.clone(),
+            region_view: view.clone(),
+            signals: HashMap::new(),
+            pressures: HashMap::new(),
+            state: RegionState::new(0),
+            claim_manager: None,
+            explore_seed: None,
+            pheromones: Vec::new(),
+        };
+        let mut pending =
+            PendingProposals::new(1, vec![(region_id.clone(), view, HashMap::new())], 0, 1.0);
+        pending.outstanding.insert(
+            region_id.clone(),
+            OutstandingProposal {
+                actor_ern: actor_ern.to_string(),
+                dispatched_at: Instant::now(),
+                request,
+            },
+        );
+        pending
+    }
+
+    #[test]
+    fn test_departed_actor_work_moves_to_survivor() {
+        let mut model = KernelCoordinatorState::default();
+        register(&mut model, "actor-a");
+        register(&mut model, "actor-b");
+
+        let region = test_region_id("r1");
+        model
+            .pending_proposals
+            .insert("propose-1".to_string(), pending_for(&region, "actor-a"));
+
+        assert!(deregister_patch_actor(&mut model, "actor-a").is_some());
+        assert!(deregister_patch_actor(&mut model, "actor-a").is_none());
+
+        let (sends, completed) = reassign_outstanding(&mut model, |o| o.actor_ern == "actor-a");
+        assert_eq!(sends.len(), 1);
+        assert!(completed.is_empty());
+
+        let pending = model.pending_proposals.get("propose-1").unwrap();
+        assert_eq!(pending.outstanding[&region].actor_ern, "actor-b");
+    }
+
+    #[test]
+    fn test_last_actor_leaving_abandons_outstanding_work() {
+        let mut model = KernelCoordinatorState::default();
+        register(&mut model, "actor-a");
+
+        let region = test_region_id("r1");
+        model
+            .pending_proposals
+            .insert("propose-1".to_string(), pending_for(&region, "actor-a"));
+
+        deregister_patch_actor(&mut model, "actor-a");
+        let (sends, completed) = reassign_outstanding(&mut model, |o| o.actor_ern == "actor-a");
+
+        assert!(sends.is_empty());
+        assert_eq!(completed, vec!["propose-1".to_string()]);
+        let pending = model.pending_proposals.get("propose-1").unwrap();
+        assert!(pending.is_complete());
+        assert_eq!(pending.proposals.len(), 1);
+        assert!(pending.proposals[0].patches.is_empty());
+    }
+
+    #[test]
+    fn test_late_reply_from_replaced_actor_is_ignored() {
+        let region = test_region_id("r1");
+        let mut pending = pending_for(&region, "actor-b");
+        let reply = |actor_ern: &str| PatchProposal {
+            correlation_id: "propose-1".to_string(),
+            region_id: region.clone(),
+            actor_name: actor_ern.to_string(),
+            actor_ern: actor_ern.to_string(),
+            model: None,
+            sampling_band: None,
+            patches: Vec::new(),
+            prompt_tokens: 0,
+            completion_tokens: 0,
+        };
+
+        // actor-a timed out and its request went to actor-b
+        assert!(take_outstanding(&mut pending, &reply("actor-a")).is_none());
+        assert!(!pending.is_complete());
+
+        let taken = take_outstanding(&mut pending, &reply("actor-b")).unwrap();
+        assert_eq!(taken.actor_ern, "actor-b");
+        assert!(pending.is_complete());
+        assert!(take_outstanding(&mut pending, &reply("actor-b")).is_none());
+    }
+
+    /// Artifact where every region is coupled to `coupled`.
+    struct CoupledArtifact {
+        regions: Vec<RegionId>,
+        coupled: RegionId,
+    }
+
+    impl Artifact for CoupledArtifact {
+        fn region_ids(&self) -> Vec<RegionId> {
+            self.regions.clone()
+        }
+
+        fn read_region(&self, id: RegionId) -> anyhow::Result<RegionView> {
+            Ok(RegionView {
+                id,
+                kind: "test".to_string(),
+                content: String::new(),
+                metadata: HashMap::new(),
+                parent: None,
+                children: Vec::new(),
+            })
+        }
+
+        fn apply_patch(&mut self, _patch: Patch) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn coupled_regions(&self, _id: &RegionId) -> Vec<RegionId> {
+            vec![self.coupled.clone()]
+        }
+
+        fn neighbors(&self, id: &RegionId) -> Vec<(RegionId, f64)> {
+            if *id == self.coupled {
+                Vec::new()
+            } else {
+                vec![(self.coupled.clone(), 0.5)]
+            }
+        }
+    }
+
+    fn patch_result(region_id: &RegionId, success: bool) -> RegionPatchResult {
+        RegionPatchResult {
+            correlation_id: "patch-1".to_string(),
+            region_id: region_id.clone(),
+            success,
+            pressure_delta: 0.5,
+            expected_delta: 0.5,
+            origin: PatchOrigin::default(),
+            new_content: Some("patched".to_string()),
+            rejection: None,
+        }
+    }
+
+    #[test]
+    fn test_applied_patch_marks_region_and_coupled_regions_dirty() {
+        let (r1, r2, r3) = (
+            test_region_id("r1"),
+            test_region_id("r2"),
+            test_region_id("r3"),
+        );
+        let mut model = KernelCoordinatorState {
+            artifact: Some(Box::new(CoupledArtifact {
+                regions: vec![r1.clone(), r2.clone(), r3.clone()],
+                coupled: r2.clone(),
+            })),
+            ..Default::default()
+        };
+
+        apply_result_to_artifact(&mut model, &patch_result(&r3, false));
+        assert!(model.dirty_regions.is_empty());
+
+        apply_result_to_artifact(&mut model, &patch_result(&r1, true));
+        assert_eq!(model.dirty_regions, HashSet::from([r1, r2]));
+    }
+
+    /// Blocks holding meetings, listed as comma-separated IDs; a block is
+    /// coupled to every other block that holds one of its meetings.
+    struct MeetingArtifact {
+        blocks: Vec<(RegionId, HashSet<u64>)>,
+    }
+
+    impl MeetingArtifact {
+        fn meetings(&self, id: &RegionId) -> Option<&HashSet<u64>> {
+            self.blocks.iter().find(|(b, _)| b == id).map(|(_, m)| m)
+        }
+    }
+
+    impl Artifact for MeetingArtifact {
+        fn region_ids(&self) -> Vec<RegionId> {
+            self.blocks.iter().map(|(id, _)| id.clone()).collect()
+        }
+
+        fn read_region(&self, id: RegionId) -> anyhow::Result<RegionView> {
+            let mut meetings: Vec<String> = self
+                .meetings(&id)
+                .into_iter()
+                .flatten()
+                .map(|m| m.to_string())
+                .collect();
+            meetings.sort();
+            Ok(RegionView {
+                id,
+                kind: "test".to_string(),
+                content: meetings.join(","),
+                metadata: HashMap::new(),
+                parent: None,
+                children: Vec::new(),
+            })
+        }
+
+        fn apply_patch(&mut self, patch: Patch) -> anyhow::Result<()> {
+            let crate::region::PatchOp::Replace(content) = patch.op else {
+                anyhow::bail!("blocks can only be replaced");
+            };
+            let (_, meetings) = self
+                .blocks
+                .iter_mut()
+                .find(|(b, _)| *b == patch.region)
+                .ok_or_else(|| anyhow::anyhow!("no such block"))?;
+            *meetings = content.split(',').filter_map(|m| m.parse().ok()).collect();
+            Ok(())
+        }
+
+        fn coupled_regions(&self, id: &RegionId) -> Vec<RegionId> {
+            let Some(meetings) = self.meetings(id) else {
+                return Vec::new();
+            };
+            self.blocks
+                .iter()
+                .filter(|(b, m)| b != id && !m.is_disjoint(meetings))
+                .map(|(b, _)| b.clone())
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_moved_meeting_marks_both_blocks_dirty() {
+        let (b1, b2, b3) = (
+            test_region_id("b1"),
+            test_region_id("b2"),
+            test_region_id("b3"),
+        );
+        let mut model = KernelCoordinatorState {
+            artifact: Some(Box::new(MeetingArtifact {
+                blocks: vec![
+                    (b1.clone(), HashSet::from([7])),
+                    (b2.clone(), HashSet::new()),
+                    (b3.clone(), HashSet::from([8])),
+                ],
+            })),
+            ..Default::default()
+        };
+        let mut result = |region: &RegionId, content: &str| {
+            model.dirty_regions.clear();
+            let result = RegionPatchResult {
+                new_content: Some(content.to_string()),
+                ..patch_result(region, true)
+            };
+            apply_result_to_artifact(&mut model, &result);
+            model.dirty_regions.clone()
+        };
+
+        // Meeting 7 is placed in b2 while b1 still holds it, then b1 lets
+        // go of it: the coupled block is dirty on both sides of the move
+        assert_eq!(result(&b2, "7"), HashSet::from([b1.clone(), b2.clone()]));
+        assert_eq!(result(&b1, ""), HashSet::from([b1.clone(), b2.clone()]));
+        assert_eq!(result(&b3, "8"), HashSet::from([b3]));
+    }
+
+    #[test]
+    fn test_stagnation_kicks_highest_pressure_regions() {
+        let (r1, r2, r3) = (
+            test_region_id("r1"),
+            test_region_id("r2"),
+            test_region_id("r3"),
+        );
+        let mut config = KernelConfig::default();
+        config.stagnation.patience = 2;
+        config.stagnation.perturb_regions = 1;
+        let mut model = KernelCoordinatorState {
+            config: Some(config),
+            artifact: Some(Box::new(CoupledArtifact {
+                regions: vec![r1.clone(), r2.clone(), r3.clone()],
+                coupled: r2.clone(),
+            })),
+            region_pressures: HashMap::from([(r1, 1.0), (r2.clone(), 5.0), (r3, 2.0)]),
+            ..Default::default()
+        };
+
+        // First pressure sets the best, then two flat ticks exhaust patience
+        for tick in 1..=3 {
+            model.current_tick = tick;
+            model.pressure_history.push(8.0);
+            assert_eq!(kick_if_stagnant(&mut model, 0).is_some(), tick == 3);
+        }
+
+        assert_eq!(model.kicks.len(), 1);
+        let kick = &model.kicks[0];
+        assert_eq!(kick.tick, 3);
+        assert_eq!(kick.response, StagnationResponse::Perturb);
+        assert_eq!(kick.regions, vec![r2.clone()]);
+        assert!(kick.perturbed.is_empty());
+        assert_eq!(model.explore_seeds.get(&r2), Some(&kick.seed));
+    }
+
+    #[test]
+    fn test_stagnation_skips_frozen_regions() {
+        let (r1, r2, r3) = (
+            test_region_id("r1"),
+            test_region_id("r2"),
+            test_region_id("r3"),
+        );
+        let mut config = KernelConfig::default();
+        config.stagnation.patience = 1;
+        config.stagnation.perturb_regions = 1;
+        let mut model = KernelCoordinatorState {
+            config: Some(config),
+            artifact: Some(Box::new(CoupledArtifact {
+                regions: vec![r1.clone(), r2.clone(), r3.clone()],
+                coupled: r2.clone(),
+            })),
+            region_pressures: HashMap::from([(r1, 1.0), (r2.clone(), 5.0), (r3.clone(), 2.0)]),
+            frozen_regions: HashSet::from([r2]),
+            ..Default::default()
+        };
+
+        for tick in 1..=2 {
+            model.current_tick = tick;
+            model.pressure_history.push(8.0);
+            kick_if_stagnant(&mut model, 0);
+        }
+
+        // The highest-pressure region is frozen, so the next one is kicked
+        assert_eq!(model.kicks[0].regions, vec![r3]);
+    }
+
+    #[test]
+    fn test_granularity_skips_frozen_regions() {
+        let (r1, r2, r3) = (
+            test_region_id("r1"),
+            test_region_id("r2"),
+            test_region_id("r3"),
+        );
+        let artifact = CoupledArtifact {
+            regions: vec![r1.clone(), r2.clone(), r3.clone()],
+            coupled: r2.clone(),
+        };
+        let config = GranularityConfig {
+            split_pressure: 2.0,
+            split_after_ticks: 1,
+            merge_pressure: 0.1,
+            merge_after_ticks: 1,
+        };
+        let mut streaks = HashMap::new();
+        update_pressure_streaks(
+            &mut streaks,
+            &config,
+            [(r1.clone(), 3.0), (r2.clone(), 0.0), (r3.clone(), 0.05)].into_iter(),
+        );
+
+        // Frozen r1 is not split, and r3 may not merge into frozen r2
+        let frozen = HashSet::from([r1.clone(), r2.clone()]);
+        assert!(plan_restructures(&artifact, &config, &streaks, &frozen).is_empty());
+
+        let frozen = HashSet::from([r2]);
+        assert_eq!(
+            plan_restructures(&artifact, &config, &streaks, &frozen),
+            vec![Restructure::Split(r1)]
+        );
+    }
+
+    #[test]
+    fn test_stagnation_perturb_changes_content() {
+        let artifact = crate::toy::ToyArtifact::new(&[1, 6, 2]);
+        let ids = artifact.region_ids();
+        let mut config = KernelConfig::default();
+        config.stagnation.patience = 1;
+        config.stagnation.perturb_regions = 1;
+        let mut model = KernelCoordinatorState {
+            config: Some(config),
+            artifact: Some(Box::new(artifact)),
+            region_pressures: ids.iter().cloned().zip([1.0, 6.0, 2.0]).collect(),
+            ..Default::default()
+        };
+
+        for tick in 1..=2 {
+            model.current_tick = tick;
+            model.pressure_history.push(9.0);
+            kick_if_stagnant(&mut model, 0);
+        }
+
+        // The highest-pressure region was rewritten and queued for re-measurement
+        let kick = &model.kicks[0];
+        assert_eq!(kick.perturbed, vec![ids[1].clone()]);
+        let artifact = model.artifact.as_ref().unwrap();
+        let content = artifact.read_region(ids[1].clone()).unwrap().content;
+        assert_eq!(content, (7 + kick.seed % 3).to_string());
+        assert_eq!(artifact.read_region(ids[0].clone()).unwrap().content, "1");
+        assert!(model.dirty_regions.contains(&ids[1]));
+    }
+
+    #[test]
+    fn test_best_so_far_keeps_lowest_pressure() {
+        let r1 = test_region_id("r1");
+        let mut model = KernelCoordinatorState {
+            artifact: Some(Box::new(CoupledArtifact {
+                regions: vec![r1.clone()],
+                coupled: r1,
+            })),
+            ..Default::default()
+        };
+
+        for (tick, pressure) in [(1, 5.0), (2, 3.0), (3, 4.0), (4, 3.0)] {
+            model.current_tick = tick;
+            let best = record_best_so_far(&mut model, pressure);
+            assert_eq!(best.is_some(), tick <= 2);
+        }
+
+        let best = model.best_so_far.expect("best recorded");
+        assert_eq!(best.tick, 2);
+        assert_eq!(best.total_pressure, 3.0);
+    }
+
+    /// Artifact that reports its own total pressure.
+    struct TotalArtifact(f64);
+
+    impl Artifact for TotalArtifact {
+        fn region_ids(&self) -> Vec<RegionId> {
+            Vec::new()
+        }
+
+        fn read_region(&self, _id: RegionId) -> anyhow::Result<RegionView> {
+            anyhow::bail!("no regions")
+        }
+
+        fn apply_patch(&mut self, _patch: Patch) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn total_pressure(&self) -> Option<f64> {
+            Some(self.0)
+        }
+    }
+
+    #[test]
+    fn test_tick_pressure_uses_one_measure() {
+        // An artifact total wins over the region EMAs on every kind of tick
+        let model = KernelCoordinatorState {
+            artifact: Some(Box::new(TotalArtifact(4.0))),
+            ..Default::default()
+        };
+        assert_eq!(tick_total_pressure(&model, 9.0), 4.0);
+
+        // Without one, the region EMAs are all there is
+        let r1 = test_region_id("r1");
+        let model = KernelCoordinatorState {
+            artifact: Some(Box::new(CoupledArtifact {
+                regions: vec![r1.clone()],
+                coupled: r1,
+            })),
+            ..Default::default()
+        };
+        assert_eq!(tick_total_pressure(&model, 9.0), 9.0);
+    }
+
+    #[test]
+    fn test_coupling_sampled_after_patched_tick() {
+        let (r1, r2, r3) = (
+            test_region_id("r1"),
+            test_region_id("r2"),
+            test_region_id("r3"),
+        );
+        let mut model = KernelCoordinatorState {
+            config: Some(KernelConfig::default()),
+            region_pressures: HashMap::from([(r1.clone(), 3.0), (r2.clone(), 2.0)]),
+            coupling_sources: Some((4, vec![r1.clone()])),
+            ..Default::default()
+        };
+
+        // r3 has no previous pressure, r1 was the patched region
+        record_region_pressures(
+            &mut model,
+            HashMap::from([(r1.clone(), 1.0), (r2.clone(), 2.5), (r3.clone(), 1.0)]),
+        );
+        assert_eq!(
+            model.coupling_samples,
+            vec![CouplingSample {
+                tick: 4,
+                source: r1.clone(),
+                target: r2.clone(),
+                delta: 0.5,
+            }]
+        );
+        assert!(model.coupling_sources.is_none());
+
+        // A kick this tick confounds the observation
+        model.coupling_samples.clear();
+        model.coupling_sources = Some((5, vec![r2.clone()]));
+        model.kicks.push(StagnationKick {
+            tick: 6,
+            response: StagnationResponse::Perturb,
+            regions: vec![r1.clone()],
+            perturbed: vec![r1.clone()],
+            best_pressure: 4.5,
+            seed: 0,
+        });
+        record_region_pressures(&mut model, HashMap::from([(r1, 2.0), (r2, 2.0), (r3, 1.0)]));
+        assert!(model.coupling_samples.is_empty());
+    }
+
+    #[test]
+    fn test_pheromones_follow_patch_results() {
+        let r1 = test_region_id("r1");
+        let mut config = KernelConfig::default();
+        config.pheromones.enabled = true;
+        let mut model = KernelCoordinatorState {
+            config: Some(config),
+            ..Default::default()
+        };
+
+        let mut rejected = patch_result(&r1, false);
+        rejected.new_content = Some("worse".to_string());
+        deposit_pheromone(&mut model, &rejected);
+        deposit_pheromone(&mut model, &patch_result(&r1, true));
+
+        // The next request for r1 sees both trails, accepted first
+        let offered = offer_pheromones(&mut model, &r1);
+        let kinds: Vec<_> = offered.iter().map(|p| p.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![PheromoneKind::Accepted, PheromoneKind::Rejected]
+        );
+
+        // Accepting a patch there reinforces the accepted trail it was shown
+        deposit_pheromone(&mut model, &patch_result(&r1, true));
+        let accepted = model.pheromones.query(&r1, PheromoneKind::Accepted, 5);
+        assert_eq!(accepted.len(), 2);
+        assert_eq!(accepted[0].reinforcements, 1);
+
+        evaporate_pheromones(&mut model);
+        assert!(model.pheromones.query(&r1, PheromoneKind::Rejected, 5)[0].weight < 1.0);
+    }
+
+    #[test]
+    fn test_config_update_is_queued_for_next_tick() {
+        let mut model = KernelCoordinatorState {
+            config: Some(KernelConfig::default()),
+            current_tick: 50,
+            ..Default::default()
+        };
+
+        let rejected = KernelConfig {
+            mode: ExecutionMode::Async,
+            ..KernelConfig::default()
+        };
+        assert_eq!(
+            update_config(&mut model, rejected),
+            Err(ConfigError::ModeChanged)
+        );
+        assert!(model.config_changes.is_empty());
+
+        let mut config = KernelConfig::default();
+        config.activation.inhibit_ms = 0;
+        let change = update_config(&mut model, config).expect("safe update");
+        assert_eq!(change.tick, 50);
+        assert_eq!(change.changed, vec!["activation"]);
+        assert_eq!(
+            model.config.as_ref().map(|c| c.activation.inhibit_ms),
+            Some(0)
+        );
+        assert_eq!(model.config_changes, vec![change]);
+    }
+
+    #[test]
+    fn test_effective_pressure_diffuses_from_neighbors() {
+        let (r1, r2, r3) = (
+            test_region_id("r1"),
+            test_region_id("r2"),
+            test_region_id("r3"),
+        );
+        let mut config = KernelConfig::default();
+        config.diffusion.rate = 0.5;
+        let mut model = KernelCoordinatorState {
+            config: Some(config),
+            artifact: Some(Box::new(CoupledArtifact {
+                regions: vec![r1.clone(), r2.clone(), r3.clone()],
+                coupled: r2.clone(),
+            })),
+            ..Default::default()
+        };
+        let pressures = || {
+            vec![
+                (r1.clone(), Some(1.0), None),
+                (r2.clone(), Some(2.0), None),
+                (r3.clone(), Some(0.0), None),
+            ]
+            .into_iter()
+        };
+
+        // Each region feels 0.5 × weight 0.5 × r2's pressure
+        let effective = effective_pressures(&model, pressures());
+        assert_eq!(effective[&r1], 1.5);
+        assert_eq!(effective[&r2], 2.0);
+        assert_eq!(effective[&r3], 0.5);
+
+        model.config.as_mut().unwrap().diffusion.rate = 0.0;
+        let effective = effective_pressures(&model, pressures());
+        assert_eq!(effective[&r1], 1.0);
+        assert_eq!(effective[&r3], 0.0);
+    }
+
+    /// A day region with two block regions nested inside it.
+    struct TreeArtifact {
+        day: RegionId,
+        blocks: Vec<RegionId>,
+    }
+
+    impl Artifact for TreeArtifact {
+        fn region_ids(&self) -> Vec<RegionId> {
+            let mut ids = vec![self.day.clone()];
+            ids.extend(self.blocks.iter().cloned());
+            ids
+        }
+
+        fn read_region(&self, id: RegionId) -> anyhow::Result<RegionView> {
+            let (parent, children) = if id == self.day {
+                (None, self.blocks.clone())
+            } else {
+                (Some(self.day.clone()), Vec::new())
+            };
+            Ok(RegionView {
+                id,
+                kind: "test".to_string(),
+                content: String::new(),
+                metadata: HashMap::new(),
+                parent,
+                children,
+            })
+        }
+
+        fn apply_patch(&mut self, _patch: Patch) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn tree() -> TreeArtifact {
+        TreeArtifact {
+            day: test_region_id("day"),
+            blocks: vec![test_region_id("block-1"), test_region_id("block-2")],
+        }
+    }
+
+    fn scored_patch(region: &RegionId, score: f64) -> (f64, Patch, PatchOrigin) {
+        let patch = Patch {
+            region: region.clone(),
+            op: crate::region::PatchOp::Replace(String::new()),
+            rationale: String::new(),
+            expected_delta: HashMap::new(),
+        };
+        (score, patch, PatchOrigin::default())
+    }
+
+    #[test]
+    fn test_frozen_region_blocks_related_patches() {
+        let tree = tree();
+        let (day, b1, b2) = (
+            tree.day.clone(),
+            tree.blocks[0].clone(),
+            tree.blocks[1].clone(),
+        );
+        let mut model = KernelCoordinatorState {
+            artifact: Some(Box::new(tree)),
+            ..Default::default()
+        };
+        let steer = |update| SteerRegions {
+            regions: vec![b1.clone()],
+            update,
+        };
+
+        // A frozen block blocks itself and the day around it, not its sibling
+        steer_frozen(
+            &mut model.frozen_regions,
+            &steer(SteeringUpdate::Freeze { reason: None }),
+        );
+        assert_eq!(frozen_in_lineage(&model, &b1), Some(b1.clone()));
+        assert_eq!(frozen_in_lineage(&model, &day), Some(b1.clone()));
+        assert_eq!(frozen_in_lineage(&model, &b2), None);
+
+        steer_frozen(&mut model.frozen_regions, &steer(SteeringUpdate::Unfreeze));
+        assert_eq!(frozen_in_lineage(&model, &day), None);
+    }
+
+    #[test]
+    fn test_frozen_regions_reject_migrants() {
+        let artifact = crate::toy::ToyArtifact::new(&[5, 3]);
+        let ids = artifact.region_ids();
+        let migrants: Vec<MigrantRegion> = ids
+            .iter()
+            .map(|id| MigrantRegion {
+                view: RegionView {
+                    content: "1".to_string(),
+                    ..artifact.read_region(id.clone()).unwrap()
+                },
+                pressure: 1.0,
+            })
+            .collect();
+        let mut model = KernelCoordinatorState {
+            artifact: Some(Box::new(artifact)),
+            frozen_regions: HashSet::from([ids[0].clone()]),
+            ..Default::default()
+        };
+
+        let (accepted, _) = import_regions(&mut model, &migrants);
+
+        assert_eq!(accepted, vec![ids[1].clone()]);
+        assert_eq!(
+            model.artifact.as_ref().unwrap().source().as_deref(),
+            Some("5\n1")
+        );
+    }
+
+    #[test]
+    fn test_pressure_aggregates_up_the_hierarchy() {
+        let tree = tree();
+        let (day, b1, b2) = (
+            tree.day.clone(),
+            tree.blocks[0].clone(),
+            tree.blocks[1].clone(),
+        );
+        let model = KernelCoordinatorState::default();
+        let pressures = |day_pressure| {
+            vec![
+                (day.clone(), day_pressure, None),
+                (b1.clone(), Some(1.0), Some(day.clone())),
+                (b2.clone(), Some(2.0), Some(day.clone())),
+            ]
+            .into_iter()
+        };
+
+        // An unmeasured day takes the pressure of its blocks
+        let effective = effective_pressures(&model, pressures(None));
+        assert_eq!(effective[&day], 3.0);
+        assert_eq!(effective[&b1], 1.0);
+        assert_eq!(effective[&b2], 2.0);
+
+        // A measured day already covers its blocks
+        let effective = effective_pressures(&model, pressures(Some(0.5)));
+        assert_eq!(effective[&day], 0.5);
+        assert_eq!(effective[&b1], 1.0);
+    }
+
+    #[test]
+    fn test_totals_count_nested_regions_once() {
+        let tree = tree();
+        let (day, b1, b2) = (
+            tree.day.clone(),
+            tree.blocks[0].clone(),
+            tree.blocks[1].clone(),
+        );
+        let regions = |day_measured| {
+            vec![
+                (&day, None, day_measured),
+                (&b1, Some(&day), true),
+                (&b2, Some(&day), false),
+            ]
+            .into_iter()
+        };
+
+        assert_eq!(
+            outermost_measured(regions(true)),
+            HashSet::from([day.clone()])
+        );
+        assert_eq!(
+            outermost_measured(regions(false)),
+            HashSet::from([b1.clone()])
+        );
+    }
+
+    #[test]
+    fn test_nested_patches_do_not_conflict() {
+        let tree = tree();
+        let (day, b1, b2) = (
+            tree.day.clone(),
+            tree.blocks[0].clone(),
+            tree.blocks[1].clone(),
+        );
+
+        assert_eq!(lineage(&tree, &b1), HashSet::from([day.clone()]));
+        assert_eq!(
+            lineage(&tree, &day),
+            HashSet::from([b1.clone(), b2.clone()])
+        );
+
+        // The day patch outscores one block but not the other
+        let kept = drop_nested_conflicts(
+            &tree,
+            vec![
+                scored_patch(&b1, 3.0),
+                scored_patch(&day, 2.0),
+                scored_patch(&b2, 1.0),
+            ],
+        );
+        let regions: Vec<RegionId> = kept.into_iter().map(|(_, p, _)| p.region).collect();
+        assert_eq!(regions, vec![b1.clone(), b2]);
+
+        // A better day patch excludes every block patch
+        let kept =
+            drop_nested_conflicts(&tree, vec![scored_patch(&b1, 1.0), scored_patch(&day, 2.0)]);
+        let regions: Vec<RegionId> = kept.into_iter().map(|(_, p, _)| p.region).collect();
+        assert_eq!(regions, vec![day]);
+    }
+
+    #[test]
+    fn test_granularity_rules_need_persistent_pressure() {
+        let (r1, r2, r3) = (
+            test_region_id("r1"),
+            test_region_id("r2"),
+            test_region_id("r3"),
+        );
+        let artifact = CoupledArtifact {
+            regions: vec![r1.clone(), r2.clone(), r3.clone()],
+            coupled: r2.clone(),
+        };
+        let config = GranularityConfig {
+            split_pressure: 2.0,
+            split_after_ticks: 2,
+            merge_pressure: 0.1,
+            merge_after_ticks: 2,
+        };
+        let pressures = [(r1.clone(), 3.0), (r2.clone(), 0.0), (r3.clone(), 0.05)];
+        let mut streaks = HashMap::new();
+
+        let frozen = HashSet::new();
+
+        update_pressure_streaks(&mut streaks, &config, pressures.iter().cloned());
+        assert!(plan_restructures(&artifact, &config, &streaks, &frozen).is_empty());
+
+        update_pressure_streaks(&mut streaks, &config, pressures.iter().cloned());
+        assert_eq!(
+            plan_restructures(&artifact, &config, &streaks, &frozen),
+            vec![
+                Restructure::Split(r1.clone()),
+                Restructure::Merge(vec![r3.clone(), r2.clone()]),
+            ]
+        );
+
+        // One tick back under the split threshold resets the streak
+        update_pressure_streaks(&mut streaks, &config, [(r1, 1.0)].into_iter());
+        assert_eq!(
+            plan_restructures(&artifact, &config, &streaks, &frozen),
+            vec![Restructure::Merge(vec![r3, r2])]
+        );
+    }
+}
@@ -22,13 +22,27 @@
 //!
 //! ClaimManager provides stigmergic coordination - agents claim column/value pairs
 //! before proposing, preventing duplicate proposals within a tick.
+//!
+//! Patch actors may join (`PatchActorReady`) or leave (`PatchActorGone`) at any
+//! time. Outstanding requests owned by a departed actor are re-dispatched, and an
+//! optional PatchActorSupervisor replaces actors that fail or miss heartbeats.
+//! A ProposerActor turns a plain `Proposer` into such a patch actor.
+//!
+//! In async mode the first Tick broadcasts `RegionWake`; from then on each
+//! RegionActor wakes itself, re-measures, and sends `RequestProposal` to the
+//! coordinator when under pressure. The coordinator answers with either
+//! `RegionApplyPatch` or `NoPatchProposed`, and later ticks only observe.
 
 mod claim_manager;
 mod coordinator;
+mod proposer_actor;
 mod region_actor;
 mod sensor_actor;
+mod supervisor;
 
 pub use claim_manager::{ClaimManager, ClaimManagerState};
 pub use coordinator::{KernelCoordinator, KernelCoordinatorState};
-pub use region_actor::{RegionActor, RegionActorState};
+pub use proposer_actor::{ProposerActor, ProposerActorState};
+pub use region_actor::{RegionActor, RegionActorState, RegionSpawner};
 pub use sensor_actor::{SensorActor, SensorActorState};
+pub use supervisor::{PatchActorFactory, PatchActorSupervisor, PatchActorSupervisorState};
@@ -0,0 +1,220 @@
+//! ProposerActor: wraps a Proposer as a self-registering patch actor.
+//!
+//! Uses the broker pub/sub pattern:
+//! - Subscribes to `CoordinatorReady` and answers with `PatchActorReady`
+//! - Handles `ProposeForRegion` sent directly by the coordinator
+//! - Broadcasts the `PatchProposal` reply with the request's correlation ID
+//! - Broadcasts `PatchActorHeartbeat` on an interval while running
+//! - Broadcasts `PatchActorGone` when stopped
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use acton_reactive::prelude::*;
+use tracing::{info, warn};
+
+use crate::messages::{
+    CoordinatorReady, DepartureReason, PatchActorGone, PatchActorHeartbeat, PatchActorReady,
+    PatchProposal, ProposeForRegion,
+};
+use crate::proposer::{AsyncProposer, Proposal, Proposer};
+
+/// The wrapped proposer.
+#[derive(Clone)]
+enum Inner {
+    Sync(Arc<dyn Proposer>),
+    Async(Arc<dyn AsyncProposer>),
+}
+
+impl Inner {
+    fn name(&self) -> &str {
+        match self {
+            Inner::Sync(p) => p.name(),
+            Inner::Async(p) => p.name(),
+        }
+    }
+
+    /// Run the proposer off the actor's task.
+    ///
+    /// Sync proposers run on a blocking thread; async proposer futures need
+    /// not be Sync, so they are driven on their own task.
+    async fn propose(self, request: ProposeForRegion) -> anyhow::Result<Proposal> {
+        let joined = match self {
+            Inner::Sync(p) => tokio::task::spawn_blocking(move || p.propose(&request)).await,
+            Inner::Async(p) => tokio::spawn(async move { p.propose(&request).await }).await,
+        };
+        joined.unwrap_or_else(|e| Err(anyhow::anyhow!("proposer panicked: {e}")))
+    }
+}
+
+/// Heartbeat interval of a `ProposerActor` unless configured otherwise.
+const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Actor state for ProposerActor.
+#[derive(Default, Clone)]
+pub struct ProposerActorState {
+    /// The wrapped proposer
+    proposer: Option<Inner>,
+    /// Set once the actor is stopping, ending its heartbeats
+    stopping: Arc<AtomicBool>,
+}
+
+impl std::fmt::Debug for ProposerActorState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProposerActorState")
+            .field("proposer", &self.proposer.as_ref().map(|p| p.name()))
+            .finish()
+    }
+}
+
+/// Actor wrapper for a [`Proposer`] or [`AsyncProposer`].
+///
+/// Requests are handled concurrently, each on its own task; every request
+/// gets a `PatchProposal`, empty if the proposer failed.
+pub struct ProposerActor {
+    proposer: Inner,
+    heartbeat_interval: Duration,
+}
+
+impl ProposerActor {
+    /// Wrap a synchronous proposer.
+    pub fn new(proposer: Arc<dyn Proposer>) -> Self {
+        Self {
+            proposer: Inner::Sync(proposer),
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+        }
+    }
+
+    /// Wrap an asynchronous proposer.
+    pub fn new_async(proposer: Arc<dyn AsyncProposer>) -> Self {
+        Self {
+            proposer: Inner::Async(proposer),
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+        }
+    }
+
+    /// Broadcast a `PatchActorHeartbeat` this often (zero = never), so the
+    /// coordinator's heartbeat timeout does not evict an idle proposer.
+    pub fn with_heartbeat_interval(mut self, interval: Duration) -> Self {
+        self.heartbeat_interval = interval;
+        self
+    }
+
+    /// Spawn this proposer actor in the given runtime.
+    ///
+    /// Must be spawned before the coordinator so it hears `CoordinatorReady`.
+    pub async fn spawn(self, runtime: &mut ActorRuntime) -> ActorHandle {
+        let name = self.proposer.name().to_string();
+        let heartbeat_interval = self.heartbeat_interval;
+        let mut actor =
+            runtime.new_actor_with_name::<ProposerActorState>(format!("Proposer:{}", name));
+
+        actor.model.proposer = Some(self.proposer);
+
+        // Subscribe BEFORE starting
+        actor.handle().subscribe::<CoordinatorReady>().await;
+
+        actor.act_on::<CoordinatorReady>(|actor, _context| {
+            let broker = actor.broker().clone();
+            let handle = actor.handle().clone();
+            let actor_ern = actor.handle().name().to_string();
+
+            Reply::pending(async move {
+                info!(actor = %actor_ern, "Proposer ready");
+                broker
+                    .broadcast(PatchActorReady { actor_ern, handle })
+                    .await;
+            })
+        });
+
+        // Once started, keep telling the coordinator we are alive
+        actor.after_start(move |actor| {
+            let broker = actor.broker().clone();
+            let actor_ern = actor.handle().name().to_string();
+            let stopping = actor.model.stopping.clone();
+
+            async move {
+                if heartbeat_interval.is_zero() {
+                    return;
+                }
+                tokio::spawn(async move {
+                    let mut interval = tokio::time::interval(heartbeat_interval);
+                    loop {
+                        interval.tick().await;
+                        if stopping.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        broker
+                            .broadcast(PatchActorHeartbeat {
+                                actor_ern: actor_ern.clone(),
+                            })
+                            .await;
+                    }
+                });
+            }
+        });
+
+        // On stop, leave the coordinator's dispatch pool
+        actor.before_stop(|actor| {
+            let broker = actor.broker().clone();
+            let actor_ern = actor.handle().name().to_string();
+            actor.model.stopping.store(true, Ordering::Relaxed);
+
+            async move {
+                broker
+                    .broadcast(PatchActorGone {
+                        actor_ern,
+                        reason: DepartureReason::Stopped,
+                    })
+                    .await;
+            }
+        });
+
+        // Proposals run detached: acton waits for pending act_on futures
+        // before handling more messages, so a proposer that never answers
+        // would otherwise stall this actor (and its shutdown)
+        actor.act_on::<ProposeForRegion>(|actor, context| {
+            let msg = context.message().clone();
+            let broker = actor.broker().clone();
+
+            let Some(proposer) = actor.model.proposer.clone() else {
+                tracing::error!("ProposerActor: proposer not initialized");
+                return Reply::ready();
+            };
+            let actor_name = proposer.name().to_string();
+            let actor_ern = actor.handle().name().to_string();
+
+            tokio::spawn(async move {
+                let correlation_id = msg.correlation_id.clone();
+                let region_id = msg.region_id.clone();
+                let proposal = proposer.propose(msg).await.unwrap_or_else(|e| {
+                    warn!(
+                        proposer = %actor_name,
+                        region = %region_id,
+                        error = %e,
+                        "Proposer failed"
+                    );
+                    Proposal::default()
+                });
+
+                broker
+                    .broadcast(PatchProposal {
+                        correlation_id,
+                        region_id,
+                        actor_name,
+                        actor_ern,
+                        model: proposal.model,
+                        sampling_band: proposal.sampling_band,
+                        patches: proposal.patches,
+                        prompt_tokens: proposal.prompt_tokens,
+                        completion_tokens: proposal.completion_tokens,
+                    })
+                    .await;
+            });
+            Reply::ready()
+        });
+
+        actor.start().await
+    }
+}
@@ -4,6 +4,10 @@
 //! - Natural conflict resolution via mailbox serialization
 //! - Local state ownership (stigmergy model from paper)
 //! - Post-patch validation to ensure δ_min > 0 (convergence theorem)
+//!
+//! In async mode (`ExecutionMode::Async`) each region also drives itself:
+//! it wakes on its own timer, decays, re-measures with every sensor, and
+//! requests a proposal from the coordinator when its pressure is high.
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -11,13 +15,19 @@ use std::sync::Arc;
 use acton_reactive::prelude::*;
 use tracing::{info, warn};
 
-use crate::config::PressureAxisConfig;
+use crate::config::{DecayConfig, PressureAxisConfig};
+use crate::constraint::Rejection;
 use crate::messages::{
-    ApplyDecay, EvaluatePatch, EvaluatePatchResponse, MeasurementResult, PressureResponse,
-    QueryPressure, RefreshContent, RegionApplyPatch, RegionPatchResult,
+    ApplyDecay, EvaluatePatch, EvaluatePatchResponse, KickRegion, MeasurementResult,
+    NoPatchProposed, PressureResponse, PressureSummary, ProvenanceReport, QueryPressure,
+    QueryProvenance, RefreshContent, RegionApplyPatch, RegionPatchResult, RegionRetired,
+    RegionWake, RequestProposal, RetireRegion, SteerRegion, SteeringUpdate, SuspendRegions,
+    UpdatePressureAxes,
 };
+use crate::normalize::SignalNormalizer;
 use crate::pressure::{Sensor, Signals};
-use crate::region::{RegionId, RegionState, RegionView};
+use crate::region::{PatchOrigin, ProvenanceRecord, RegionId, RegionState, RegionView, Steering};
+use crate::reputation::weighted_expected_delta;
 
 /// Pending patch validation state.
 #[derive(Clone)]
@@ -32,6 +42,14 @@ pub struct PendingValidation {
     pub inhibit_ms: u64,
     /// Patch rationale for provenance
     pub rationale: String,
+    /// Who proposed the patch
+    pub origin: PatchOrigin,
+    /// Tick the patch was sent on
+    pub tick: usize,
+    /// Predicted improvement (weighted sum over axes)
+    pub expected_delta: f64,
+    /// Content hash before the patch
+    pub hash_before: u64,
 }
 
 /// Actor state for a single region.
@@ -48,6 +66,10 @@ pub struct RegionActorState {
     pub content: String,
     /// Arbitrary metadata
     pub metadata: HashMap<String, serde_json::Value>,
+    /// Enclosing region, if nested
+    pub parent: Option<RegionId>,
+    /// Nested regions
+    pub children: Vec<RegionId>,
     /// Mutable state: fitness, confidence, pressure EMA, inhibition
     pub state: RegionState,
     /// Handle to coordinator for sending responses
@@ -56,10 +78,28 @@ pub struct RegionActorState {
     pub sensor: Option<Arc<dyn Sensor>>,
     /// Pressure axis configuration for weighted pressure calculation
     pub pressure_axes: Vec<PressureAxisConfig>,
+    /// Normalizes signals before weighting (shared across regions)
+    pub normalizer: Arc<SignalNormalizer>,
     /// Current signals from last measurement
     pub signals: Signals,
     /// Pending validation requests (correlation_id -> validation state)
     pub pending_validations: HashMap<String, PendingValidation>,
+    /// All sensors, for self-measurement in async mode
+    pub sensors: Vec<Arc<dyn Sensor>>,
+    /// Wake parameters once async mode has started (None in tick mode)
+    pub autonomy: Option<RegionWake>,
+    /// Whether async wakeups have been suspended
+    pub suspended: bool,
+    /// Whether a RequestProposal is awaiting an outcome (async mode)
+    pub proposal_in_flight: bool,
+    /// Provenance records kept (0 = unbounded)
+    pub provenance_limit: usize,
+    /// Decay settings from the latest `ApplyDecay` or `RegionWake`
+    pub decay: Option<DecayConfig>,
+    /// Tick (async mode: wake count) decay was last applied at
+    pub decay_tick: usize,
+    /// Wakes handled so far (async mode)
+    pub wakes: usize,
 }
 
 impl std::fmt::Debug for RegionActorState {
@@ -79,9 +119,15 @@ impl std::fmt::Debug for RegionActorState {
 /// Handles:
 /// - `ApplyDecay` - decay fitness/confidence at tick start
 /// - `MeasurementResult` - update signals and pressure EMA
-/// - `QueryPressure` - respond with current pressure state
+/// - `QueryPressure` - respond with current pressure state (or a summary)
 /// - `RegionApplyPatch` - validate and apply patches
 /// - `RefreshContent` - update content after artifact modification
+/// - `QueryProvenance` - report the region's patch history
+/// - `RetireRegion` - hand state back when the region is split or merged away
+/// - `KickRegion` - clear inhibition and decayed fitness after stagnation
+/// - `UpdatePressureAxes` - adopt axes from a hot config reload
+/// - `SteerRegion` - freeze, unfreeze or bias the region (external steering)
+/// - `RegionWake`, `SuspendRegions`, `NoPatchProposed` - async mode autonomy
 pub struct RegionActor {
     /// Unique region identifier
     pub region_id: RegionId,
@@ -91,12 +137,26 @@ pub struct RegionActor {
     pub content: String,
     /// Initial metadata
     pub metadata: HashMap<String, serde_json::Value>,
+    /// Enclosing region, if nested
+    pub parent: Option<RegionId>,
+    /// Nested regions
+    pub children: Vec<RegionId>,
     /// Handle to coordinator
     pub coordinator: ActorHandle,
     /// Sensor for validation
     pub sensor: Arc<dyn Sensor>,
     /// Pressure axis configuration
     pub pressure_axes: Vec<PressureAxisConfig>,
+    /// Shared signal normalizer (a private one if None)
+    pub normalizer: Option<Arc<SignalNormalizer>>,
+    /// All sensors, for self-measurement in async mode
+    pub sensors: Vec<Arc<dyn Sensor>>,
+    /// Provenance records kept (0 = unbounded)
+    pub provenance_limit: usize,
+    /// State carried over from a split or merged region (fresh if None)
+    pub initial_state: Option<RegionState>,
+    /// Tick decay counts from (0 at startup)
+    pub decay_tick: usize,
 }
 
 impl RegionActor {
@@ -115,12 +175,67 @@ impl RegionActor {
             kind,
             content,
             metadata,
+            parent: None,
+            children: Vec::new(),
             coordinator,
             sensor,
             pressure_axes,
+            normalizer: None,
+            sensors: Vec::new(),
+            provenance_limit: 0,
+            initial_state: None,
+            decay_tick: 0,
         }
     }
 
+    /// Set the sensors used for self-measurement in async mode.
+    ///
+    /// Defaults to just the validation sensor.
+    pub fn with_sensors(mut self, sensors: Vec<Arc<dyn Sensor>>) -> Self {
+        self.sensors = sensors;
+        self
+    }
+
+    /// Place the region in a hierarchy (see `RegionView::parent`).
+    pub fn with_hierarchy(mut self, parent: Option<RegionId>, children: Vec<RegionId>) -> Self {
+        self.parent = parent;
+        self.children = children;
+        self
+    }
+
+    /// Set how many provenance records the region keeps.
+    ///
+    /// Defaults to 0 (unbounded).
+    pub fn with_provenance_limit(mut self, limit: usize) -> Self {
+        self.provenance_limit = limit;
+        self
+    }
+
+    /// Share a signal normalizer with other regions.
+    ///
+    /// Without one, running normalization statistics cover only this region.
+    pub fn with_normalizer(mut self, normalizer: Arc<SignalNormalizer>) -> Self {
+        self.normalizer = Some(normalizer);
+        self
+    }
+
+    /// Start from an existing state instead of a fresh one.
+    ///
+    /// Used when a region replaces others that were split or merged.
+    pub fn with_state(mut self, state: RegionState) -> Self {
+        self.initial_state = Some(state);
+        self
+    }
+
+    /// Count decay from `tick` instead of 0.
+    ///
+    /// Used for regions spawned mid-run, whose first `ApplyDecay` should cover
+    /// one tick rather than every tick since the start.
+    pub fn with_decay_tick(mut self, tick: usize) -> Self {
+        self.decay_tick = tick;
+        self
+    }
+
     /// Spawn this region actor in the given runtime.
     ///
     /// The actor will:
@@ -138,15 +253,31 @@ impl RegionActor {
         actor.model.kind = self.kind;
         actor.model.content = self.content;
         actor.model.metadata = self.metadata;
-        actor.model.state = RegionState::new(now_ms);
+        actor.model.parent = self.parent;
+        actor.model.children = self.children;
+        actor.model.state = self
+            .initial_state
+            .unwrap_or_else(|| RegionState::new(now_ms));
         actor.model.coordinator = Some(self.coordinator);
+        actor.model.sensors = if self.sensors.is_empty() {
+            vec![self.sensor.clone()]
+        } else {
+            self.sensors
+        };
         actor.model.sensor = Some(self.sensor);
+        actor.model.normalizer = self
+            .normalizer
+            .unwrap_or_else(|| Arc::new(SignalNormalizer::new(&self.pressure_axes)));
         actor.model.pressure_axes = self.pressure_axes;
+        actor.model.provenance_limit = self.provenance_limit;
+        actor.model.decay_tick = self.decay_tick;
         actor.model.signals = HashMap::new();
 
         // Subscribe to broadcast messages BEFORE starting
         actor.handle().subscribe::<ApplyDecay>().await;
         actor.handle().subscribe::<QueryPressure>().await;
+        actor.handle().subscribe::<RegionWake>().await;
+        actor.handle().subscribe::<SuspendRegions>().await;
 
         // Configure handlers
         configure_region_actor(&mut actor);
@@ -155,71 +286,251 @@ impl RegionActor {
     }
 }
 
+/// Spawns RegionActors that share a coordinator, sensors, and axes.
+///
+/// The kernel uses it at startup; the coordinator keeps it to spawn actors
+/// for regions created by a split or merge.
+#[derive(Clone)]
+pub struct RegionSpawner {
+    /// Runtime the actors are spawned into
+    runtime: ActorRuntime,
+    /// Handle to coordinator
+    coordinator: ActorHandle,
+    /// Sensor for validation
+    sensor: Arc<dyn Sensor>,
+    /// All sensors, for self-measurement in async mode
+    sensors: Vec<Arc<dyn Sensor>>,
+    /// Pressure axis configuration
+    pressure_axes: Vec<PressureAxisConfig>,
+    /// Signal normalizer shared by every spawned region
+    normalizer: Arc<SignalNormalizer>,
+    /// Provenance records kept (0 = unbounded)
+    provenance_limit: usize,
+}
+
+impl std::fmt::Debug for RegionSpawner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegionSpawner")
+            .field("sensor", &self.sensor.name())
+            .field("sensors", &self.sensors.len())
+            .field("provenance_limit", &self.provenance_limit)
+            .finish()
+    }
+}
+
+impl RegionSpawner {
+    /// Create a new RegionSpawner.
+    pub fn new(
+        runtime: ActorRuntime,
+        coordinator: ActorHandle,
+        sensor: Arc<dyn Sensor>,
+        pressure_axes: Vec<PressureAxisConfig>,
+    ) -> Self {
+        Self {
+            runtime,
+            coordinator,
+            sensor,
+            sensors: Vec::new(),
+            normalizer: Arc::new(SignalNormalizer::new(&pressure_axes)),
+            pressure_axes,
+            provenance_limit: 0,
+        }
+    }
+
+    /// Set the sensors used for self-measurement (see `RegionActor::with_sensors`).
+    pub fn with_sensors(mut self, sensors: Vec<Arc<dyn Sensor>>) -> Self {
+        self.sensors = sensors;
+        self
+    }
+
+    /// Set how many provenance records each region keeps.
+    pub fn with_provenance_limit(mut self, limit: usize) -> Self {
+        self.provenance_limit = limit;
+        self
+    }
+
+    /// Set the signal normalizer shared by every spawned region.
+    pub fn with_normalizer(mut self, normalizer: Arc<SignalNormalizer>) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Replace the pressure axes given to regions spawned from now on.
+    pub fn set_pressure_axes(&mut self, pressure_axes: Vec<PressureAxisConfig>) {
+        self.pressure_axes = pressure_axes;
+    }
+
+    /// Spawn an actor for `view`, starting from `state` (fresh if None).
+    ///
+    /// `tick` is the last tick decay was applied at (0 before the first).
+    pub async fn spawn(
+        &self,
+        view: RegionView,
+        state: Option<RegionState>,
+        tick: usize,
+    ) -> ActorHandle {
+        let mut region_actor = RegionActor::new(
+            view.id,
+            view.kind,
+            view.content,
+            view.metadata,
+            self.coordinator.clone(),
+            self.sensor.clone(),
+            self.pressure_axes.clone(),
+        )
+        .with_sensors(self.sensors.clone())
+        .with_hierarchy(view.parent, view.children)
+        .with_provenance_limit(self.provenance_limit)
+        .with_normalizer(self.normalizer.clone())
+        .with_decay_tick(tick);
+        if let Some(state) = state {
+            region_actor = region_actor.with_state(state);
+        }
+        let mut runtime = self.runtime.clone();
+        region_actor.spawn(&mut runtime, 0).await
+    }
+}
+
 /// Configure message handlers for the RegionActor.
 fn configure_region_actor(actor: &mut ManagedActor<Idle, RegionActorState>) {
     // Handle ApplyDecay - mutate_on because we modify state
     actor.mutate_on::<ApplyDecay>(|actor, context| {
         let msg = context.message();
+        apply_decay(&mut actor.model, msg.now_ms, msg.tick, &msg.decay);
+        Reply::ready()
+    });
 
-        // Apply decay to fitness
-        if msg.fitness_half_life_ms > 0 {
-            let dt_ms = msg.now_ms.saturating_sub(actor.model.state.last_updated_ms);
-            if dt_ms > 0 {
-                let lambda = std::f64::consts::LN_2 / msg.fitness_half_life_ms as f64;
-                actor.model.state.fitness *= (-lambda * dt_ms as f64).exp();
-            }
+    // Handle MeasurementResult - update signals and pressure EMA
+    actor.mutate_on::<MeasurementResult>(|actor, context| {
+        let signals = context.message().signals.clone();
+        record_signals(&mut actor.model, &signals);
+        Reply::ready()
+    });
+
+    // Handle RegionWake - async mode: decay, re-measure, maybe request a proposal
+    actor.mutate_on::<RegionWake>(|actor, context| {
+        let msg = context.message().clone();
+
+        if actor.model.suspended {
+            return Reply::ready();
         }
+        actor.model.autonomy = Some(msg.clone());
+
+        actor.model.wakes += 1;
+        let wakes = actor.model.wakes;
+        apply_decay(&mut actor.model, msg.now_ms, wakes, &msg.decay);
+        measure_self(&mut actor.model);
+
+        let total_pressure = actor
+            .model
+            .state
+            .steering
+            .steer(actor.model.state.pressure_ema.values().sum(), wakes);
+        let request = if !actor.model.proposal_in_flight
+            && !actor.model.state.is_inhibited(msg.now_ms)
+            && total_pressure >= msg.min_total_pressure
+        {
+            actor.model.proposal_in_flight = true;
+            Some(RequestProposal {
+                region_id: actor.model.region_id.clone(),
+                region_view: current_view(&actor.model),
+                signals: actor.model.signals.clone(),
+                pressures: actor.model.state.pressure_ema.clone(),
+                state: actor.model.state.clone(),
+                now_ms: msg.now_ms,
+            })
+        } else {
+            None
+        };
+
+        let coordinator = actor.model.coordinator.clone();
+        let handle = actor.handle().clone();
 
-        // Apply decay to confidence
-        if msg.confidence_half_life_ms > 0 {
-            let dt_ms = msg.now_ms.saturating_sub(actor.model.state.last_updated_ms);
-            if dt_ms > 0 {
-                let lambda = std::f64::consts::LN_2 / msg.confidence_half_life_ms as f64;
-                actor.model.state.confidence *= (-lambda * dt_ms as f64).exp();
+        Reply::pending(async move {
+            if let (Some(request), Some(coordinator)) = (request, coordinator) {
+                coordinator.send(request).await;
             }
-        }
 
-        actor.model.state.last_updated_ms = msg.now_ms;
+            // Schedule our own next wake
+            tokio::spawn(async move {
+                tokio::time::sleep(std::time::Duration::from_millis(msg.interval_ms)).await;
+                let now_ms = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_millis() as u64;
+                handle.send(RegionWake { now_ms, ..msg }).await;
+            });
+        })
+    });
 
+    // Handle SuspendRegions - stop async wakeups
+    actor.mutate_on::<SuspendRegions>(|actor, _context| {
+        actor.model.suspended = true;
         Reply::ready()
     });
 
-    // Handle MeasurementResult - update signals and pressure EMA
-    actor.mutate_on::<MeasurementResult>(|actor, context| {
-        let msg = context.message();
+    // Handle RetireRegion - hand state back before the coordinator stops us
+    actor.mutate_on::<RetireRegion>(|actor, context| {
+        actor.model.suspended = true;
+        let retired = RegionRetired {
+            correlation_id: context.message().correlation_id.clone(),
+            region_id: actor.model.region_id.clone(),
+            state: actor.model.state.clone(),
+        };
+        let coordinator = actor.model.coordinator.clone();
 
-        // Merge new signals into our signal map
-        for (key, value) in &msg.signals {
-            actor.model.signals.insert(key.clone(), *value);
-        }
+        Reply::pending(async move {
+            if let Some(coordinator) = coordinator {
+                coordinator.send(retired).await;
+            }
+        })
+    });
+
+    // Handle KickRegion - stagnation: compete for proposals again
+    actor.mutate_on::<KickRegion>(|actor, context| {
+        let fresh = RegionState::new(context.message().now_ms);
+        let state = &mut actor.model.state;
+        state.suppress_until_ms = None;
+        state.fitness = fresh.fitness;
+        state.confidence = fresh.confidence;
+        Reply::ready()
+    });
+
+    // Handle UpdatePressureAxes - hot config reload
+    actor.mutate_on::<UpdatePressureAxes>(|actor, context| {
+        actor.model.pressure_axes = context.message().axes.clone();
+        Reply::ready()
+    });
 
-        // Update pressure EMA for each axis
-        let alpha = 0.2; // EMA smoothing factor
-        for axis in &actor.model.pressure_axes {
-            if let Some(signal_value) = msg.signals.get(&axis.expr) {
-                let weight = axis
-                    .kind_weights
-                    .get(&actor.model.kind)
-                    .copied()
-                    .unwrap_or(axis.weight);
-                let weighted_pressure = signal_value * weight;
-
-                let current = actor
-                    .model
-                    .state
-                    .pressure_ema
-                    .get(&axis.name)
-                    .copied()
-                    .unwrap_or(weighted_pressure);
-                let new_ema = alpha * weighted_pressure + (1.0 - alpha) * current;
-                actor
-                    .model
-                    .state
-                    .pressure_ema
-                    .insert(axis.name.clone(), new_ema);
+    // Handle SteerRegion - external steering from a human or supervisor
+    actor.mutate_on::<SteerRegion>(|actor, context| {
+        let tick = actor.model.decay_tick;
+        let steering = &mut actor.model.state.steering;
+        match context.message().update.clone() {
+            SteeringUpdate::Freeze { reason } => {
+                steering.frozen = true;
+                steering.reason = reason.or(steering.reason.take());
+            }
+            SteeringUpdate::Unfreeze => steering.frozen = false,
+            SteeringUpdate::Bias {
+                bias,
+                focus,
+                ticks,
+                reason,
+            } => {
+                steering.bias = bias;
+                steering.focus = focus;
+                steering.until_tick = Some(tick + ticks);
+                steering.reason = reason.or(steering.reason.take());
             }
         }
+        info!(region_id = %actor.model.region_id, steering = ?steering, "Region steered");
+        Reply::ready()
+    });
 
+    // Handle NoPatchProposed - async mode: allow the next wake to ask again
+    actor.mutate_on::<NoPatchProposed>(|actor, _context| {
+        actor.model.proposal_in_flight = false;
         Reply::ready()
     });
 
@@ -227,10 +538,28 @@ fn configure_region_actor(actor: &mut ManagedActor<Idle, RegionActorState>) {
     actor.act_on::<QueryPressure>(|actor, context| {
         let msg = context.message().clone();
         let broker = actor.broker().clone();
-        let region_id = actor.model.region_id.clone();
-        let kind = actor.model.kind.clone();
-        let content = actor.model.content.clone();
-        let metadata = actor.model.metadata.clone();
+
+        // Summary queries skip the content and state clones
+        let tick = actor.model.decay_tick;
+        if msg.summary_only {
+            let state = &actor.model.state;
+            let total_pressure = state.pressure_ema.values().sum();
+            let summary = PressureSummary {
+                correlation_id: msg.correlation_id,
+                region_id: actor.model.region_id.clone(),
+                parent: actor.model.parent.clone(),
+                total_pressure,
+                steered_pressure: state.steering.steer(total_pressure, tick),
+                pressures: state.pressure_ema.clone(),
+                is_inhibited: state.is_inhibited(msg.now_ms),
+                steering: active_steering(&actor.model),
+            };
+            return Reply::pending(async move {
+                broker.broadcast(summary).await;
+            });
+        }
+
+        let view = current_view(&actor.model);
         let state = actor.model.state.clone();
         let signals = actor.model.signals.clone();
 
@@ -240,16 +569,12 @@ fn configure_region_actor(actor: &mut ManagedActor<Idle, RegionActorState>) {
 
         let response = PressureResponse {
             correlation_id: msg.correlation_id,
-            region_id: region_id.clone(),
+            region_id: view.id.clone(),
             total_pressure,
+            steered_pressure: state.steering.steer(total_pressure, tick),
             is_inhibited,
             state,
-            view: RegionView {
-                id: region_id,
-                kind,
-                content,
-                metadata,
-            },
+            view,
             signals,
         };
 
@@ -272,6 +597,7 @@ fn configure_region_actor(actor: &mut ManagedActor<Idle, RegionActorState>) {
 
         // Save pending validation state
         let validation_id = msg.correlation_id.clone();
+        let hash_before = current_view(&actor.model).content_hash();
         actor.model.pending_validations.insert(
             validation_id.clone(),
             PendingValidation {
@@ -280,6 +606,14 @@ fn configure_region_actor(actor: &mut ManagedActor<Idle, RegionActorState>) {
                 now_ms: msg.now_ms,
                 inhibit_ms: msg.inhibit_ms,
                 rationale: msg.patch.rationale.clone(),
+                origin: msg.origin,
+                tick: msg.tick,
+                expected_delta: weighted_expected_delta(
+                    &actor.model.pressure_axes,
+                    &actor.model.kind,
+                    &msg.patch.expected_delta,
+                ),
+                hash_before,
             },
         );
 
@@ -298,7 +632,7 @@ fn configure_region_actor(actor: &mut ManagedActor<Idle, RegionActorState>) {
 
     // Handle EvaluatePatchResponse - use coordinator's clone-based validation result
     actor.mutate_on::<EvaluatePatchResponse>(|actor, context| {
-        let msg = context.message().clone();
+        let mut msg = context.message().clone();
         let coordinator = actor.model.coordinator.clone();
         let region_id = actor.model.region_id.clone();
 
@@ -307,6 +641,9 @@ fn configure_region_actor(actor: &mut ManagedActor<Idle, RegionActorState>) {
             return Reply::ready();
         };
 
+        // Any evaluation outcome settles an async-mode proposal request
+        actor.model.proposal_in_flight = false;
+
         // Retrieve pending validation state
         let Some(pending) = actor.model.pending_validations.remove(&msg.correlation_id) else {
             warn!(
@@ -317,23 +654,37 @@ fn configure_region_actor(actor: &mut ManagedActor<Idle, RegionActorState>) {
             return Reply::ready();
         };
 
-        // Use coordinator's clone-based evaluation result
+        // Use coordinator's clone-based evaluation result; frozen regions take no patches
+        if actor.model.state.steering.frozen {
+            msg.should_accept = false;
+            msg.rejection = Some(Rejection::Frozen(region_id.clone()));
+        }
         if !msg.should_accept {
-            warn!(
-                region_id = %region_id,
-                delta = msg.pressure_delta,
-                "Patch rejected - no improvement (coordinator validation)"
-            );
+            if let Some(rejection) = &msg.rejection {
+                warn!(
+                    region_id = %region_id,
+                    delta = msg.pressure_delta,
+                    rejection = %rejection,
+                    "Patch rejected - axis constraint (coordinator validation)"
+                );
+            } else {
+                warn!(
+                    region_id = %region_id,
+                    delta = msg.pressure_delta,
+                    "Patch rejected - no improvement (coordinator validation)"
+                );
+            }
             let result = RegionPatchResult {
                 correlation_id: msg.correlation_id,
                 region_id: region_id.clone(),
                 success: false,
-                new_content: None,
+                new_content: Some(msg.new_content),
                 pressure_delta: msg.pressure_delta,
-                error: Some(format!(
-                    "Patch provides no improvement (delta={:.2})",
-                    msg.pressure_delta
-                )),
+                expected_delta: pending.expected_delta,
+                origin: pending.origin,
+                rejection: Some(msg.rejection.unwrap_or(Rejection::NoImprovement {
+                    delta: msg.pressure_delta,
+                })),
             };
             return Reply::pending(async move {
                 coordinator.send(result).await;
@@ -352,7 +703,28 @@ fn configure_region_actor(actor: &mut ManagedActor<Idle, RegionActorState>) {
         actor.model.state.confidence = (actor.model.state.confidence + 0.05).min(1.0);
         actor.model.state.suppress_until_ms = Some(pending.now_ms + pending.inhibit_ms);
         actor.model.state.last_updated_ms = pending.now_ms;
-        actor.model.state.provenance.push(pending.rationale);
+        let hash_after = current_view(&actor.model).content_hash();
+        let record = ProvenanceRecord {
+            tick: pending.tick,
+            timestamp_ms: pending.now_ms,
+            actor_name: pending.origin.actor_name.clone(),
+            model: pending.origin.model.clone(),
+            sampling_band: pending.origin.sampling_band.clone(),
+            rationale: pending.rationale,
+            transforms: pending.origin.transforms.clone(),
+            expected_delta: pending.expected_delta,
+            actual_delta: msg.pressure_delta,
+            hash_before: pending.hash_before,
+            hash_after,
+            steering: active_steering(&actor.model),
+        };
+        let limit = actor.model.provenance_limit;
+        actor.model.state.record_provenance(record, limit);
+
+        // In async mode, re-measure right away so our pressure reflects the change
+        if actor.model.autonomy.is_some() {
+            measure_self(&mut actor.model);
+        }
 
         let result = RegionPatchResult {
             correlation_id: msg.correlation_id,
@@ -360,7 +732,9 @@ fn configure_region_actor(actor: &mut ManagedActor<Idle, RegionActorState>) {
             success: true,
             new_content: Some(msg.new_content),
             pressure_delta: msg.pressure_delta,
-            error: None,
+            expected_delta: pending.expected_delta,
+            origin: pending.origin,
+            rejection: None,
         };
 
         Reply::pending(async move {
@@ -368,11 +742,125 @@ fn configure_region_actor(actor: &mut ManagedActor<Idle, RegionActorState>) {
         })
     });
 
+    // Handle QueryProvenance - report patch history via broker broadcast
+    actor.act_on::<QueryProvenance>(|actor, context| {
+        let msg = context.message();
+        let report = ProvenanceReport {
+            correlation_id: msg.correlation_id.clone(),
+            region_id: actor.model.region_id.clone(),
+            records: actor.model.state.provenance.clone(),
+        };
+        let broker = actor.broker().clone();
+        Reply::pending(async move {
+            broker.broadcast(report).await;
+        })
+    });
+
     // Handle RefreshContent - update content from artifact
     actor.mutate_on::<RefreshContent>(|actor, context| {
         let msg = context.message();
         actor.model.content = msg.new_content.clone();
         actor.model.metadata = msg.metadata.clone();
+        actor.model.parent = msg.parent.clone();
+        actor.model.children = msg.children.clone();
         Reply::ready()
     });
 }
+
+/// Decay fitness, confidence and the pressure EMA since the last update.
+///
+/// Millisecond half-lives use the time since `last_updated_ms`; tick
+/// half-lives use the ticks since decay was last applied.
+fn apply_decay(model: &mut RegionActorState, now_ms: u64, tick: usize, decay: &DecayConfig) {
+    let dt_ms = now_ms.saturating_sub(model.state.last_updated_ms);
+    let dt_ticks = tick.saturating_sub(model.decay_tick);
+    let kind = &model.kind;
+
+    model.state.fitness *= decay.fitness_half_life(kind).retention(dt_ms, dt_ticks);
+    model.state.confidence *= decay.confidence_half_life(kind).retention(dt_ms, dt_ticks);
+    for (axis, pressure) in model.state.pressure_ema.iter_mut() {
+        if let Some(half_life) = decay.pressure_half_life(axis, kind) {
+            *pressure *= half_life.retention(dt_ms, dt_ticks);
+        }
+    }
+
+    model.state.last_updated_ms = now_ms;
+    model.decay_tick = tick;
+    model.decay = Some(decay.clone());
+}
+
+/// EMA smoothing factor before any decay settings have arrived.
+const DEFAULT_EMA_ALPHA: f64 = 0.2;
+
+/// Merge measured signals and fold them into the pressure EMA.
+fn record_signals(model: &mut RegionActorState, signals: &Signals) {
+    // Merge new signals into our signal map
+    for (key, value) in signals {
+        model.signals.insert(key.clone(), *value);
+    }
+
+    // Update pressure EMA for each axis
+    for axis in &model.pressure_axes {
+        let alpha = model
+            .decay
+            .as_ref()
+            .map_or(DEFAULT_EMA_ALPHA, |d| d.ema_alpha(&axis.name, &model.kind));
+        if let Some(signal_value) = signals.get(&axis.expr) {
+            let weight = axis
+                .kind_weights
+                .get(&model.kind)
+                .copied()
+                .unwrap_or(axis.weight);
+            let weighted_pressure = model.normalizer.observe(axis, *signal_value) * weight;
+
+            let current = model
+                .state
+                .pressure_ema
+                .get(&axis.name)
+                .copied()
+                .unwrap_or(weighted_pressure);
+            let new_ema = alpha * weighted_pressure + (1.0 - alpha) * current;
+            model.state.pressure_ema.insert(axis.name.clone(), new_ema);
+        }
+    }
+}
+
+/// The region's steering, if any is active on its current tick.
+fn active_steering(model: &RegionActorState) -> Option<Steering> {
+    let steering = &model.state.steering;
+    steering
+        .is_active(model.decay_tick)
+        .then(|| steering.clone())
+}
+
+/// Snapshot the region's current content as a view.
+fn current_view(model: &RegionActorState) -> RegionView {
+    RegionView {
+        id: model.region_id.clone(),
+        kind: model.kind.clone(),
+        content: model.content.clone(),
+        metadata: model.metadata.clone(),
+        parent: model.parent.clone(),
+        children: model.children.clone(),
+    }
+}
+
+/// Measure the region with every sensor and record the results (async mode).
+fn measure_self(model: &mut RegionActorState) {
+    let view = current_view(model);
+
+    let mut signals = Signals::new();
+    for sensor in &model.sensors {
+        match sensor.measure(&view) {
+            Ok(measured) => signals.extend(measured),
+            Err(e) => warn!(
+                region_id = %model.region_id,
+                sensor = sensor.name(),
+                error = %e,
+                "Sensor measurement failed"
+            ),
+        }
+    }
+
+    record_signals(model, &signals);
+}
@@ -1,28 +1,41 @@
 //! SensorActor: wraps a Sensor for concurrent measurement via acton-reactive.
 //!
 //! Uses the broker pub/sub pattern:
-//! - Subscribes to `MeasureRegion` broadcasts
+//! - Subscribes to `MeasureRegion` and batched `MeasureRegions` broadcasts
 //! - Broadcasts `SensorReady` on start for coordinator tracking
-//! - Broadcasts `MeasurementResult` responses
+//! - Broadcasts `MeasurementResult` (or `MeasurementBatch`) responses, and
+//!   `MeasurementFailed` when the sensor returns an error
+//!
+//! Results for local (non-global) sensors are cached by the content hash of
+//! the region and its neighbors, so re-measuring unchanged content does not
+//! re-run the sensor.
 
 use std::sync::Arc;
 
 use acton_reactive::prelude::*;
+use dashmap::DashMap;
 
-use crate::messages::{MeasureRegion, MeasurementResult, SensorReady};
-use crate::pressure::Sensor;
+use crate::messages::{
+    MeasureRegion, MeasureRegions, MeasurementBatch, MeasurementFailed, MeasurementResult,
+    SensorReady,
+};
+use crate::pressure::{Sensor, Signals};
+use crate::region::{NeighborView, RegionId, RegionView};
 
 /// Actor state for SensorActor.
 #[derive(Default, Clone)]
 pub struct SensorActorState {
     /// The wrapped sensor implementation
     sensor: Option<Arc<dyn Sensor>>,
+    /// Last measurement per region, keyed by content hash
+    cache: Arc<DashMap<RegionId, (u64, Signals)>>,
 }
 
 impl std::fmt::Debug for SensorActorState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SensorActorState")
             .field("sensor", &self.sensor.as_ref().map(|s| s.name()))
+            .field("cached_regions", &self.cache.len())
             .finish()
     }
 }
@@ -63,15 +76,17 @@ impl SensorActor {
 
         // Subscribe to MeasureRegion broadcasts BEFORE starting
         actor.handle().subscribe::<MeasureRegion>().await;
+        actor.handle().subscribe::<MeasureRegions>().await;
 
         // Broadcast SensorReady on start so coordinator knows about us
         // Include our ERN since broker broadcasts don't preserve sender identity
         actor.after_start(|actor| {
             let broker = actor.broker().clone();
             let sensor_ern = actor.handle().name().to_string();
+            let handle = actor.handle().clone();
 
             Reply::pending(async move {
-                broker.broadcast(SensorReady { sensor_ern }).await;
+                broker.broadcast(SensorReady { sensor_ern, handle }).await;
             })
         });
 
@@ -79,6 +94,7 @@ impl SensorActor {
         actor.act_on::<MeasureRegion>(|actor, context| {
             let msg = context.message().clone();
             let sensor = actor.model.sensor.clone();
+            let cache = actor.model.cache.clone();
             let broker = actor.broker().clone();
 
             let Some(sensor) = sensor else {
@@ -89,7 +105,13 @@ impl SensorActor {
             let sensor_name = sensor.name().to_string();
 
             // Measure synchronously
-            let result = sensor.measure(&msg.region_view);
+            let result = measure_cached(
+                sensor.as_ref(),
+                &cache,
+                &msg.region_id,
+                &msg.region_view,
+                &msg.neighbors,
+            );
 
             match result {
                 Ok(signals) => {
@@ -112,11 +134,104 @@ impl SensorActor {
                         error = %e,
                         "Sensor measurement failed"
                     );
-                    Reply::ready()
+                    let failed = MeasurementFailed {
+                        correlation_id: msg.correlation_id,
+                        region_id: msg.region_id,
+                        sensor_name,
+                    };
+                    Reply::pending(async move {
+                        broker.broadcast(failed).await;
+                    })
                 }
             }
         });
 
+        // act_on = concurrent (batches measured in parallel)
+        actor.act_on::<MeasureRegions>(|actor, context| {
+            let msg = context.message().clone();
+            let sensor = actor.model.sensor.clone();
+            let cache = actor.model.cache.clone();
+            let broker = actor.broker().clone();
+
+            let Some(sensor) = sensor else {
+                tracing::error!("SensorActor: sensor not initialized");
+                return Reply::ready();
+            };
+
+            let mut results = Vec::new();
+            let mut failed = Vec::new();
+            for (region_id, view) in msg.regions {
+                let neighbors = msg.neighbors.get(&region_id).map_or(&[][..], Vec::as_slice);
+                match measure_cached(sensor.as_ref(), &cache, &region_id, &view, neighbors) {
+                    Ok(signals) => results.push((region_id, signals)),
+                    Err(e) => {
+                        tracing::warn!(
+                            sensor = sensor.name(),
+                            region = %region_id,
+                            error = %e,
+                            "Sensor measurement failed"
+                        );
+                        failed.push(region_id);
+                    }
+                }
+            }
+
+            let batch = MeasurementBatch {
+                correlation_id: msg.correlation_id,
+                sensor_name: sensor.name().to_string(),
+                results,
+                failed,
+            };
+
+            // Broadcast results (coordinator subscribes to MeasurementBatch)
+            Reply::pending(async move {
+                broker.broadcast(batch).await;
+            })
+        });
+
         actor.start().await
     }
 }
+
+/// Measure a region, reusing the cached result if neither its content nor its
+/// neighborhood has changed.
+fn measure_cached(
+    sensor: &dyn Sensor,
+    cache: &DashMap<RegionId, (u64, Signals)>,
+    region_id: &RegionId,
+    view: &RegionView,
+    neighbors: &[NeighborView],
+) -> anyhow::Result<Signals> {
+    if sensor.is_global() {
+        return sensor.measure_with_neighbors(view, neighbors);
+    }
+
+    let content_hash = neighborhood_hash(view, neighbors);
+    if let Some(entry) = cache.get(region_id)
+        && entry.0 == content_hash
+    {
+        return Ok(entry.1.clone());
+    }
+
+    let signals = sensor.measure_with_neighbors(view, neighbors)?;
+    cache.insert(region_id.clone(), (content_hash, signals.clone()));
+    Ok(signals)
+}
+
+/// Content hash of a region combined with its neighbors' hashes and weights.
+fn neighborhood_hash(view: &RegionView, neighbors: &[NeighborView]) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let content_hash = view.content_hash();
+    if neighbors.is_empty() {
+        return content_hash;
+    }
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    content_hash.hash(&mut hasher);
+    for neighbor in neighbors {
+        neighbor.view.content_hash().hash(&mut hasher);
+        neighbor.weight.to_bits().hash(&mut hasher);
+    }
+    hasher.finish()
+}
@@ -0,0 +1,136 @@
+//! PatchActorSupervisor: restarts failed patch actors from a factory.
+//!
+//! Uses the broker pub/sub pattern:
+//! - Subscribes to `PatchActorGone` broadcasts (from patch actors or coordinator eviction)
+//! - Spawns a replacement via the factory for `Failed` and `TimedOut` departures
+//! - Registers the replacement by sending `PatchActorReady` to the coordinator
+//!
+//! Replacements are registered directly because they start after the
+//! coordinator's one-off `CoordinatorReady` broadcast.
+
+use std::sync::Arc;
+
+use acton_reactive::prelude::*;
+use futures::future::BoxFuture;
+use tracing::{info, warn};
+
+use crate::messages::{DepartureReason, PatchActorGone, PatchActorReady};
+
+/// Spawns a patch actor and returns its handle.
+///
+/// Receives a runtime handle and the restart number (1-based), which can be
+/// used to derive a unique actor name or seed.
+pub type PatchActorFactory =
+    Arc<dyn Fn(ActorRuntime, usize) -> BoxFuture<'static, ActorHandle> + Send + Sync>;
+
+/// Actor state for PatchActorSupervisor.
+#[derive(Default, Clone)]
+pub struct PatchActorSupervisorState {
+    /// Factory used to spawn replacement actors
+    factory: Option<PatchActorFactory>,
+    /// Runtime the replacements are spawned into
+    runtime: Option<ActorRuntime>,
+    /// Coordinator that replacements register with
+    coordinator: Option<ActorHandle>,
+    /// Maximum replacements over the whole run
+    max_restarts: usize,
+    /// Replacements spawned so far
+    restarts: usize,
+}
+
+impl std::fmt::Debug for PatchActorSupervisorState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PatchActorSupervisorState")
+            .field("max_restarts", &self.max_restarts)
+            .field("restarts", &self.restarts)
+            .finish()
+    }
+}
+
+/// Supervisor that replaces patch actors which fail or miss heartbeats.
+///
+/// Deliberate departures (`DepartureReason::Stopped`) are not replaced, so
+/// callers can scale down by stopping an actor and broadcasting `PatchActorGone`.
+pub struct PatchActorSupervisor {
+    /// Factory used to spawn replacement actors
+    pub factory: PatchActorFactory,
+    /// Maximum replacements over the whole run
+    pub max_restarts: usize,
+}
+
+impl PatchActorSupervisor {
+    /// Create a new PatchActorSupervisor.
+    pub fn new(factory: PatchActorFactory, max_restarts: usize) -> Self {
+        Self {
+            factory,
+            max_restarts,
+        }
+    }
+
+    /// Spawn this supervisor in the given runtime.
+    pub async fn spawn(self, runtime: &mut ActorRuntime, coordinator: ActorHandle) -> ActorHandle {
+        let mut actor = runtime
+            .new_actor_with_name::<PatchActorSupervisorState>("PatchActorSupervisor".to_string());
+
+        actor.model.factory = Some(self.factory);
+        actor.model.runtime = Some(runtime.clone());
+        actor.model.coordinator = Some(coordinator);
+        actor.model.max_restarts = self.max_restarts;
+
+        // Subscribe to departures BEFORE starting
+        actor.handle().subscribe::<PatchActorGone>().await;
+
+        actor.mutate_on::<PatchActorGone>(|actor, context| {
+            let msg = context.message().clone();
+
+            if msg.reason == DepartureReason::Stopped {
+                return Reply::ready();
+            }
+
+            if actor.model.restarts >= actor.model.max_restarts {
+                warn!(
+                    patch_actor_ern = %msg.actor_ern,
+                    reason = ?msg.reason,
+                    max_restarts = actor.model.max_restarts,
+                    "Patch actor lost and restart budget exhausted"
+                );
+                return Reply::ready();
+            }
+
+            let (Some(factory), Some(runtime), Some(coordinator)) = (
+                actor.model.factory.clone(),
+                actor.model.runtime.clone(),
+                actor.model.coordinator.clone(),
+            ) else {
+                warn!("PatchActorSupervisor: not initialized");
+                return Reply::ready();
+            };
+
+            actor.model.restarts += 1;
+            let restart = actor.model.restarts;
+
+            Reply::pending(async move {
+                // Factory futures need not be Sync, so drive them on their own task
+                let Ok(handle) = tokio::spawn(factory(runtime, restart)).await else {
+                    warn!(restart, "Patch actor factory panicked");
+                    return;
+                };
+                let actor_ern = handle.name();
+
+                info!(
+                    replaced = %msg.actor_ern,
+                    replacement = %actor_ern,
+                    reason = ?msg.reason,
+                    restart,
+                    "Restarted patch actor"
+                );
+
+                coordinator
+                    .send(PatchActorReady { actor_ern, handle })
+                    .await;
+            })
+        });
+
+        actor.start().await
+    }
+}
@@ -1,5 +1,6 @@
 //! Artifact trait: the interface for mutable objects under pressure.
 
+use crate::pressure::Signals;
 use crate::region::{Patch, RegionId, RegionView};
 
 /// An artifact is any mutable object that can be refined through pressure-driven coordination.
@@ -30,6 +31,15 @@ pub trait Artifact: Send + Sync {
         None
     }
 
+    /// Optional: callback invoked when a snapshot replaces the live artifact
+    /// (stagnation restart, see `StagnationResponse::Restart`).
+    ///
+    /// Use this to re-publish state others read, such as a schedule shared
+    /// with sensors.
+    ///
+    /// Default implementation does nothing.
+    fn on_restored(&mut self) {}
+
     /// Optional: get the full source content of the artifact.
     ///
     /// Used for saving the final state after optimization.
@@ -72,6 +82,20 @@ pub trait Artifact: Send + Sync {
         (true, 0.0)
     }
 
+    /// Optional: how each signal of the whole artifact would change under a patch.
+    ///
+    /// Returns after-minus-before values (positive = the signal grows), keyed
+    /// like sensor signals. Used to enforce hard and prioritized pressure axes
+    /// (see `constraint`) on top of `evaluate_patch`. Every hard axis's signal
+    /// (in Pareto mode, every axis's) must be reported, even when unchanged; a
+    /// patch that leaves one out is rejected.
+    ///
+    /// Default implementation returns None (prioritized axes are not enforced,
+    /// and with a hard axis or Pareto acceptance no patch is accepted).
+    fn evaluate_patch_signals(&self, _patch: &Patch) -> Option<Signals> {
+        None
+    }
+
     /// Optional: get the total pressure of the artifact.
     ///
     /// Returns the actual pressure computed directly from the artifact state.
@@ -82,4 +106,58 @@ pub trait Artifact: Send + Sync {
     fn total_pressure(&self) -> Option<f64> {
         None
     }
+
+    /// Optional: regions whose signals may change when `id` is patched.
+    ///
+    /// The coordinator re-measures these alongside the patched region on the
+    /// next tick; all other regions reuse their cached signals.
+    ///
+    /// Default implementation returns no coupled regions.
+    fn coupled_regions(&self, _id: &RegionId) -> Vec<RegionId> {
+        Vec::new()
+    }
+
+    /// Optional: the regions adjacent to `id`, with relation weights.
+    ///
+    /// Neighbor views are passed to `Sensor::measure_with_neighbors`, neighbors
+    /// of a patched region are re-measured, and with pressure diffusion enabled
+    /// a region's effective pressure includes its neighbors' weighted pressure.
+    ///
+    /// Default implementation returns no neighbors.
+    fn neighbors(&self, _id: &RegionId) -> Vec<(RegionId, f64)> {
+        Vec::new()
+    }
+
+    /// Optional: split a region into smaller regions covering the same content.
+    ///
+    /// Returns the IDs replacing `id`, or `None` if the region cannot be split.
+    /// The kernel calls this for regions that stay above
+    /// `GranularityConfig::split_pressure` (see `KernelConfig::granularity`).
+    ///
+    /// Default implementation never splits.
+    fn split_region(&mut self, _id: &RegionId) -> Option<Vec<RegionId>> {
+        None
+    }
+
+    /// Optional: merge neighboring regions into one.
+    ///
+    /// Returns the ID replacing `ids`, or `None` if they cannot be merged.
+    /// The kernel calls this for neighbors that stay below
+    /// `GranularityConfig::merge_pressure`.
+    ///
+    /// Default implementation never merges.
+    fn merge_regions(&mut self, _ids: &[RegionId]) -> Option<RegionId> {
+        None
+    }
+
+    /// Optional: a random change to a region, to escape a pressure plateau.
+    ///
+    /// Called for the highest-pressure regions when the kernel's stagnation
+    /// response is `StagnationResponse::Perturb` (see
+    /// `KernelConfig::stagnation`). The same seed should give the same patch.
+    ///
+    /// Default implementation returns None (regions are only kicked).
+    fn perturb_region(&self, _id: &RegionId, _seed: u64) -> Option<Patch> {
+        None
+    }
 }
@@ -1,13 +1,16 @@
 //! Configuration types for the kernel.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::normalize::Normalization;
 
 /// Top-level kernel configuration.
 ///
 /// This defines the pressure landscape, decay dynamics, and selection criteria.
 /// Loaded from TOML/JSON at runtime.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct KernelConfig {
     /// Tick interval in milliseconds (for decay calculations and internal tick rate)
     pub tick_interval_ms: u64,
@@ -20,6 +23,10 @@ pub struct KernelConfig {
     #[serde(default)]
     pub stable_threshold: usize,
 
+    /// Execution mode: global tick barrier or autonomous regions
+    #[serde(default)]
+    pub mode: ExecutionMode,
+
     /// Pressure axis definitions
     pub pressure_axes: Vec<PressureAxisConfig>,
 
@@ -31,10 +38,63 @@ pub struct KernelConfig {
 
     /// Patch selection configuration
     pub selection: SelectionConfig,
+
+    /// Patch actor liveness tracking
+    #[serde(default)]
+    pub membership: MembershipConfig,
+
+    /// Dirty-region measurement configuration
+    #[serde(default)]
+    pub measurement: MeasurementConfig,
+
+    /// Message batching and mailbox configuration
+    #[serde(default)]
+    pub messaging: MessagingConfig,
+
+    /// Per-region provenance history
+    #[serde(default)]
+    pub provenance: ProvenanceConfig,
+
+    /// How patch actor reputation feeds into selection and dispatch
+    #[serde(default)]
+    pub reputation: ReputationConfig,
+
+    /// Pressure diffusion between neighboring regions
+    #[serde(default)]
+    pub diffusion: DiffusionConfig,
+
+    /// Adaptive region splitting and merging
+    #[serde(default)]
+    pub granularity: GranularityConfig,
+
+    /// What the kernel does when pressure stops improving
+    #[serde(default)]
+    pub stagnation: StagnationConfig,
+
+    /// Runtime checks of the convergence-theorem assumptions
+    #[serde(default)]
+    pub monitor: MonitorConfig,
+
+    /// Pheromone deposits left by accepted and rejected patches
+    #[serde(default)]
+    pub pheromones: PheromoneConfig,
+}
+
+/// How the kernel schedules measurement, proposals, and patches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionMode {
+    /// Every tick measures all regions, then proposes, then patches, in lockstep
+    #[default]
+    Tick,
+    /// Each region wakes every `tick_interval_ms`, decays, re-measures, and
+    /// requests its own proposals; validated patches apply immediately.
+    /// Ticks only observe pressure and drive global stopping.
+    Async,
 }
 
 /// Configuration for a single pressure axis.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct PressureAxisConfig {
     /// Unique name for this axis
     pub name: String,
@@ -50,10 +110,26 @@ pub struct PressureAxisConfig {
     /// Per-region-kind weight overrides
     #[serde(default)]
     pub kind_weights: HashMap<String, f64>,
+
+    /// Reject any patch that increases this axis, whatever it does to the others
+    #[serde(default)]
+    pub hard: bool,
+
+    /// Lexicographic rank: higher-priority axes are compared first and lower
+    /// ones only break ties (all equal = one weighted sum)
+    #[serde(default)]
+    pub priority: i32,
+
+    /// How the signal is rescaled before the weight is applied
+    #[serde(default)]
+    pub normalization: Normalization,
 }
 
 /// Decay configuration: how quickly state erodes without reinforcement.
-#[derive(Debug, Clone, Deserialize)]
+///
+/// Overrides resolve most specific first: per axis (pressure EMA only), then
+/// per region kind, then the global values.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct DecayConfig {
     /// Half-life for fitness decay (milliseconds)
     pub fitness_half_life_ms: u64,
@@ -63,10 +139,116 @@ pub struct DecayConfig {
 
     /// Smoothing factor for pressure EMA (0.0 to 1.0)
     pub ema_alpha: f64,
+
+    /// Fitness half-life in ms or ticks; replaces `fitness_half_life_ms` when set
+    #[serde(default)]
+    pub fitness_half_life: Option<HalfLife>,
+
+    /// Confidence half-life in ms or ticks; replaces `confidence_half_life_ms` when set
+    #[serde(default)]
+    pub confidence_half_life: Option<HalfLife>,
+
+    /// Half-life of the pressure EMA between measurements (None = no decay)
+    #[serde(default)]
+    pub pressure_half_life: Option<HalfLife>,
+
+    /// Overrides per pressure axis name (pressure half-life and EMA alpha only)
+    #[serde(default)]
+    pub axes: HashMap<String, DecayOverride>,
+
+    /// Overrides per region kind
+    #[serde(default)]
+    pub kinds: HashMap<String, DecayOverride>,
+}
+
+impl DecayConfig {
+    /// Fitness half-life for regions of `kind`.
+    pub fn fitness_half_life(&self, kind: &str) -> HalfLife {
+        self.kinds
+            .get(kind)
+            .and_then(|o| o.fitness_half_life)
+            .or(self.fitness_half_life)
+            .unwrap_or(HalfLife::Ms(self.fitness_half_life_ms))
+    }
+
+    /// Confidence half-life for regions of `kind`.
+    pub fn confidence_half_life(&self, kind: &str) -> HalfLife {
+        self.kinds
+            .get(kind)
+            .and_then(|o| o.confidence_half_life)
+            .or(self.confidence_half_life)
+            .unwrap_or(HalfLife::Ms(self.confidence_half_life_ms))
+    }
+
+    /// Pressure EMA half-life for `axis` in regions of `kind` (None = no decay).
+    pub fn pressure_half_life(&self, axis: &str, kind: &str) -> Option<HalfLife> {
+        self.axes
+            .get(axis)
+            .and_then(|o| o.pressure_half_life)
+            .or_else(|| self.kinds.get(kind).and_then(|o| o.pressure_half_life))
+            .or(self.pressure_half_life)
+    }
+
+    /// EMA smoothing factor for `axis` in regions of `kind`.
+    pub fn ema_alpha(&self, axis: &str, kind: &str) -> f64 {
+        self.axes
+            .get(axis)
+            .and_then(|o| o.ema_alpha)
+            .or_else(|| self.kinds.get(kind).and_then(|o| o.ema_alpha))
+            .unwrap_or(self.ema_alpha)
+    }
+}
+
+/// A half-life in wall-clock milliseconds or in kernel ticks.
+///
+/// Tick half-lives make decay independent of how long each tick takes. In
+/// async mode a region counts its own wakes as ticks. Zero disables decay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HalfLife {
+    /// Milliseconds
+    Ms(u64),
+    /// Ticks
+    Ticks(u64),
+}
+
+impl HalfLife {
+    /// Fraction of a value left after `dt_ms` milliseconds or `dt_ticks` ticks,
+    /// whichever this half-life is measured in.
+    pub fn retention(&self, dt_ms: u64, dt_ticks: usize) -> f64 {
+        let (elapsed, half_life) = match *self {
+            HalfLife::Ms(half_life) => (dt_ms as f64, half_life),
+            HalfLife::Ticks(half_life) => (dt_ticks as f64, half_life),
+        };
+        if half_life == 0 {
+            return 1.0;
+        }
+        (-std::f64::consts::LN_2 / half_life as f64 * elapsed).exp()
+    }
+}
+
+/// Decay settings for one axis or region kind; unset fields fall through.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct DecayOverride {
+    /// Fitness half-life (region kinds only)
+    #[serde(default)]
+    pub fitness_half_life: Option<HalfLife>,
+
+    /// Confidence half-life (region kinds only)
+    #[serde(default)]
+    pub confidence_half_life: Option<HalfLife>,
+
+    /// Pressure EMA half-life
+    #[serde(default)]
+    pub pressure_half_life: Option<HalfLife>,
+
+    /// Pressure EMA smoothing factor
+    #[serde(default)]
+    pub ema_alpha: Option<f64>,
 }
 
 /// Activation configuration: when to trigger action proposals.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ActivationConfig {
     /// Minimum total weighted pressure to trigger proposals
     pub min_total_pressure: f64,
@@ -76,10 +258,337 @@ pub struct ActivationConfig {
 }
 
 /// Selection configuration: how to choose among candidate patches.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct SelectionConfig {
     /// Minimum expected improvement to accept a patch
     pub min_expected_improvement: f64,
+
+    /// How validated patches are judged across pressure axes
+    #[serde(default)]
+    pub acceptance: AcceptanceMode,
+}
+
+/// How the per-axis changes of a patch decide whether it is accepted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AcceptanceMode {
+    /// The artifact's weighted pressure sum decides (subject to hard and
+    /// prioritized axes)
+    #[default]
+    Weighted,
+    /// A patch may not worsen any axis; one that improves at least one axis
+    /// dominates the current state and is accepted. Non-dominated snapshots
+    /// are archived (see `crate::pareto`).
+    Pareto,
+}
+
+/// Membership configuration: how the coordinator tracks patch actor liveness.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct MembershipConfig {
+    /// Evict patch actors that have not been heard from for this long
+    /// (milliseconds, 0 = disabled). Heartbeats and proposals both count.
+    #[serde(default)]
+    pub heartbeat_timeout_ms: u64,
+
+    /// Give up on a dispatched proposal after this long and hand the region
+    /// to another actor (milliseconds, 0 = wait forever)
+    #[serde(default)]
+    pub proposal_timeout_ms: u64,
+}
+
+impl MembershipConfig {
+    /// How often kernel-spawned patch actors heartbeat: a third of the
+    /// heartbeat timeout, or never when eviction is disabled.
+    pub fn heartbeat_interval(&self) -> Duration {
+        if self.heartbeat_timeout_ms == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_millis((self.heartbeat_timeout_ms / 3).max(1))
+    }
+}
+
+/// Measurement configuration: which regions are re-measured each tick.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MeasurementConfig {
+    /// Re-measure every region every N ticks; other ticks only measure regions
+    /// patched on the previous tick and the regions coupled to them.
+    /// 1 = measure everything every tick (the default), 0 = only the first
+    /// tick is full.
+    #[serde(default = "default_full_remeasure_interval")]
+    pub full_remeasure_interval: usize,
+}
+
+fn default_full_remeasure_interval() -> usize {
+    1
+}
+
+impl Default for MeasurementConfig {
+    fn default() -> Self {
+        Self {
+            full_remeasure_interval: default_full_remeasure_interval(),
+        }
+    }
+}
+
+/// Messaging configuration: batching and mailbox sizes for large artifacts.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct MessagingConfig {
+    /// Regions per `MeasureRegions` message; also switches pressure queries to
+    /// content-free summaries with full state fetched only for selected regions
+    /// (0 = one message per region)
+    #[serde(default)]
+    pub batch_size: usize,
+
+    /// Coordinator mailbox capacity; senders wait when it is full
+    /// (0 = runtime default)
+    #[serde(default)]
+    pub coordinator_inbox_capacity: usize,
+}
+
+/// Provenance configuration: how much patch history each region keeps.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ProvenanceConfig {
+    /// Records kept per region; older records are dropped (0 = unbounded)
+    #[serde(default = "default_provenance_history_limit")]
+    pub history_limit: usize,
+}
+
+fn default_provenance_history_limit() -> usize {
+    64
+}
+
+impl Default for ProvenanceConfig {
+    fn default() -> Self {
+        Self {
+            history_limit: default_provenance_history_limit(),
+        }
+    }
+}
+
+/// Reputation configuration: how each actor's track record is used.
+///
+/// Both are opt-in; by default reputation is only tracked and reported.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ReputationConfig {
+    /// Multiply proposal scores by the proposer's reputation weight
+    #[serde(default)]
+    pub rescale_scores: bool,
+
+    /// Send more proposal requests to actors with higher reputation
+    #[serde(default)]
+    pub weighted_dispatch: bool,
+}
+
+/// Diffusion configuration: how much neighbor pressure a region feels.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct DiffusionConfig {
+    /// Fraction of neighbors' weighted pressure added to a region's own when
+    /// selecting regions for proposals (0.0 = no diffusion). Reported totals
+    /// are not diffused.
+    #[serde(default)]
+    pub rate: f64,
+}
+
+/// Granularity configuration: when regions are split or merged (tick mode).
+///
+/// Requires an artifact that implements `Artifact::split_region` and
+/// `Artifact::merge_regions`. Both rules are off by default.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct GranularityConfig {
+    /// Split a region whose pressure stays at or above this
+    #[serde(default)]
+    pub split_pressure: f64,
+
+    /// Consecutive ticks at or above `split_pressure` before splitting (0 = never split)
+    #[serde(default)]
+    pub split_after_ticks: usize,
+
+    /// Merge neighboring regions whose pressures all stay at or below this
+    #[serde(default)]
+    pub merge_pressure: f64,
+
+    /// Consecutive ticks at or below `merge_pressure` before merging (0 = never merge)
+    #[serde(default)]
+    pub merge_after_ticks: usize,
+}
+
+/// Stagnation configuration: how the kernel escapes a pressure plateau.
+///
+/// Each kick is reported as a `StagnationKick` in the `TickResult` of the
+/// tick it happened on. Off by default.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StagnationConfig {
+    /// Ticks without improvement before a kick (0 = never kick)
+    #[serde(default)]
+    pub patience: usize,
+
+    /// Pressure drop below the best so far that counts as improvement
+    #[serde(default)]
+    pub min_improvement: f64,
+
+    /// What a kick does
+    #[serde(default)]
+    pub response: StagnationResponse,
+
+    /// Regions perturbed per kick, highest pressure first
+    /// (`StagnationResponse::Perturb`)
+    #[serde(default = "default_perturb_regions")]
+    pub perturb_regions: usize,
+}
+
+fn default_perturb_regions() -> usize {
+    3
+}
+
+impl Default for StagnationConfig {
+    fn default() -> Self {
+        Self {
+            patience: 0,
+            min_improvement: 0.0,
+            response: StagnationResponse::Perturb,
+            perturb_regions: default_perturb_regions(),
+        }
+    }
+}
+
+/// What the kernel does once pressure has stagnated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StagnationResponse {
+    /// Perturb the highest-pressure regions (`Artifact::perturb_region`),
+    /// clear their inhibition and decayed fitness, and send their next
+    /// proposal requests with an exploration seed
+    #[default]
+    Perturb,
+    /// Restore the lowest-pressure artifact seen so far (`Artifact::snapshot`)
+    /// and kick every region with fresh seeds; falls back to `Perturb` when
+    /// the artifact cannot be snapshotted
+    Restart,
+}
+
+/// Monitor configuration: which convergence assumptions are checked at runtime.
+///
+/// Violations are reported as `AssumptionViolation`s in the `TickResult` of
+/// the tick they were seen on (see `crate::monitor`). Off by default.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+pub struct MonitorConfig {
+    /// Run the monitors at all
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// δ_min: smallest realized improvement an accepted patch may have
+    #[serde(default)]
+    pub min_delta: f64,
+
+    /// Declared coupling bound ε (`None` = coupling is not checked)
+    #[serde(default)]
+    pub coupling_bound: Option<f64>,
+
+    /// Total pressure increase tolerated before it counts as a violation
+    #[serde(default)]
+    pub pressure_tolerance: f64,
+}
+
+/// Pheromone configuration: what accepted and rejected patches leave behind.
+///
+/// Deposits evaporate every tick and are handed to patch actors with each
+/// `ProposeForRegion` (see `crate::pheromone`). Off by default.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PheromoneConfig {
+    /// Deposit, evaporate and hand out pheromones at all
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Weight kept per tick (0.95 = 5% evaporation)
+    #[serde(default = "default_pheromone_decay")]
+    pub decay_factor: f64,
+
+    /// Weight added when a deposit is reinforced
+    #[serde(default = "default_pheromone_reinforcement")]
+    pub reinforcement: f64,
+
+    /// Cap on a reinforced deposit's weight
+    #[serde(default = "default_pheromone_max_weight")]
+    pub max_weight: f64,
+
+    /// Deposits that evaporate below this weight are evicted
+    #[serde(default = "default_pheromone_eviction")]
+    pub eviction_threshold: f64,
+
+    /// Deposits kept per region and kind (lightest evicted first)
+    #[serde(default = "default_pheromone_capacity")]
+    pub max_per_region: usize,
+
+    /// Deposits of each kind sent with a `ProposeForRegion`
+    #[serde(default = "default_pheromone_per_proposal")]
+    pub per_proposal: usize,
+}
+
+fn default_pheromone_decay() -> f64 {
+    0.95
+}
+
+fn default_pheromone_reinforcement() -> f64 {
+    0.3
+}
+
+fn default_pheromone_max_weight() -> f64 {
+    2.0
+}
+
+fn default_pheromone_eviction() -> f64 {
+    0.1
+}
+
+fn default_pheromone_capacity() -> usize {
+    10
+}
+
+fn default_pheromone_per_proposal() -> usize {
+    3
+}
+
+impl Default for PheromoneConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            decay_factor: default_pheromone_decay(),
+            reinforcement: default_pheromone_reinforcement(),
+            max_weight: default_pheromone_max_weight(),
+            eviction_threshold: default_pheromone_eviction(),
+            max_per_region: default_pheromone_capacity(),
+            per_proposal: default_pheromone_per_proposal(),
+        }
+    }
+}
+
+/// Migration configuration: how islands exchange regions (see `crate::island`).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MigrationConfig {
+    /// Migrate every N ticks (0 = islands never migrate)
+    #[serde(default = "default_migration_interval")]
+    pub interval: usize,
+
+    /// Lowest-pressure regions each island offers per migration
+    #[serde(default = "default_migrants")]
+    pub migrants: usize,
+}
+
+fn default_migration_interval() -> usize {
+    5
+}
+
+fn default_migrants() -> usize {
+    2
+}
+
+impl Default for MigrationConfig {
+    fn default() -> Self {
+        Self {
+            interval: default_migration_interval(),
+            migrants: default_migrants(),
+        }
+    }
 }
 
 impl Default for KernelConfig {
@@ -88,11 +597,17 @@ impl Default for KernelConfig {
             tick_interval_ms: 250,
             max_ticks: 0,        // unlimited
             stable_threshold: 3, // stop after 3 ticks with no patches
+            mode: ExecutionMode::Tick,
             pressure_axes: Vec::new(),
             decay: DecayConfig {
                 fitness_half_life_ms: 600_000,      // 10 minutes
                 confidence_half_life_ms: 1_800_000, // 30 minutes
                 ema_alpha: 0.2,
+                fitness_half_life: None,
+                confidence_half_life: None,
+                pressure_half_life: None,
+                axes: HashMap::new(),
+                kinds: HashMap::new(),
             },
             activation: ActivationConfig {
                 min_total_pressure: 0.8,
@@ -100,7 +615,18 @@ impl Default for KernelConfig {
             },
             selection: SelectionConfig {
                 min_expected_improvement: 0.15,
+                acceptance: AcceptanceMode::Weighted,
             },
+            membership: MembershipConfig::default(),
+            measurement: MeasurementConfig::default(),
+            messaging: MessagingConfig::default(),
+            provenance: ProvenanceConfig::default(),
+            reputation: ReputationConfig::default(),
+            diffusion: DiffusionConfig::default(),
+            granularity: GranularityConfig::default(),
+            stagnation: StagnationConfig::default(),
+            monitor: MonitorConfig::default(),
+            pheromones: PheromoneConfig::default(),
         }
     }
 }
@@ -0,0 +1,379 @@
+//! Hard constraints and lexicographic axis priorities.
+//!
+//! By default a patch is judged on the artifact's single weighted pressure
+//! sum, so a worse score on one axis can be bought with better scores on
+//! others. Axes marked `hard` cannot be traded: any patch that increases one
+//! is rejected outright. Axes with different `priority` values are compared
+//! tier by tier, highest first, and the first tier a patch changes decides;
+//! the lowest tier falls back to the artifact's own verdict.
+//!
+//! In `AcceptanceMode::Pareto` no axis may be traded at all: a patch that
+//! worsens any axis is rejected, and one that improves at least one axis
+//! (dominating the current state) is accepted. Every axis is checked, so
+//! every axis's signal has to be reported.
+//!
+//! All of these need `Artifact::evaluate_patch_signals`. A hard axis whose
+//! signal the artifact does not report cannot be enforced, so a patch that
+//! leaves one unreported is rejected rather than waved through.
+
+use crate::artifact::Artifact;
+use crate::config::{AcceptanceMode, PressureAxisConfig};
+use crate::pressure::Signals;
+use crate::region::{Patch, RegionId};
+
+/// Changes smaller than this are treated as no change.
+const EPSILON: f64 = 1e-9;
+
+/// The axis configuration's verdict on a patch.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AxisVerdict {
+    /// No hard or higher-priority axis decided; use the artifact's verdict
+    Undecided,
+    /// The patch increases these hard axes
+    HardViolation(Vec<String>),
+    /// No signal delta was reported for these hard axes (any axis in Pareto mode)
+    MissingSignals(Vec<String>),
+    /// The patch worsens these axes (Pareto mode)
+    Worsens(Vec<String>),
+    /// The patch worsens no axis and improves at least one (Pareto mode)
+    Dominates,
+    /// A higher-priority tier improved (`accept`) or worsened the artifact
+    Decided {
+        /// Whether the deciding tier improved
+        accept: bool,
+        /// Priority of the deciding tier
+        priority: i32,
+    },
+}
+
+/// Why a patch was rejected, carried on `RegionPatchResult`.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum Rejection {
+    /// The artifact found no improvement (`delta` positive = better)
+    #[error("Patch provides no improvement (delta={delta:.2})")]
+    NoImprovement {
+        /// Measured pressure improvement
+        delta: f64,
+    },
+    /// The patch increases these hard axes
+    #[error("Hard constraint violated: {} increased", .0.join(", "))]
+    HardViolation(Vec<String>),
+    /// The artifact reported no signal delta for these checked axes
+    #[error("No signal delta reported for axes: {}", .0.join(", "))]
+    MissingSignals(Vec<String>),
+    /// The patch worsens these axes (Pareto mode)
+    #[error("Pareto: {} worsened", .0.join(", "))]
+    Worsens(Vec<String>),
+    /// A higher-priority tier worsened
+    #[error("Priority {0} axes worsened")]
+    PriorityWorsened(i32),
+    /// The region, or a region enclosing it, is frozen
+    #[error("Region {0} is frozen")]
+    Frozen(RegionId),
+}
+
+/// Whether `axes` need per-signal patch evaluation at all.
+pub fn needs_signal_deltas(axes: &[PressureAxisConfig], mode: AcceptanceMode) -> bool {
+    if mode == AcceptanceMode::Pareto {
+        return !axes.is_empty();
+    }
+    let Some(first) = axes.first() else {
+        return false;
+    };
+    axes.iter().any(|a| a.hard || a.priority != first.priority)
+}
+
+/// Judge a patch from its signal deltas (after minus before, positive = worse).
+///
+/// A hard axis (or in Pareto mode, any axis) whose signal is missing from
+/// `deltas` cannot be checked and is reported as `MissingSignals`; other
+/// missing axes are treated as unchanged.
+pub fn judge(axes: &[PressureAxisConfig], mode: AcceptanceMode, deltas: &Signals) -> AxisVerdict {
+    let delta_of = |axis: &PressureAxisConfig| deltas.get(&axis.expr).copied().unwrap_or(0.0);
+
+    let violated: Vec<String> = axes
+        .iter()
+        .filter(|a| a.hard && delta_of(a) > EPSILON)
+        .map(|a| a.name.clone())
+        .collect();
+    if !violated.is_empty() {
+        return AxisVerdict::HardViolation(violated);
+    }
+    let checked = |axis: &PressureAxisConfig| axis.hard || mode == AcceptanceMode::Pareto;
+    let missing: Vec<String> = axes
+        .iter()
+        .filter(|a| checked(a) && !deltas.contains_key(&a.expr))
+        .map(|a| a.name.clone())
+        .collect();
+    if !missing.is_empty() {
+        return AxisVerdict::MissingSignals(missing);
+    }
+
+    if mode == AcceptanceMode::Pareto {
+        let worse: Vec<String> = axes
+            .iter()
+            .filter(|a| delta_of(a) > EPSILON)
+            .map(|a| a.name.clone())
+            .collect();
+        if !worse.is_empty() {
+            return AxisVerdict::Worsens(worse);
+        }
+        if axes.iter().any(|a| delta_of(a) < -EPSILON) {
+            return AxisVerdict::Dominates;
+        }
+        return AxisVerdict::Undecided;
+    }
+
+    let mut priorities: Vec<i32> = axes.iter().map(|a| a.priority).collect();
+    priorities.sort_unstable_by(|a, b| b.cmp(a));
+    priorities.dedup();
+
+    // Every tier but the lowest may decide
+    for &priority in priorities.iter().take(priorities.len().saturating_sub(1)) {
+        let change: f64 = axes
+            .iter()
+            .filter(|a| a.priority == priority)
+            .map(|a| delta_of(a) * a.weight)
+            .sum();
+        if change.abs() > EPSILON {
+            return AxisVerdict::Decided {
+                accept: change < 0.0,
+                priority,
+            };
+        }
+    }
+
+    AxisVerdict::Undecided
+}
+
+/// Evaluate a patch with the artifact, then apply hard and prioritized axes.
+///
+/// Returns `(should_accept, pressure_delta, rejection)`, where `rejection`
+/// explains a veto by the axes (None when the axes did not reject it).
+pub fn evaluate(
+    artifact: &dyn Artifact,
+    axes: &[PressureAxisConfig],
+    mode: AcceptanceMode,
+    patch: &Patch,
+) -> (bool, f64, Option<Rejection>) {
+    let (should_accept, pressure_delta) = artifact.evaluate_patch(patch);
+    if !needs_signal_deltas(axes, mode) {
+        return (should_accept, pressure_delta, None);
+    }
+    let deltas = match artifact.evaluate_patch_signals(patch) {
+        Some(deltas) => deltas,
+        // Nothing for the axes to overrule
+        None if !should_accept => return (false, pressure_delta, None),
+        None => Signals::new(),
+    };
+
+    match judge(axes, mode, &deltas) {
+        AxisVerdict::Undecided => (should_accept, pressure_delta, None),
+        AxisVerdict::HardViolation(names) => {
+            (false, pressure_delta, Some(Rejection::HardViolation(names)))
+        }
+        AxisVerdict::MissingSignals(names) => (
+            false,
+            pressure_delta,
+            Some(Rejection::MissingSignals(names)),
+        ),
+        AxisVerdict::Worsens(names) => (false, pressure_delta, Some(Rejection::Worsens(names))),
+        AxisVerdict::Dominates => (true, pressure_delta, None),
+        AxisVerdict::Decided { accept, priority } => {
+            let rejection = (!accept).then_some(Rejection::PriorityWorsened(priority));
+            (accept, pressure_delta, rejection)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalize::Normalization;
+    use crate::region::PatchOp;
+    use crate::toy::{ToyArtifact, toy_axis};
+
+    fn axis(name: &str, weight: f64, hard: bool, priority: i32) -> PressureAxisConfig {
+        PressureAxisConfig {
+            name: name.to_string(),
+            weight,
+            expr: format!("{}_count", name),
+            kind_weights: Default::default(),
+            hard,
+            priority,
+            normalization: Normalization::None,
+        }
+    }
+
+    fn deltas(pairs: &[(&str, f64)]) -> Signals {
+        pairs
+            .iter()
+            .map(|(name, d)| (format!("{}_count", name), *d))
+            .collect()
+    }
+
+    #[test]
+    fn test_hard_axis_cannot_be_traded() {
+        let axes = vec![
+            axis("overlap", 2.0, true, 0),
+            axis("unscheduled", 1.5, false, 0),
+        ];
+        assert!(needs_signal_deltas(&axes, AcceptanceMode::Weighted));
+
+        // One new overlap for two fewer unscheduled meetings
+        let verdict = judge(
+            &axes,
+            AcceptanceMode::Weighted,
+            &deltas(&[("overlap", 1.0), ("unscheduled", -2.0)]),
+        );
+        assert_eq!(verdict, AxisVerdict::HardViolation(vec!["overlap".into()]));
+
+        let verdict = judge(
+            &axes,
+            AcceptanceMode::Weighted,
+            &deltas(&[("overlap", -1.0), ("unscheduled", 1.0)]),
+        );
+        assert_eq!(verdict, AxisVerdict::Undecided);
+    }
+
+    #[test]
+    fn test_unreported_hard_axis_rejects_the_patch() {
+        let axes = vec![
+            axis("overlap", 2.0, true, 0),
+            axis("unscheduled", 1.5, false, 0),
+        ];
+        let verdict = judge(
+            &axes,
+            AcceptanceMode::Weighted,
+            &deltas(&[("unscheduled", -2.0)]),
+        );
+        assert_eq!(verdict, AxisVerdict::MissingSignals(vec!["overlap".into()]));
+
+        // The toy artifact reports no signal deltas at all
+        let artifact = ToyArtifact::new(&[5]);
+        let hard = vec![PressureAxisConfig {
+            hard: true,
+            ..toy_axis()
+        }];
+        let patch = |value: &str| Patch {
+            region: artifact.region_ids()[0].clone(),
+            op: PatchOp::Replace(value.to_string()),
+            rationale: String::new(),
+            expected_delta: Default::default(),
+        };
+        let (accept, delta, rejection) =
+            evaluate(&artifact, &hard, AcceptanceMode::Weighted, &patch("2"));
+        assert!(!accept);
+        assert_eq!(delta, 3.0);
+        assert_eq!(
+            rejection,
+            Some(Rejection::MissingSignals(vec![toy_axis().name]))
+        );
+
+        // A patch the artifact rejects itself keeps its own verdict
+        let (accept, _, rejection) =
+            evaluate(&artifact, &hard, AcceptanceMode::Weighted, &patch("9"));
+        assert!(!accept);
+        assert_eq!(rejection, None);
+    }
+
+    #[test]
+    fn test_priorities_compare_tier_by_tier() {
+        let axes = vec![
+            axis("overlap", 1.0, false, 1),
+            axis("unscheduled", 1.0, false, 0),
+        ];
+
+        let verdict = judge(
+            &axes,
+            AcceptanceMode::Weighted,
+            &deltas(&[("overlap", 1.0), ("unscheduled", -5.0)]),
+        );
+        assert_eq!(
+            verdict,
+            AxisVerdict::Decided {
+                accept: false,
+                priority: 1
+            }
+        );
+
+        let verdict = judge(
+            &axes,
+            AcceptanceMode::Weighted,
+            &deltas(&[("overlap", -1.0), ("unscheduled", 5.0)]),
+        );
+        assert_eq!(
+            verdict,
+            AxisVerdict::Decided {
+                accept: true,
+                priority: 1
+            }
+        );
+
+        // Top tier unchanged: the lowest tier is left to the artifact
+        let verdict = judge(
+            &axes,
+            AcceptanceMode::Weighted,
+            &deltas(&[("unscheduled", -1.0)]),
+        );
+        assert_eq!(verdict, AxisVerdict::Undecided);
+        assert!(!needs_signal_deltas(
+            &[axis("overlap", 1.0, false, 0)],
+            AcceptanceMode::Weighted
+        ));
+    }
+
+    #[test]
+    fn test_pareto_rejects_any_worse_axis() {
+        let axes = vec![
+            axis("overlap", 2.0, false, 0),
+            axis("unscheduled", 1.5, false, 0),
+        ];
+        assert!(needs_signal_deltas(&axes, AcceptanceMode::Pareto));
+
+        let verdict = judge(
+            &axes,
+            AcceptanceMode::Pareto,
+            &deltas(&[("overlap", 1.0), ("unscheduled", -2.0)]),
+        );
+        assert_eq!(verdict, AxisVerdict::Worsens(vec!["overlap".into()]));
+
+        let verdict = judge(
+            &axes,
+            AcceptanceMode::Pareto,
+            &deltas(&[("overlap", 0.0), ("unscheduled", -1.0)]),
+        );
+        assert_eq!(verdict, AxisVerdict::Dominates);
+
+        let verdict = judge(
+            &axes,
+            AcceptanceMode::Pareto,
+            &deltas(&[("overlap", 0.0), ("unscheduled", 0.0)]),
+        );
+        assert_eq!(verdict, AxisVerdict::Undecided);
+
+        // An unreported axis cannot be shown not to worsen
+        let verdict = judge(
+            &axes,
+            AcceptanceMode::Pareto,
+            &deltas(&[("unscheduled", -1.0)]),
+        );
+        assert_eq!(verdict, AxisVerdict::MissingSignals(vec!["overlap".into()]));
+
+        // Likewise an artifact that reports no signal deltas at all
+        let artifact = ToyArtifact::new(&[5]);
+        let patch = Patch {
+            region: artifact.region_ids()[0].clone(),
+            op: PatchOp::Replace("2".to_string()),
+            rationale: String::new(),
+            expected_delta: Default::default(),
+        };
+        let (accept, _, rejection) =
+            evaluate(&artifact, &[toy_axis()], AcceptanceMode::Pareto, &patch);
+        assert!(!accept);
+        assert_eq!(
+            rejection,
+            Some(Rejection::MissingSignals(vec![toy_axis().name]))
+        );
+    }
+}
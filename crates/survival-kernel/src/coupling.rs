@@ -0,0 +1,244 @@
+//! Empirical inter-region coupling.
+//!
+//! The convergence argument assumes ε-bounded coupling: patching region A
+//! moves the pressure of any other region B by at most ε. The coordinator
+//! checks this as the run goes. When it observes region pressures after a
+//! tick that applied patches, it compares each unpatched region's pressure
+//! with the previous observation and reports one `CouplingSample` per
+//! (patched, unpatched) pair in that tick's `TickResult`. A `CouplingMatrix`
+//! accumulates the samples; its `CouplingReport` is what `KernelResult`
+//! carries and what `results/analyze_coupling.R` reads.
+//!
+//! When several regions are patched in the same tick, a change in B cannot
+//! be attributed to one of them, so it is recorded against each. The
+//! measured coupling is therefore an upper bound.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::region::RegionId;
+
+/// Pressure changes at or below this size count as no coupling.
+pub const NEGLIGIBLE_DELTA: f64 = 1e-9;
+
+/// Change in one region's pressure observed after a patch to another.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CouplingSample {
+    /// Tick the patch was applied on
+    pub tick: usize,
+    /// Region that was patched
+    pub source: RegionId,
+    /// Region whose pressure was observed
+    pub target: RegionId,
+    /// Signed change in the target's measured pressure
+    pub delta: f64,
+}
+
+/// Accumulated coupling from one region to another.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Cell {
+    samples: u64,
+    sum_abs: f64,
+    max_abs: f64,
+}
+
+impl Cell {
+    fn mean_abs(&self) -> f64 {
+        self.sum_abs / self.samples as f64
+    }
+}
+
+/// One (source, target) pair of the coupling matrix.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CouplingEntry {
+    /// Region that was patched
+    pub source: RegionId,
+    /// Region whose pressure was observed
+    pub target: RegionId,
+    /// Patches to `source` after which `target` was observed
+    pub samples: u64,
+    /// Mean absolute pressure change of `target`
+    pub mean_delta: f64,
+    /// Largest absolute pressure change of `target`
+    pub max_delta: f64,
+}
+
+/// Summary of the coupling matrix.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct CouplingStats {
+    /// Largest absolute change seen in any pair: the empirical ε
+    pub max: f64,
+    /// Mean over pairs of their mean absolute change
+    pub mean: f64,
+    /// Fraction of pairs whose changes never exceeded `NEGLIGIBLE_DELTA`
+    pub sparsity: f64,
+    /// Pairs with at least one sample
+    pub pairs: usize,
+    /// Samples recorded
+    pub samples: u64,
+}
+
+/// Coupling statistics plus the matrix they summarize, for export.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CouplingReport {
+    /// Summary statistics
+    pub stats: CouplingStats,
+    /// Every observed pair, sorted by source then target
+    pub entries: Vec<CouplingEntry>,
+}
+
+/// Running coupling matrix built from `CouplingSample`s.
+#[derive(Debug, Clone, Default)]
+pub struct CouplingMatrix {
+    cells: HashMap<(RegionId, RegionId), Cell>,
+}
+
+impl CouplingMatrix {
+    /// Create an empty matrix.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold one sample into its pair.
+    pub fn record(&mut self, sample: &CouplingSample) {
+        let cell = self
+            .cells
+            .entry((sample.source.clone(), sample.target.clone()))
+            .or_default();
+        let delta = sample.delta.abs();
+        cell.samples += 1;
+        cell.sum_abs += delta;
+        cell.max_abs = cell.max_abs.max(delta);
+    }
+
+    /// Pairs with at least one sample.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Whether nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Summarize the matrix.
+    pub fn stats(&self) -> CouplingStats {
+        if self.cells.is_empty() {
+            return CouplingStats::default();
+        }
+        let pairs = self.cells.len();
+        let max = self.cells.values().map(|c| c.max_abs).fold(0.0, f64::max);
+        let mean = self.cells.values().map(Cell::mean_abs).sum::<f64>() / pairs as f64;
+        let uncoupled = self
+            .cells
+            .values()
+            .filter(|c| c.max_abs <= NEGLIGIBLE_DELTA)
+            .count();
+        CouplingStats {
+            max,
+            mean,
+            sparsity: uncoupled as f64 / pairs as f64,
+            pairs,
+            samples: self.cells.values().map(|c| c.samples).sum(),
+        }
+    }
+
+    /// Statistics and every pair, ready to serialize.
+    pub fn report(&self) -> CouplingReport {
+        let mut entries: Vec<_> = self
+            .cells
+            .iter()
+            .map(|((source, target), cell)| CouplingEntry {
+                source: source.clone(),
+                target: target.clone(),
+                samples: cell.samples,
+                mean_delta: cell.mean_abs(),
+                max_delta: cell.max_abs,
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            (a.source.to_string(), a.target.to_string())
+                .cmp(&(b.source.to_string(), b.target.to_string()))
+        });
+        CouplingReport {
+            stats: self.stats(),
+            entries,
+        }
+    }
+}
+
+/// Samples for one observation of region pressures.
+///
+/// Every region in `sources` is paired with every other region present in
+/// both `before` and `after`. Regions that were split, merged or not yet
+/// measured are skipped.
+pub fn observe(
+    tick: usize,
+    sources: &[RegionId],
+    before: &HashMap<RegionId, f64>,
+    after: &HashMap<RegionId, f64>,
+) -> Vec<CouplingSample> {
+    let mut samples = Vec::new();
+    for (target, new) in after {
+        if sources.contains(target) {
+            continue;
+        }
+        let Some(old) = before.get(target) else {
+            continue;
+        };
+        for source in sources {
+            samples.push(CouplingSample {
+                tick,
+                source: source.clone(),
+                target: target.clone(),
+                delta: new - old,
+            });
+        }
+    }
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mti::prelude::*;
+
+    #[test]
+    fn test_observe_and_summarize_coupling() {
+        let a = "region".create_type_id::<V7>();
+        let b = "region".create_type_id::<V7>();
+        let c = "region".create_type_id::<V7>();
+        let before = HashMap::from([(a.clone(), 4.0), (b.clone(), 2.0), (c.clone(), 1.0)]);
+        let after = HashMap::from([(a.clone(), 1.0), (b.clone(), 2.5), (c.clone(), 1.0)]);
+
+        // Only the unpatched regions are targets
+        let samples = observe(3, std::slice::from_ref(&a), &before, &after);
+        assert_eq!(samples.len(), 2);
+        assert!(samples.iter().all(|s| s.source == a && s.tick == 3));
+
+        let mut matrix = CouplingMatrix::new();
+        for sample in &samples {
+            matrix.record(sample);
+        }
+        matrix.record(&CouplingSample {
+            tick: 4,
+            source: a.clone(),
+            target: b.clone(),
+            delta: -1.5,
+        });
+
+        let stats = matrix.stats();
+        assert_eq!(stats.pairs, 2);
+        assert_eq!(stats.samples, 3);
+        assert_eq!(stats.max, 1.5);
+        // a→b averages 1.0, a→c never moved
+        assert_eq!(stats.mean, 0.5);
+        assert_eq!(stats.sparsity, 0.5);
+
+        let report = matrix.report();
+        let ab = report.entries.iter().find(|e| e.target == b).unwrap();
+        assert_eq!((ab.samples, ab.mean_delta, ab.max_delta), (2, 1.0, 1.5));
+        assert_eq!(CouplingMatrix::new().stats(), CouplingStats::default());
+    }
+}
@@ -0,0 +1,545 @@
+//! Island model: kernel replicas that trade their best regions.
+//!
+//! A single trajectory often stalls. An `IslandModel` runs several kernels
+//! (islands) side by side, each on its own artifact replica and configured
+//! differently: other seeds, patch actors with other sampling bands, or
+//! another acceptance policy. Islands tick in lockstep. Every
+//! `MigrationConfig::interval` ticks each island offers its lowest-pressure
+//! regions (`ExportRegions`) to the others, which adopt a migrant only if
+//! replacing their own region with it passes `Artifact::evaluate_patch` as an
+//! improvement (`ImportRegions`). The best island's result is returned.
+//!
+//! Region IDs must agree across replicas for migrants to find their region.
+
+use std::collections::HashSet;
+
+use acton_reactive::prelude::*;
+use futures::future::join_all;
+use mti::prelude::*;
+use tracing::{info, warn};
+
+use crate::config::{ExecutionMode, KernelConfig, MigrationConfig};
+use crate::kernel::{AsyncKernelBuilder, KernelResult, RunTally, TickResult};
+use crate::messages::{
+    ExportRegions, ImportRegions, MigrantRegion, RegionExport, RegionsImported, StopReason,
+    SuspendRegions, Tick,
+};
+use crate::region::RegionId;
+
+/// One kernel replica and the runtime it runs in.
+pub struct Island {
+    /// Label used in logs and results
+    name: String,
+    /// Runtime holding this island's actors
+    runtime: ActorRuntime,
+    /// Kernel to run on this island
+    builder: AsyncKernelBuilder,
+    /// Patch actors spawned into `runtime` that must register first
+    expected_patch_actors: usize,
+}
+
+impl Island {
+    /// Create an island that runs `builder` in `runtime`.
+    ///
+    /// Every island needs its own runtime: kernel actors talk through the
+    /// runtime's broker, so two kernels sharing one would hear each other.
+    /// Spawn the island's patch actors into `runtime` beforehand.
+    pub fn new(
+        name: impl Into<String>,
+        runtime: ActorRuntime,
+        builder: AsyncKernelBuilder,
+        expected_patch_actors: usize,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            runtime,
+            builder,
+            expected_patch_actors,
+        }
+    }
+}
+
+/// Migrants adopted by one island.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationEvent {
+    /// Tick after which the migration happened
+    pub tick: usize,
+    /// Receiving island
+    pub island: String,
+    /// Regions offered by the other islands
+    pub offered: usize,
+    /// Regions replaced by a migrant
+    pub accepted: Vec<RegionId>,
+}
+
+/// Final result of an island run.
+#[derive(Debug, Clone)]
+pub struct IslandResult {
+    /// Each island's name and result, in the order they were added
+    pub islands: Vec<(String, KernelResult)>,
+    /// Index of the best island: the one that completed, else the lowest
+    /// final pressure
+    pub best: usize,
+    /// Every migration, in order
+    pub migrations: Vec<MigrationEvent>,
+}
+
+impl IslandResult {
+    /// Result of the best island.
+    pub fn best(&self) -> &KernelResult {
+        &self.islands[self.best].1
+    }
+}
+
+/// Runs islands in lockstep with periodic migration.
+pub struct IslandModel {
+    islands: Vec<Island>,
+    migration: MigrationConfig,
+}
+
+impl IslandModel {
+    /// Create an island model with the given migration settings.
+    pub fn new(migration: MigrationConfig) -> Self {
+        Self {
+            islands: Vec::new(),
+            migration,
+        }
+    }
+
+    /// Add an island.
+    pub fn add_island(mut self, island: Island) -> Self {
+        self.islands.push(island);
+        self
+    }
+
+    /// Run every island until one completes or all have stopped.
+    ///
+    /// Each island stops on its own `max_ticks` and `stable_threshold`; once
+    /// one completes the artifact the others stop with
+    /// `StopReason::Superseded`. Island runtimes are shut down afterwards.
+    pub async fn run(self) -> IslandResult {
+        let mut islands = Vec::with_capacity(self.islands.len());
+        for island in self.islands {
+            islands.push(RunningIsland::start(island).await);
+        }
+        let interval_ms = islands
+            .iter()
+            .map(|i| i.config.tick_interval_ms)
+            .max()
+            .unwrap_or(0);
+        let mut migrations = Vec::new();
+        let mut tick = 0usize;
+
+        info!(
+            islands = islands.len(),
+            migration_interval = self.migration.interval,
+            migrants = self.migration.migrants,
+            "Starting island run"
+        );
+
+        while islands.iter().any(|i| i.stop.is_none()) {
+            tick += 1;
+            let now_ms = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64;
+
+            join_all(
+                islands
+                    .iter_mut()
+                    .filter(|i| i.stop.is_none())
+                    .map(|i| i.tick(now_ms)),
+            )
+            .await;
+
+            if islands.iter().any(|i| i.stop == Some(StopReason::Complete)) {
+                for island in islands.iter_mut().filter(|i| i.stop.is_none()) {
+                    island.stop = Some(StopReason::Superseded);
+                }
+                break;
+            }
+
+            if self.migration.interval > 0 && tick.is_multiple_of(self.migration.interval) {
+                migrate(&mut islands, tick, &self.migration, &mut migrations).await;
+            }
+
+            tokio::time::sleep(std::time::Duration::from_millis(interval_ms)).await;
+        }
+
+        let best = islands
+            .iter()
+            .position(|i| i.stop == Some(StopReason::Complete))
+            .or_else(|| {
+                (0..islands.len()).min_by(|&a, &b| {
+                    islands[a]
+                        .tally
+                        .final_pressure()
+                        .total_cmp(&islands[b].tally.final_pressure())
+                })
+            })
+            .unwrap_or(0);
+
+        let mut results = Vec::with_capacity(islands.len());
+        for island in islands {
+            results.push(island.finish().await);
+        }
+        if let Some((name, result)) = results.get(best) {
+            info!(
+                island = %name,
+                final_pressure = format!("{:.3}", result.final_pressure),
+                "Island run complete"
+            );
+        }
+
+        IslandResult {
+            islands: results,
+            best,
+            migrations,
+        }
+    }
+}
+
+/// An island whose kernel has been started.
+struct RunningIsland {
+    name: String,
+    runtime: ActorRuntime,
+    config: KernelConfig,
+    coordinator: ActorHandle,
+    ticks: tokio::sync::mpsc::Receiver<TickResult>,
+    exports: tokio::sync::mpsc::Receiver<RegionExport>,
+    imports: tokio::sync::mpsc::Receiver<RegionsImported>,
+    tally: RunTally,
+    /// Why the island stopped (None while it runs)
+    stop: Option<StopReason>,
+}
+
+impl RunningIsland {
+    /// Spawn the island's kernel and wait for its actors to register.
+    async fn start(island: Island) -> Self {
+        let Island {
+            name,
+            mut runtime,
+            builder,
+            expected_patch_actors,
+        } = island;
+        let config = builder.config().clone();
+
+        let (exports_tx, exports) = tokio::sync::mpsc::channel(16);
+        let (imports_tx, imports) = tokio::sync::mpsc::channel(16);
+        spawn_forwarder::<RegionExport>(&mut runtime, "RegionExportObserver", exports_tx).await;
+        spawn_forwarder::<RegionsImported>(&mut runtime, "RegionsImportedObserver", imports_tx)
+            .await;
+
+        let (coordinator, ticks, _) = builder.start(&mut runtime, expected_patch_actors).await;
+
+        Self {
+            name,
+            runtime,
+            tally: RunTally::new(&config),
+            config,
+            coordinator,
+            ticks,
+            exports,
+            imports,
+            stop: None,
+        }
+    }
+
+    /// Run one tick and record its result.
+    async fn tick(&mut self, now_ms: u64) {
+        self.coordinator.send(Tick { now_ms }).await;
+        match self.ticks.recv().await {
+            Some(result) => self.stop = self.tally.record(result),
+            None => {
+                warn!(island = %self.name, "TickComplete channel closed unexpectedly");
+                self.stop = Some(StopReason::MaxTicks);
+            }
+        }
+    }
+
+    /// The island's `count` lowest-pressure regions, totals, and source.
+    async fn export(&mut self, count: usize) -> Option<RegionExport> {
+        let correlation_id = "export".create_type_id::<V7>().to_string();
+        self.coordinator
+            .send(ExportRegions {
+                correlation_id: correlation_id.clone(),
+                count,
+            })
+            .await;
+        while let Some(export) = self.exports.recv().await {
+            if export.correlation_id == correlation_id {
+                return Some(export);
+            }
+        }
+        None
+    }
+
+    /// Offer migrants to the island; returns the regions it adopted.
+    async fn import(&mut self, migrants: Vec<MigrantRegion>) -> Vec<RegionId> {
+        let correlation_id = "import".create_type_id::<V7>().to_string();
+        self.coordinator
+            .send(ImportRegions {
+                correlation_id: correlation_id.clone(),
+                migrants,
+            })
+            .await;
+        while let Some(report) = self.imports.recv().await {
+            if report.correlation_id == correlation_id {
+                return report.accepted;
+            }
+        }
+        Vec::new()
+    }
+
+    /// Stop the island and build its result.
+    async fn finish(mut self) -> (String, KernelResult) {
+        let source = self.export(0).await.and_then(|e| e.source);
+        if self.config.mode == ExecutionMode::Async {
+            self.runtime.broker().broadcast(SuspendRegions).await;
+        }
+        let stop = self.stop.take().unwrap_or(StopReason::MaxTicks);
+        let result = self.tally.finish(stop, source);
+        let _ = self.runtime.shutdown_all().await;
+        (self.name, result)
+    }
+}
+
+/// Offer every running island the best regions of the others.
+///
+/// When several islands offer the same region, only the lowest-pressure
+/// offer is kept.
+async fn migrate(
+    islands: &mut [RunningIsland],
+    tick: usize,
+    config: &MigrationConfig,
+    events: &mut Vec<MigrationEvent>,
+) {
+    let active: Vec<usize> = (0..islands.len())
+        .filter(|&i| islands[i].stop.is_none())
+        .collect();
+    if active.len() < 2 {
+        return;
+    }
+
+    let mut exports = Vec::with_capacity(active.len());
+    for &i in &active {
+        if let Some(export) = islands[i].export(config.migrants).await {
+            exports.push((i, export.regions));
+        }
+    }
+
+    for &i in &active {
+        let mut migrants: Vec<MigrantRegion> = exports
+            .iter()
+            .filter(|(source, _)| *source != i)
+            .flat_map(|(_, regions)| regions.iter().cloned())
+            .collect();
+        migrants.sort_by(|a, b| a.pressure.total_cmp(&b.pressure));
+        let mut seen = HashSet::new();
+        migrants.retain(|m| seen.insert(m.view.id.clone()));
+        if migrants.is_empty() {
+            continue;
+        }
+
+        let offered = migrants.len();
+        let accepted = islands[i].import(migrants).await;
+        info!(
+            tick,
+            island = %islands[i].name,
+            offered,
+            accepted = accepted.len(),
+            "Migration"
+        );
+        events.push(MigrationEvent {
+            tick,
+            island: islands[i].name.clone(),
+            offered,
+            accepted,
+        });
+    }
+}
+
+/// Spawn an actor that forwards every broadcast `M` to a channel.
+async fn spawn_forwarder<M>(
+    runtime: &mut ActorRuntime,
+    name: &str,
+    tx: tokio::sync::mpsc::Sender<M>,
+) where
+    M: ActonMessage + Clone + Send + Sync + 'static,
+{
+    #[derive(Clone, Debug)]
+    struct State<M> {
+        tx: Option<tokio::sync::mpsc::Sender<M>>,
+    }
+
+    impl<M> Default for State<M> {
+        fn default() -> Self {
+            Self { tx: None }
+        }
+    }
+
+    let mut actor = runtime.new_actor_with_name::<State<M>>(name.to_string());
+    actor.model.tx = Some(tx);
+
+    actor.handle().subscribe::<M>().await;
+
+    actor.act_on::<M>(|actor, context| {
+        let msg = context.message().clone();
+        let tx = actor.model.tx.clone();
+        Reply::pending(async move {
+            if let Some(tx) = tx {
+                let _ = tx.send(msg).await;
+            }
+        })
+    });
+
+    actor.start().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::artifact::Artifact;
+    use crate::config::PressureAxisConfig;
+    use crate::pressure::{Sensor, Signals};
+    use crate::region::{Patch, PatchOp, RegionView};
+    use uuid::Uuid;
+
+    fn test_region_id(name: &str) -> RegionId {
+        let v5_uuid = Uuid::new_v5(&Uuid::NAMESPACE_DNS, name.as_bytes());
+        let prefix = TypeIdPrefix::try_from("test").expect("test is valid prefix");
+        MagicTypeId::new(prefix, TypeIdSuffix::from(v5_uuid))
+    }
+
+    /// Regions hold a number; pressure is the sum.
+    struct NumberArtifact {
+        regions: Vec<(RegionId, f64)>,
+    }
+
+    impl NumberArtifact {
+        fn value(&self, patch: &Patch) -> Option<(f64, f64)> {
+            let PatchOp::Replace(content) = &patch.op else {
+                return None;
+            };
+            let (_, old) = self.regions.iter().find(|(id, _)| *id == patch.region)?;
+            Some((*old, content.parse().ok()?))
+        }
+    }
+
+    impl Artifact for NumberArtifact {
+        fn region_ids(&self) -> Vec<RegionId> {
+            self.regions.iter().map(|(id, _)| id.clone()).collect()
+        }
+
+        fn read_region(&self, id: RegionId) -> anyhow::Result<RegionView> {
+            let (_, value) = self
+                .regions
+                .iter()
+                .find(|(rid, _)| *rid == id)
+                .ok_or_else(|| anyhow::anyhow!("unknown region"))?;
+            Ok(RegionView {
+                id,
+                kind: "number".to_string(),
+                content: value.to_string(),
+                metadata: HashMap::new(),
+                parent: None,
+                children: Vec::new(),
+            })
+        }
+
+        fn apply_patch(&mut self, patch: Patch) -> anyhow::Result<()> {
+            let (_, new) = self
+                .value(&patch)
+                .ok_or_else(|| anyhow::anyhow!("bad patch"))?;
+            for (id, value) in &mut self.regions {
+                if *id == patch.region {
+                    *value = new;
+                }
+            }
+            Ok(())
+        }
+
+        fn source(&self) -> Option<String> {
+            let values: Vec<String> = self.regions.iter().map(|(_, v)| v.to_string()).collect();
+            Some(values.join(" "))
+        }
+
+        fn evaluate_patch(&self, patch: &Patch) -> (bool, f64) {
+            self.value(patch)
+                .map_or((false, 0.0), |(old, new)| (new < old, old - new))
+        }
+    }
+
+    struct NumberSensor;
+
+    impl Sensor for NumberSensor {
+        fn name(&self) -> &str {
+            "number"
+        }
+
+        fn measure(&self, region: &RegionView) -> anyhow::Result<Signals> {
+            Ok(HashMap::from([(
+                "value".to_string(),
+                region.content.parse()?,
+            )]))
+        }
+    }
+
+    async fn island(name: &str, values: [f64; 2]) -> Island {
+        let config = KernelConfig {
+            tick_interval_ms: 10,
+            max_ticks: 2,
+            stable_threshold: 0,
+            pressure_axes: vec![PressureAxisConfig {
+                name: "value".to_string(),
+                weight: 1.0,
+                expr: "value".to_string(),
+                kind_weights: HashMap::new(),
+                hard: false,
+                priority: 0,
+                normalization: Default::default(),
+            }],
+            ..KernelConfig::default()
+        };
+        let artifact = NumberArtifact {
+            regions: vec![
+                (test_region_id("r1"), values[0]),
+                (test_region_id("r2"), values[1]),
+            ],
+        };
+        let builder =
+            AsyncKernelBuilder::new(config, Box::new(artifact)).add_sensor(Box::new(NumberSensor));
+        Island::new(name, ActonApp::launch_async().await, builder, 0)
+    }
+
+    #[tokio::test]
+    async fn test_islands_adopt_each_others_best_regions() {
+        let migration = MigrationConfig {
+            interval: 1,
+            migrants: 2,
+        };
+        let result = IslandModel::new(migration)
+            .add_island(island("a", [1.0, 9.0]).await)
+            .add_island(island("b", [9.0, 1.0]).await)
+            .run()
+            .await;
+
+        // Each island only takes the region the other does better
+        let first: Vec<_> = result.migrations.iter().filter(|m| m.tick == 1).collect();
+        assert_eq!(first.len(), 2);
+        assert!(
+            first
+                .iter()
+                .all(|m| m.offered == 2 && m.accepted.len() == 1)
+        );
+
+        assert_eq!(result.islands.len(), 2);
+        for (_, island) in &result.islands {
+            assert_eq!(island.ticks_executed, 2);
+            assert_eq!(island.stop_reason, StopReason::MaxTicks);
+            assert_eq!(island.final_source.as_deref(), Some("1 1"));
+        }
+    }
+}
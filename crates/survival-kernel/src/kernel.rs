@@ -30,16 +30,28 @@ use std::sync::Arc;
 
 use acton_reactive::prelude::*;
 
-use crate::actors::{ClaimManager, KernelCoordinator, RegionActor};
+use crate::actors::{
+    ClaimManager, KernelCoordinator, PatchActorFactory, PatchActorSupervisor, ProposerActor,
+    RegionSpawner,
+};
 use crate::artifact::Artifact;
-use crate::config::KernelConfig;
+use crate::config::{ExecutionMode, KernelConfig};
+use crate::coupling::{CouplingMatrix, CouplingReport, CouplingSample};
 use crate::messages::Tick;
 use crate::messages::{
-    PatchActorsReady, RegisterRegionActors, SensorsReady, StopReason, WaitForPatchActors,
-    WaitForSensors,
+    KernelComplete, PatchActorsReady, RegisterRegionActors, SensorsReady, StopReason,
+    SuspendRegions, WaitForPatchActors, WaitForSensors,
 };
-use crate::pressure::Sensor;
+use crate::monitor::{AssumptionViolation, ViolationCounts};
+use crate::normalize::NormalizationStats;
+use crate::pareto::{ParetoArchive, ParetoSnapshot};
+use crate::pressure::{PressureVector, Sensor};
+use crate::proposer::{AsyncProposer, Proposer};
 use crate::region::{Patch, RegionId};
+use crate::reload::ConfigChange;
+use crate::reputation::ReputationTable;
+use crate::stagnation::StagnationKick;
+use crate::transform::PatchTransformer;
 
 /// Final result of running the kernel to completion.
 #[derive(Debug, Clone)]
@@ -62,6 +74,29 @@ pub struct KernelResult {
     pub tick_results: Vec<TickResult>,
     /// Pressure history (one entry per tick)
     pub pressure_history: Vec<f64>,
+    /// Per-axis pressure totals (one entry per tick)
+    pub axis_history: Vec<PressureVector>,
+    /// Non-dominated artifact snapshots (Pareto acceptance mode only)
+    pub pareto_archive: Vec<ParetoSnapshot>,
+    /// Patch actor reputation at the end of the run
+    pub reputation: ReputationTable,
+    /// Lowest-pressure state seen during the run (may predate the final one)
+    pub best_so_far: Option<BestSoFar>,
+    /// Measured inter-region coupling over the run
+    pub coupling: CouplingReport,
+    /// Convergence assumption violations over the run
+    pub violations: ViolationCounts,
+}
+
+/// The lowest-total-pressure artifact state seen so far.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BestSoFar {
+    /// Tick the state was reached on
+    pub tick: usize,
+    /// Total pressure of that state
+    pub total_pressure: f64,
+    /// Artifact source at that tick (if the artifact supports `source()`)
+    pub source: Option<String>,
 }
 
 /// Result of a single tick.
@@ -75,6 +110,8 @@ pub struct TickResult {
     pub skipped: usize,
     /// Total pressure across all regions
     pub total_pressure: f64,
+    /// Measured pressure per axis, summed over all regions
+    pub axis_pressures: PressureVector,
     /// Velocity: rate of pressure change (dP/dt)
     pub velocity: f64,
     /// Acceleration: rate of velocity change (d²P/dt²)
@@ -85,6 +122,22 @@ pub struct TickResult {
     pub completion_tokens: u32,
     /// Whether the artifact is now complete
     pub is_complete: bool,
+    /// Patch actor reputation as of this tick
+    pub reputation: ReputationTable,
+    /// Snapshot that entered the Pareto archive this tick (Pareto mode only)
+    pub pareto_snapshot: Option<ParetoSnapshot>,
+    /// Running statistics of each normalized axis, by axis name
+    pub normalization: HashMap<String, NormalizationStats>,
+    /// Stagnation kicks made at the start of this tick
+    pub kicks: Vec<StagnationKick>,
+    /// New best-so-far state, if this tick set one
+    pub best_so_far: Option<BestSoFar>,
+    /// Config updates accepted since the previous tick
+    pub config_changes: Vec<ConfigChange>,
+    /// Pressure changes of other regions after the previous tick's patches
+    pub coupling: Vec<CouplingSample>,
+    /// Convergence assumptions seen violated this tick (monitors enabled only)
+    pub violations: Vec<AssumptionViolation>,
 }
 
 /// Apply exponential decay with the given half-life.
@@ -105,13 +158,18 @@ pub fn half_life_decay(value: &mut f64, dt_ms: u64, half_life_ms: u64) {
 ///
 /// Uses the broker pub/sub pattern for actor registration:
 /// - Sensors self-register via `SensorReady` broadcast
-/// - Patch actors self-register via `PatchActorReady` broadcast
+/// - Patch actors self-register via `PatchActorReady` broadcast, including
+///   the ones the kernel spawns for `add_proposer`
 pub struct AsyncKernelBuilder {
     coordinator: KernelCoordinator,
     /// Sensors to spawn (they self-register via SensorReady broadcast)
     sensors: Vec<Arc<dyn Sensor>>,
     /// Validation sensor for RegionActors (first sensor added)
     validation_sensor: Option<Arc<dyn Sensor>>,
+    /// Optional supervisor that replaces failed patch actors
+    supervisor: Option<PatchActorSupervisor>,
+    /// Proposers to spawn as patch actors
+    proposers: Vec<ProposerActor>,
 }
 
 impl AsyncKernelBuilder {
@@ -121,6 +179,8 @@ impl AsyncKernelBuilder {
             coordinator: KernelCoordinator::new(config, artifact),
             sensors: Vec::new(),
             validation_sensor: None,
+            supervisor: None,
+            proposers: Vec::new(),
         }
     }
 
@@ -141,6 +201,57 @@ impl AsyncKernelBuilder {
         self
     }
 
+    /// Append a patch transformer to the chain.
+    ///
+    /// Every proposed patch runs through the transformers in the order they
+    /// were added, between `PatchProposal` receipt and evaluation. See
+    /// `crate::transform`.
+    pub fn add_transformer(mut self, transformer: Box<dyn PatchTransformer>) -> Self {
+        self.coordinator.transformers.push(Arc::from(transformer));
+        self
+    }
+
+    /// Add a synchronous proposer as a patch actor.
+    ///
+    /// It is wrapped in a `ProposerActor` during `spawn()`, which registers
+    /// with the coordinator and answers its requests. See `crate::proposer`.
+    /// It heartbeats often enough for `MembershipConfig::heartbeat_timeout_ms`.
+    pub fn add_proposer(self, proposer: Box<dyn Proposer>) -> Self {
+        let interval = self.coordinator.config.membership.heartbeat_interval();
+        self.add_proposer_actor(
+            ProposerActor::new(Arc::from(proposer)).with_heartbeat_interval(interval),
+        )
+    }
+
+    /// Add an asynchronous proposer as a patch actor (see `add_proposer`).
+    pub fn add_async_proposer(self, proposer: Box<dyn AsyncProposer>) -> Self {
+        let interval = self.coordinator.config.membership.heartbeat_interval();
+        self.add_proposer_actor(
+            ProposerActor::new_async(Arc::from(proposer)).with_heartbeat_interval(interval),
+        )
+    }
+
+    /// Add an already configured `ProposerActor`, spawned as is.
+    pub fn add_proposer_actor(mut self, proposer: ProposerActor) -> Self {
+        self.proposers.push(proposer);
+        self
+    }
+
+    /// Restart patch actors that fail or miss heartbeats.
+    ///
+    /// Spawns a `PatchActorSupervisor` that calls `factory` to replace any
+    /// patch actor that leaves with `DepartureReason::Failed` or `TimedOut`,
+    /// up to `max_restarts` times over the run. Heartbeat eviction is enabled
+    /// via `MembershipConfig::heartbeat_timeout_ms`.
+    pub fn supervise_patch_actors(
+        mut self,
+        factory: PatchActorFactory,
+        max_restarts: usize,
+    ) -> Self {
+        self.supervisor = Some(PatchActorSupervisor::new(factory, max_restarts));
+        self
+    }
+
     /// Spawn the kernel, sensor actors, and region actors.
     ///
     /// Returns the coordinator's actor handle. To run ticks:
@@ -164,14 +275,26 @@ impl AsyncKernelBuilder {
             })
             .collect();
         let pressure_axes = self.coordinator.config.pressure_axes.clone();
+        let provenance_limit = self.coordinator.config.provenance.history_limit;
         let validation_sensor = self.validation_sensor.clone();
+        let all_sensors = self.sensors.clone();
+        let normalizer = self.coordinator.normalizer.clone();
 
         // Spawn ClaimManager first (it broadcasts ClaimManagerReady that coordinator needs)
         ClaimManager::spawn(runtime).await;
 
+        // Spawn proposers first so they hear the coordinator's CoordinatorReady
+        for proposer in self.proposers {
+            proposer.spawn(runtime).await;
+        }
+
         // Spawn the coordinator (it subscribes to ClaimManagerReady, SensorReady)
         let coordinator_handle = self.coordinator.spawn(runtime).await;
 
+        if let Some(supervisor) = self.supervisor {
+            supervisor.spawn(runtime, coordinator_handle.clone()).await;
+        }
+
         // Spawn sensor actors - they self-register via SensorReady broadcast
         for sensor in self.sensors {
             let sensor_actor = SensorActor::new(sensor);
@@ -180,19 +303,19 @@ impl AsyncKernelBuilder {
 
         // Spawn RegionActors if we have a validation sensor
         if let Some(sensor) = validation_sensor {
+            let spawner = RegionSpawner::new(
+                runtime.clone(),
+                coordinator_handle.clone(),
+                sensor,
+                pressure_axes,
+            )
+            .with_sensors(all_sensors)
+            .with_provenance_limit(provenance_limit)
+            .with_normalizer(normalizer);
             let mut region_actors: HashMap<RegionId, ActorHandle> = HashMap::new();
 
             for (rid, view) in region_views {
-                let region_actor = RegionActor::new(
-                    rid.clone(),
-                    view.kind,
-                    view.content,
-                    view.metadata,
-                    coordinator_handle.clone(),
-                    sensor.clone(),
-                    pressure_axes.clone(),
-                );
-                let handle = region_actor.spawn(runtime, 0).await;
+                let handle = spawner.spawn(view, None, 0).await;
                 region_actors.insert(rid, handle);
             }
 
@@ -200,6 +323,7 @@ impl AsyncKernelBuilder {
             coordinator_handle
                 .send(RegisterRegionActors {
                     actors: region_actors,
+                    spawner: Some(spawner),
                 })
                 .await;
         }
@@ -216,72 +340,97 @@ impl AsyncKernelBuilder {
     /// 4. Waits for `KernelComplete` (from TickActor)
     /// 5. Returns the final result
     ///
-    /// The caller should spawn patch actors before calling this method.
-    /// Use `WaitForPatchActors` to synchronize startup.
+    /// The caller should spawn patch actors before calling this method and
+    /// pass their number as `expected_patch_actors`; proposers added with
+    /// `add_proposer` are counted automatically.
     pub async fn run(
         self,
         runtime: &mut ActorRuntime,
         expected_patch_actors: usize,
     ) -> KernelResult {
-        use crate::actors::SensorActor;
-
-        // Get artifact info and config before moving
         let config = self.coordinator.config.clone();
-        let region_ids: Vec<RegionId> = self.coordinator.artifact.region_ids();
-        let region_views: Vec<_> = region_ids
-            .iter()
-            .filter_map(|rid| {
-                self.coordinator
-                    .artifact
-                    .read_region(rid.clone())
-                    .ok()
-                    .map(|v| (rid.clone(), v))
-            })
-            .collect();
-        let pressure_axes = config.pressure_axes.clone();
-        let validation_sensor = self.validation_sensor.clone();
+        let (coordinator_handle, mut tick_rx, mut complete_rx) =
+            self.start(runtime, expected_patch_actors).await;
 
-        // Spawn ClaimManager first (it broadcasts ClaimManagerReady that coordinator needs)
-        ClaimManager::spawn(runtime).await;
+        // Run the tick loop directly (not inside an actor handler)
+        let mut tally = RunTally::new(&config);
+        let mut stop_reason = StopReason::MaxTicks;
 
-        // Spawn the coordinator (it subscribes to ClaimManagerReady)
-        let coordinator_handle = self.coordinator.spawn(runtime).await;
+        tracing::info!(
+            interval_ms = config.tick_interval_ms,
+            max_ticks = config.max_ticks,
+            stable_threshold = config.stable_threshold,
+            "Starting tick loop"
+        );
 
-        // Spawn sensor actors (capture count before moving)
-        let sensor_count = self.sensors.len();
-        for sensor in self.sensors {
-            let sensor_actor = SensorActor::new(sensor);
-            sensor_actor.spawn(runtime).await;
-        }
+        loop {
+            let now_ms = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64;
 
-        // Spawn RegionActors
-        if let Some(sensor) = validation_sensor {
-            let mut region_actors: HashMap<RegionId, ActorHandle> = HashMap::new();
+            // Send Tick to coordinator
+            coordinator_handle.send(Tick { now_ms }).await;
 
-            for (rid, view) in region_views {
-                let region_actor = RegionActor::new(
-                    rid.clone(),
-                    view.kind,
-                    view.content,
-                    view.metadata,
-                    coordinator_handle.clone(),
-                    sensor.clone(),
-                    pressure_axes.clone(),
-                );
-                let handle = region_actor.spawn(runtime, 0).await;
-                region_actors.insert(rid, handle);
+            // Wait for TickComplete via the observer channel; a Shutdown ends
+            // the run with KernelComplete instead (ticks are delivered first)
+            let result = tokio::select! {
+                biased;
+                result = tick_rx.recv() => result,
+                Some(complete) = complete_rx.recv() => {
+                    stop_reason = complete.reason;
+                    break;
+                }
+            };
+            let Some(result) = result else {
+                tracing::warn!("TickComplete channel closed unexpectedly");
+                break;
+            };
+
+            // Track results and check termination conditions
+            if let Some(reason) = tally.record(result) {
+                stop_reason = reason;
+                break;
             }
 
-            coordinator_handle
-                .send(RegisterRegionActors {
-                    actors: region_actors,
-                })
-                .await;
+            // Wait for the interval before next tick
+            tokio::time::sleep(std::time::Duration::from_millis(tally.interval_ms())).await;
+        }
+
+        // Stop autonomous region wakeups so no work continues after the result
+        if config.mode == ExecutionMode::Async {
+            runtime.broker().broadcast(SuspendRegions).await;
         }
 
+        tally.finish(stop_reason, None) // TODO: query source from artifact via SaveArtifact
+    }
+
+    /// The kernel configuration.
+    pub(crate) fn config(&self) -> &KernelConfig {
+        &self.coordinator.config
+    }
+
+    /// Spawn the kernel and wait until sensors and patch actors have registered.
+    ///
+    /// Returns the coordinator's handle, a channel of `TickComplete` results
+    /// and a channel of `KernelComplete` notifications.
+    pub(crate) async fn start(
+        self,
+        runtime: &mut ActorRuntime,
+        expected_patch_actors: usize,
+    ) -> (
+        ActorHandle,
+        tokio::sync::mpsc::Receiver<TickResult>,
+        tokio::sync::mpsc::Receiver<KernelComplete>,
+    ) {
+        let sensor_count = self.sensors.len();
+        let expected_patch_actors = expected_patch_actors + self.proposers.len();
+        let coordinator_handle = self.spawn(runtime).await;
+
         // Create observer to collect TickComplete results
-        let (tick_tx, mut tick_rx) = tokio::sync::mpsc::channel::<TickResult>(1000);
-        let tick_observer = TickResultObserver::new(tick_tx);
+        let (tick_tx, tick_rx) = tokio::sync::mpsc::channel::<TickResult>(1000);
+        let (complete_tx, complete_rx) = tokio::sync::mpsc::channel::<KernelComplete>(1);
+        let tick_observer = TickResultObserver::new(tick_tx, complete_tx);
         tick_observer.spawn(runtime).await;
 
         // Create observers to wait for registrations
@@ -322,107 +471,171 @@ impl AsyncKernelBuilder {
             actors_rx.recv().await;
         }
 
-        // Run the tick loop directly (not inside an actor handler)
-        let interval_ms = config.tick_interval_ms;
-        let max_ticks = config.max_ticks;
-        let stable_threshold = config.stable_threshold;
-
-        let mut tick_results = Vec::new();
-        let mut applied_patches = Vec::new();
-        let mut pressure_history = Vec::new();
-        let mut prompt_tokens = 0u32;
-        let mut completion_tokens = 0u32;
-        let mut current_tick = 0usize;
-        let mut stable_ticks = 0usize;
-        let mut stop_reason = StopReason::MaxTicks;
-        let mut final_pressure = 0.0;
-
-        tracing::info!(
-            interval_ms,
-            max_ticks,
-            stable_threshold,
-            "Starting tick loop"
-        );
+        (coordinator_handle, tick_rx, complete_rx)
+    }
+}
 
-        loop {
-            current_tick += 1;
+/// Running totals of one kernel's tick results.
+///
+/// Shared by `AsyncKernelBuilder::run` and island runs (`crate::island`).
+pub(crate) struct RunTally {
+    max_ticks: usize,
+    stable_threshold: usize,
+    interval_ms: u64,
+    tick_results: Vec<TickResult>,
+    applied_patches: Vec<Patch>,
+    pressure_history: Vec<f64>,
+    axis_history: Vec<PressureVector>,
+    pareto_archive: ParetoArchive,
+    prompt_tokens: u32,
+    completion_tokens: u32,
+    stable_ticks: usize,
+    final_pressure: f64,
+    reputation: ReputationTable,
+    best_so_far: Option<BestSoFar>,
+    coupling: CouplingMatrix,
+    violations: ViolationCounts,
+}
 
-            let now_ms = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_millis() as u64;
+impl RunTally {
+    /// Start an empty tally with the stopping rules of `config`.
+    pub(crate) fn new(config: &KernelConfig) -> Self {
+        Self {
+            max_ticks: config.max_ticks,
+            stable_threshold: config.stable_threshold,
+            interval_ms: config.tick_interval_ms,
+            tick_results: Vec::new(),
+            applied_patches: Vec::new(),
+            pressure_history: Vec::new(),
+            axis_history: Vec::new(),
+            pareto_archive: ParetoArchive::new(),
+            prompt_tokens: 0,
+            completion_tokens: 0,
+            stable_ticks: 0,
+            final_pressure: 0.0,
+            reputation: ReputationTable::new(),
+            best_so_far: None,
+            coupling: CouplingMatrix::new(),
+            violations: ViolationCounts::default(),
+        }
+    }
 
-            // Send Tick to coordinator
-            coordinator_handle.send(Tick { now_ms }).await;
+    /// Record a tick's result; returns why the kernel should stop, if it should.
+    ///
+    /// Stopping rules follow any config update reported in the result.
+    pub(crate) fn record(&mut self, result: TickResult) -> Option<StopReason> {
+        if let Some(change) = result.config_changes.last() {
+            self.max_ticks = change.config.max_ticks;
+            self.stable_threshold = change.config.stable_threshold;
+            self.interval_ms = change.config.tick_interval_ms;
+        }
+        self.pressure_history.push(result.total_pressure);
+        self.axis_history.push(result.axis_pressures.clone());
+        if let Some(snapshot) = result.pareto_snapshot.clone() {
+            self.pareto_archive.offer(snapshot);
+        }
+        self.prompt_tokens += result.prompt_tokens;
+        self.completion_tokens += result.completion_tokens;
+        self.applied_patches.extend(result.applied.clone());
+        for sample in &result.coupling {
+            self.coupling.record(sample);
+        }
+        for violation in &result.violations {
+            self.violations.record(violation);
+        }
+        self.final_pressure = result.total_pressure;
+        self.reputation.clone_from(&result.reputation);
+        if let Some(best) = &result.best_so_far {
+            self.best_so_far = Some(best.clone());
+        }
 
-            // Wait for TickComplete via the observer channel
-            let Some(result) = tick_rx.recv().await else {
-                tracing::warn!("TickComplete channel closed unexpectedly");
-                break;
-            };
+        // Track stability (consecutive ticks with no patches)
+        if result.applied.is_empty() {
+            self.stable_ticks += 1;
+        } else {
+            self.stable_ticks = 0;
+        }
 
-            // Track results
-            pressure_history.push(result.total_pressure);
-            prompt_tokens += result.prompt_tokens;
-            completion_tokens += result.completion_tokens;
-            applied_patches.extend(result.applied.clone());
-            final_pressure = result.total_pressure;
-
-            // Track stability (consecutive ticks with no patches)
-            if result.applied.is_empty() {
-                stable_ticks += 1;
-            } else {
-                stable_ticks = 0;
-            }
+        let is_complete = result.is_complete;
+        self.tick_results.push(result);
+        let ticks = self.ticks();
+
+        if is_complete {
+            Some(StopReason::Complete)
+        } else if self.max_ticks > 0 && ticks >= self.max_ticks {
+            Some(StopReason::MaxTicks)
+        } else if self.stable_threshold > 0 && self.stable_ticks >= self.stable_threshold {
+            Some(StopReason::Converged {
+                stable_ticks: self.stable_ticks,
+            })
+        } else {
+            None
+        }
+    }
 
-            let is_complete = result.is_complete;
-            tick_results.push(result);
+    /// Tick interval of the latest config.
+    pub(crate) fn interval_ms(&self) -> u64 {
+        self.interval_ms
+    }
 
-            // Check termination conditions
-            if is_complete {
-                stop_reason = StopReason::Complete;
-                break;
-            } else if max_ticks > 0 && current_tick >= max_ticks {
-                stop_reason = StopReason::MaxTicks;
-                break;
-            } else if stable_threshold > 0 && stable_ticks >= stable_threshold {
-                stop_reason = StopReason::Converged { stable_ticks };
-                break;
-            }
+    /// Ticks recorded so far.
+    pub(crate) fn ticks(&self) -> usize {
+        self.tick_results.len()
+    }
 
-            // Wait for the interval before next tick
-            tokio::time::sleep(std::time::Duration::from_millis(interval_ms)).await;
-        }
+    /// Total pressure of the latest tick.
+    pub(crate) fn final_pressure(&self) -> f64 {
+        self.final_pressure
+    }
 
+    /// Build the kernel result.
+    pub(crate) fn finish(
+        self,
+        stop_reason: StopReason,
+        final_source: Option<String>,
+    ) -> KernelResult {
         tracing::info!(
-            ticks_executed = current_tick,
+            ticks_executed = self.ticks(),
             ?stop_reason,
-            final_pressure = format!("{:.3}", final_pressure),
+            final_pressure = format!("{:.3}", self.final_pressure),
             "Kernel complete"
         );
 
         KernelResult {
-            ticks_executed: current_tick,
-            final_pressure,
+            ticks_executed: self.ticks(),
+            final_pressure: self.final_pressure,
             stop_reason,
-            applied_patches,
-            prompt_tokens,
-            completion_tokens,
-            final_source: None, // TODO: query from artifact via SaveArtifact
-            tick_results,
-            pressure_history,
+            applied_patches: self.applied_patches,
+            prompt_tokens: self.prompt_tokens,
+            completion_tokens: self.completion_tokens,
+            final_source,
+            tick_results: self.tick_results,
+            pressure_history: self.pressure_history,
+            axis_history: self.axis_history,
+            pareto_archive: self.pareto_archive.into_snapshots(),
+            reputation: self.reputation,
+            best_so_far: self.best_so_far,
+            coupling: self.coupling.report(),
+            violations: self.violations,
         }
     }
 }
 
 /// Internal observer actor to collect TickComplete broadcasts.
+///
+/// Also forwards `KernelComplete`; one actor handles both so a tick's result
+/// is always forwarded before the shutdown that follows it.
 struct TickResultObserver {
     tx: tokio::sync::mpsc::Sender<TickResult>,
+    complete_tx: tokio::sync::mpsc::Sender<KernelComplete>,
 }
 
 impl TickResultObserver {
-    fn new(tx: tokio::sync::mpsc::Sender<TickResult>) -> Self {
-        Self { tx }
+    fn new(
+        tx: tokio::sync::mpsc::Sender<TickResult>,
+        complete_tx: tokio::sync::mpsc::Sender<KernelComplete>,
+    ) -> Self {
+        Self { tx, complete_tx }
     }
 
     async fn spawn(self, runtime: &mut ActorRuntime) {
@@ -431,13 +644,16 @@ impl TickResultObserver {
         #[derive(Default, Clone, Debug)]
         struct State {
             tx: Option<tokio::sync::mpsc::Sender<TickResult>>,
+            complete_tx: Option<tokio::sync::mpsc::Sender<KernelComplete>>,
         }
 
         let mut actor = runtime.new_actor_with_name::<State>("TickResultObserver".to_string());
         actor.model.tx = Some(self.tx);
+        actor.model.complete_tx = Some(self.complete_tx);
 
-        // Subscribe to TickComplete broadcasts
+        // Subscribe to TickComplete and KernelComplete broadcasts
         actor.handle().subscribe::<TickComplete>().await;
+        actor.handle().subscribe::<KernelComplete>().await;
 
         actor.act_on::<TickComplete>(|actor, context| {
             let msg = context.message();
@@ -451,6 +667,16 @@ impl TickResultObserver {
             })
         });
 
+        actor.mutate_on::<KernelComplete>(|actor, context| {
+            let complete = context.message().clone();
+            let tx = actor.model.complete_tx.clone();
+            Reply::pending(async move {
+                if let Some(tx) = tx {
+                    let _ = tx.send(complete).await;
+                }
+            })
+        });
+
         actor.start().await;
     }
 }
@@ -538,4 +764,205 @@ mod tests {
         half_life_decay(&mut value, 600_000, 600_000); // one half-life
         assert!((value - 0.5).abs() < 0.01);
     }
+
+    #[test]
+    fn test_decay_overrides_and_tick_half_lives() {
+        use crate::config::{DecayOverride, HalfLife};
+
+        let mut decay = KernelConfig::default().decay;
+        decay.pressure_half_life = Some(HalfLife::Ticks(4));
+        decay.kinds.insert(
+            "block".to_string(),
+            DecayOverride {
+                fitness_half_life: Some(HalfLife::Ticks(2)),
+                ema_alpha: Some(0.5),
+                ..Default::default()
+            },
+        );
+        decay.axes.insert(
+            "overlaps".to_string(),
+            DecayOverride {
+                pressure_half_life: Some(HalfLife::Ms(1_000)),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(decay.fitness_half_life("block"), HalfLife::Ticks(2));
+        assert_eq!(decay.fitness_half_life("other"), HalfLife::Ms(600_000));
+        assert_eq!(
+            decay.pressure_half_life("overlaps", "block"),
+            Some(HalfLife::Ms(1_000))
+        );
+        assert_eq!(
+            decay.pressure_half_life("gaps", "block"),
+            Some(HalfLife::Ticks(4))
+        );
+        assert_eq!(decay.ema_alpha("gaps", "block"), 0.5);
+        assert_eq!(decay.ema_alpha("gaps", "other"), 0.2);
+
+        // Tick half-lives ignore wall-clock time
+        assert_eq!(HalfLife::Ticks(2).retention(1_000_000, 2), 0.5);
+        assert_eq!(HalfLife::Ticks(2).retention(1_000_000, 0), 1.0);
+        assert_eq!(HalfLife::Ms(0).retention(1_000_000, 10), 1.0);
+    }
+
+    /// One region holding fixed text.
+    struct FixedArtifact {
+        id: RegionId,
+    }
+
+    impl Artifact for FixedArtifact {
+        fn region_ids(&self) -> Vec<RegionId> {
+            vec![self.id.clone()]
+        }
+
+        fn read_region(&self, id: RegionId) -> anyhow::Result<crate::region::RegionView> {
+            Ok(crate::region::RegionView {
+                id,
+                kind: "text".to_string(),
+                content: "fixed".to_string(),
+                metadata: HashMap::new(),
+                parent: None,
+                children: Vec::new(),
+            })
+        }
+
+        fn apply_patch(&mut self, _patch: Patch) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn source(&self) -> Option<String> {
+            Some("fixed".to_string())
+        }
+    }
+
+    struct LengthSensor;
+
+    impl Sensor for LengthSensor {
+        fn name(&self) -> &str {
+            "length"
+        }
+
+        fn measure(&self, region: &crate::region::RegionView) -> anyhow::Result<crate::Signals> {
+            Ok(HashMap::from([(
+                "length".to_string(),
+                region.content.len() as f64,
+            )]))
+        }
+    }
+
+    /// Counts requests and fails every one of them.
+    struct FailingProposer {
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl Proposer for FailingProposer {
+        fn name(&self) -> &str {
+            "failing"
+        }
+
+        fn propose(
+            &self,
+            _request: &crate::messages::ProposeForRegion,
+        ) -> anyhow::Result<crate::proposer::Proposal> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            anyhow::bail!("no idea")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_proposer_is_registered_and_always_answers() {
+        use crate::config::PressureAxisConfig;
+        use mti::prelude::*;
+
+        let config = KernelConfig {
+            tick_interval_ms: 5,
+            max_ticks: 3,
+            stable_threshold: 0,
+            pressure_axes: vec![PressureAxisConfig {
+                name: "length".to_string(),
+                weight: 1.0,
+                expr: "length".to_string(),
+                kind_weights: HashMap::new(),
+                hard: false,
+                priority: 0,
+                normalization: crate::normalize::Normalization::None,
+            }],
+            ..KernelConfig::default()
+        };
+        let artifact = FixedArtifact {
+            id: "region".create_type_id::<V7>(),
+        };
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        // No hand-written patch actors: the proposer is counted by the builder
+        let mut runtime = ActonApp::launch_async().await;
+        let result = AsyncKernelBuilder::new(config, Box::new(artifact))
+            .add_sensor(Box::new(LengthSensor))
+            .add_proposer(Box::new(FailingProposer {
+                calls: calls.clone(),
+            }))
+            .run(&mut runtime, 0)
+            .await;
+        let _ = runtime.shutdown_all().await;
+
+        // Every failed request was still answered, so no tick hung
+        assert_eq!(result.stop_reason, StopReason::MaxTicks);
+        assert_eq!(result.ticks_executed, 3);
+        assert!(calls.load(std::sync::atomic::Ordering::SeqCst) >= 1);
+        assert!(result.applied_patches.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_pause_then_shutdown_saves_state() {
+        use crate::messages::{Pause, Shutdown};
+        use mti::prelude::*;
+
+        let config = KernelConfig {
+            tick_interval_ms: 5,
+            max_ticks: 0,
+            stable_threshold: 0,
+            ..KernelConfig::default()
+        };
+        let artifact = FixedArtifact {
+            id: "region".create_type_id::<V7>(),
+        };
+        let save_to = std::env::temp_dir().join(format!(
+            "survival-kernel-shutdown-{}",
+            "test".create_type_id::<V7>()
+        ));
+
+        let mut runtime = ActonApp::launch_async().await;
+        let broker = runtime.broker().clone();
+        let control = {
+            let save_to = save_to.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                broker.broadcast(Pause).await;
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                broker
+                    .broadcast(Shutdown {
+                        save_to: Some(save_to),
+                    })
+                    .await;
+            })
+        };
+        let result = AsyncKernelBuilder::new(config, Box::new(artifact))
+            .add_sensor(Box::new(LengthSensor))
+            .run(&mut runtime, 0)
+            .await;
+        control.await.expect("control task");
+        let _ = runtime.shutdown_all().await;
+
+        assert_eq!(result.stop_reason, StopReason::Requested);
+        assert!(result.ticks_executed > 0);
+        let artifact = std::fs::read_to_string(save_to.join("artifact.txt")).expect("artifact");
+        assert_eq!(artifact, "fixed");
+        let regions: HashMap<String, serde_json::Value> = serde_json::from_str(
+            &std::fs::read_to_string(save_to.join("regions.json")).expect("regions"),
+        )
+        .expect("valid json");
+        assert_eq!(regions.len(), 1);
+        let _ = std::fs::remove_dir_all(&save_to);
+    }
 }
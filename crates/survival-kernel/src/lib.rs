@@ -12,13 +12,13 @@
 //! // Create runtime
 //! let mut runtime = ActonApp::launch_async().await;
 //!
-//! // Spawn your patch actor first (it needs coordinator handle later)
-//! // ... spawn LlmActor or other patch actors ...
+//! // Patch actors written by hand (e.g. LlmActor) are spawned first and
+//! // register themselves; a plain `Proposer` is wrapped by the kernel
 //!
-//! // Build kernel with sensors and pre-spawned actor handles
+//! // Build kernel with sensors and proposers
 //! let coordinator = AsyncKernelBuilder::new(config, Box::new(artifact))
 //!     .add_sensor(Box::new(MySensor))
-//!     .add_patch_actor_handle(llm_actor_handle)
+//!     .add_proposer(Box::new(MyProposer))
 //!     .spawn(&mut runtime)
 //!     .await;
 //!
@@ -29,24 +29,71 @@
 pub mod actors;
 pub mod artifact;
 pub mod config;
+pub mod constraint;
+pub mod coupling;
+pub mod island;
 pub mod kernel;
 pub mod messages;
+pub mod monitor;
+pub mod normalize;
+pub mod pareto;
+pub mod pheromone;
 pub mod pressure;
+pub mod proposer;
 pub mod region;
+pub mod reload;
+pub mod reputation;
+pub mod scripted;
+pub mod stagnation;
+pub mod toy;
+pub mod transform;
 
 pub use actors::{
-    KernelCoordinator, KernelCoordinatorState, RegionActor, RegionActorState, SensorActor,
-    SensorActorState,
+    KernelCoordinator, KernelCoordinatorState, PatchActorFactory, PatchActorSupervisor,
+    ProposerActor, RegionActor, RegionActorState, RegionSpawner, SensorActor, SensorActorState,
 };
 pub use artifact::Artifact;
-pub use config::{KernelConfig, PressureAxisConfig};
-pub use kernel::{AsyncKernelBuilder, KernelResult, TickResult, half_life_decay};
+pub use config::{
+    AcceptanceMode, DiffusionConfig, ExecutionMode, GranularityConfig, KernelConfig,
+    MeasurementConfig, MembershipConfig, MessagingConfig, MigrationConfig, MonitorConfig,
+    PheromoneConfig, PressureAxisConfig, ProvenanceConfig, ReputationConfig, StagnationConfig,
+    StagnationResponse,
+};
+pub use constraint::{AxisVerdict, Rejection};
+pub use coupling::{CouplingEntry, CouplingMatrix, CouplingReport, CouplingSample, CouplingStats};
+pub use island::{Island, IslandModel, IslandResult, MigrationEvent};
+pub use kernel::{AsyncKernelBuilder, BestSoFar, KernelResult, TickResult, half_life_decay};
 pub use messages::{
-    ApplyDecay, CoordinatorReady, KernelComplete, MeasureRegion, MeasurementResult,
-    PatchActorReady, PatchActorsReady, PatchProposal, PressureResponse, ProposeForRegion,
-    QueryPressure, RefreshContent, RegionApplyPatch, RegionPatchResult, RegisterRegionActors,
-    SaveArtifact, SensorReady, SensorsReady, SetOutputDir, StopReason, Tick, TickComplete,
-    ValidatePatch, ValidatePatchResponse, WaitForPatchActors, WaitForSensors,
+    ApplyDecay, BestSoFarReport, ConfigUpdated, CoordinatorReady, DepartureReason, ExportRegions,
+    ImportRegions, KernelComplete, KickRegion, MeasureRegion, MeasureRegions, MeasurementBatch,
+    MeasurementFailed, MeasurementResult, MigrantRegion, NoPatchProposed, PatchActorGone,
+    PatchActorHeartbeat, PatchActorReady, PatchActorsReady, PatchProposal, PressureResponse,
+    PressureSummary, ProposeForRegion, ProvenanceReport, QueryBestSoFar, QueryPressure,
+    QueryProvenance, RefreshContent, RegionApplyPatch, RegionExport, RegionPatchResult,
+    RegionRetired, RegionWake, RegionsImported, RegionsRestructured, RegisterRegionActors,
+    RequestProposal, RetireRegion, SaveArtifact, SensorReady, SensorsReady, SetOutputDir,
+    SteerRegion, SteerRegions, SteeringUpdate, StopReason, SuspendRegions, Tick, TickComplete,
+    UpdateConfig, UpdatePressureAxes, ValidatePatch, ValidatePatchResponse, WaitForPatchActors,
+    WaitForSensors,
+};
+pub use monitor::{AssumptionMonitor, AssumptionViolation, ViolationCounts};
+pub use normalize::{Normalization, NormalizationStats, SignalNormalizer};
+pub use pareto::{ParetoArchive, ParetoSnapshot};
+pub use pheromone::{Pheromone, PheromoneKind, PheromoneStore};
+pub use pressure::{
+    Pressure, PressureVector, Sensor, Signals, measure_pressure_inline,
+    measure_pressure_inline_normalized,
+};
+pub use proposer::{AsyncProposer, Proposal, Proposer};
+pub use region::{
+    NeighborView, Patch, PatchOp, PatchOrigin, ProvenanceRecord, RegionId, RegionState, RegionView,
+    Steering,
+};
+pub use reload::{ConfigChange, ConfigError};
+pub use reputation::{ActorReputation, ReputationTable};
+pub use scripted::{
+    GarbageProposer, RandomProposer, ReplayProposer, RequestLog, SilentProposer, SleepyProposer,
 };
-pub use pressure::{Pressure, PressureVector, Sensor, Signals, measure_pressure_inline};
-pub use region::{Patch, PatchOp, RegionId, RegionState, RegionView};
+pub use stagnation::{StagnationKick, StagnationTracker};
+pub use toy::{Decrement, ToyArtifact, ToySensor};
+pub use transform::{DropDuplicatePatches, PatchTransformer, Transformed, TrimContent, Veto};
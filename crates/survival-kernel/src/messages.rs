@@ -5,8 +5,16 @@
 
 use std::collections::HashMap;
 
+use crate::actors::RegionSpawner;
+use crate::config::{DecayConfig, KernelConfig, PressureAxisConfig};
+use crate::constraint::Rejection;
+use crate::kernel::BestSoFar;
+use crate::pheromone::Pheromone;
 use crate::pressure::{PressureVector, Signals};
-use crate::region::{Patch, RegionId, RegionState, RegionView};
+use crate::region::{
+    NeighborView, Patch, PatchOrigin, ProvenanceRecord, RegionId, RegionState, RegionView, Steering,
+};
+use crate::reload::{ConfigChange, ConfigError};
 
 /// Broadcast by coordinator after it starts to signal that patch actors
 /// can now register themselves.
@@ -24,6 +32,8 @@ pub struct CoordinatorReady;
 pub struct SensorReady {
     /// The sensor actor's ERN
     pub sensor_ern: String,
+    /// The actor's handle, so the coordinator can stop it on shutdown
+    pub handle: acton_reactive::prelude::ActorHandle,
 }
 
 /// Notification that a patch actor is ready - broadcast on start.
@@ -38,6 +48,47 @@ pub struct PatchActorReady {
     pub handle: acton_reactive::prelude::ActorHandle,
 }
 
+/// Why a patch actor left the dispatch pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepartureReason {
+    /// The actor stopped deliberately (scale-down or shutdown)
+    Stopped,
+    /// The actor failed and should be replaced if a supervisor is configured
+    Failed,
+    /// The actor missed its heartbeat deadline
+    TimedOut,
+}
+
+/// Notification that a patch actor is leaving - broadcast on stop or failure.
+///
+/// The coordinator removes the actor from round-robin dispatch and hands any
+/// outstanding proposals it owned to the remaining actors. The coordinator
+/// also broadcasts this itself when it evicts an actor for missing heartbeats,
+/// so a `PatchActorSupervisor` can restart it.
+#[derive(Debug, Clone)]
+pub struct PatchActorGone {
+    /// The departing actor's ERN (as sent in `PatchActorReady`)
+    pub actor_ern: String,
+    /// Why the actor left
+    pub reason: DepartureReason,
+}
+
+/// Liveness signal from a patch actor.
+///
+/// Only required when `MembershipConfig::heartbeat_timeout_ms` is non-zero.
+/// Replying to a `ProposeForRegion` also counts as a heartbeat.
+#[derive(Debug, Clone)]
+pub struct PatchActorHeartbeat {
+    /// The patch actor's ERN
+    pub actor_ern: String,
+}
+
+/// Sweep for timed-out patch actors and overdue proposals.
+///
+/// Scheduled by the coordinator itself while proposals are outstanding.
+#[derive(Debug, Clone)]
+pub struct SweepPatchActors;
+
 /// Request to measure signals for a region - broadcast to SensorActors.
 #[derive(Debug, Clone)]
 pub struct MeasureRegion {
@@ -47,6 +98,8 @@ pub struct MeasureRegion {
     pub region_id: RegionId,
     /// View of the region content
     pub region_view: RegionView,
+    /// Views of the region's neighbors (empty if the artifact defines none)
+    pub neighbors: Vec<NeighborView>,
     /// Current timestamp
     pub now_ms: u64,
 }
@@ -64,6 +117,51 @@ pub struct MeasurementResult {
     pub signals: Signals,
 }
 
+/// A sensor failed to measure a region - sent back to Coordinator.
+///
+/// Counts toward the expected measurements; the region is sent no signals
+/// and keeps the ones it has.
+#[derive(Debug, Clone)]
+pub struct MeasurementFailed {
+    /// Correlation ID matching the original request
+    pub correlation_id: String,
+    /// The region that could not be measured
+    pub region_id: RegionId,
+    /// Name of the sensor that failed
+    pub sensor_name: String,
+}
+
+/// Request to measure a batch of regions - broadcast to SensorActors.
+///
+/// Used instead of `MeasureRegion` when `MessagingConfig::batch_size` > 0.
+#[derive(Debug, Clone)]
+pub struct MeasureRegions {
+    /// Correlation ID for this tick's measurements
+    pub correlation_id: String,
+    /// The regions to measure
+    pub regions: Vec<(RegionId, RegionView)>,
+    /// Neighbor views for regions in this batch that have neighbors
+    pub neighbors: HashMap<RegionId, Vec<NeighborView>>,
+    /// Current timestamp
+    pub now_ms: u64,
+}
+
+/// Result of measuring a batch of regions - sent back to Coordinator.
+///
+/// Regions whose measurement failed are listed in `failed` and, as with
+/// `MeasurementFailed`, sent no signals.
+#[derive(Debug, Clone)]
+pub struct MeasurementBatch {
+    /// Correlation ID matching the original request
+    pub correlation_id: String,
+    /// Name of the sensor that produced these results
+    pub sensor_name: String,
+    /// Measured signals per region
+    pub results: Vec<(RegionId, Signals)>,
+    /// Regions the sensor failed to measure
+    pub failed: Vec<RegionId>,
+}
+
 /// Request to propose patches for a high-pressure region - sent to PatchActors.
 #[derive(Debug, Clone)]
 pub struct ProposeForRegion {
@@ -81,6 +179,12 @@ pub struct ProposeForRegion {
     pub state: RegionState,
     /// Handle to ClaimManager for stigmergic column/value claims (optional for backwards compat)
     pub claim_manager: Option<acton_reactive::prelude::ActorHandle>,
+    /// Set when a stagnation kick asks for an exploratory proposal (see
+    /// `crate::stagnation`); patch actors may seed their sampling with it
+    pub explore_seed: Option<u64>,
+    /// Heaviest pheromones on this region, accepted then rejected (empty
+    /// unless pheromones are enabled, see `crate::pheromone`)
+    pub pheromones: Vec<Pheromone>,
 }
 
 /// Patch proposal result - sent back to Coordinator.
@@ -88,8 +192,18 @@ pub struct ProposeForRegion {
 pub struct PatchProposal {
     /// Correlation ID matching the original request
     pub correlation_id: String,
+    /// The region this proposal answers (from `ProposeForRegion::region_id`)
+    pub region_id: RegionId,
     /// Name of the actor that produced this proposal
     pub actor_name: String,
+    /// ERN of the actor that produced this proposal (as sent in
+    /// `PatchActorReady`); replies from any other actor than the one the
+    /// request is outstanding with are ignored
+    pub actor_ern: String,
+    /// Model behind the actor, if any (recorded in provenance)
+    pub model: Option<String>,
+    /// Sampling band of the actor, if any (recorded in provenance)
+    pub sampling_band: Option<String>,
     /// Proposed patches with scores (higher = better)
     pub patches: Vec<(f64, Patch)>,
     /// Prompt tokens used for this proposal (for metrics tracking)
@@ -126,6 +240,10 @@ pub enum StopReason {
     },
     /// Reached max_ticks limit
     MaxTicks,
+    /// Another island completed the artifact first (see `crate::island`)
+    Superseded,
+    /// A `Shutdown` message stopped the kernel
+    Requested,
 }
 
 /// Notification that the kernel has finished all ticks.
@@ -141,6 +259,57 @@ pub struct KernelComplete {
     pub final_pressure: f64,
 }
 
+/// Swap the coordinator's `KernelConfig` during a run (see `crate::reload`).
+///
+/// The coordinator answers with a `ConfigUpdated` broadcast. An accepted
+/// config applies from the next tick and is reported in its `TickResult`.
+#[derive(Debug, Clone)]
+pub struct UpdateConfig {
+    /// Correlation ID echoed in the answer
+    pub correlation_id: String,
+    /// The complete replacement config
+    pub config: KernelConfig,
+}
+
+/// Outcome of an `UpdateConfig` - broadcast by the coordinator.
+#[derive(Debug, Clone)]
+pub struct ConfigUpdated {
+    /// Correlation ID matching the request
+    pub correlation_id: String,
+    /// The recorded change, or why the config was rejected
+    pub result: Result<ConfigChange, ConfigError>,
+}
+
+/// Hold ticks until `Resume` or `Step` (sent or broadcast to the coordinator).
+///
+/// A tick already running completes; the next `Tick` waits.
+#[derive(Debug, Clone)]
+pub struct Pause;
+
+/// Resume ticking after `Pause` or `Step`.
+#[derive(Debug, Clone)]
+pub struct Resume;
+
+/// Run `n` more ticks, then pause.
+#[derive(Debug, Clone)]
+pub struct Step {
+    /// Ticks to run before pausing
+    pub n: usize,
+}
+
+/// Stop the kernel gracefully.
+///
+/// The coordinator lets the current tick complete, collects every region's
+/// state, optionally writes the artifact (`artifact.txt`) and region states
+/// (`regions.json`) to `save_to`, stops all region, sensor and claim actors,
+/// and broadcasts `KernelComplete` with `StopReason::Requested`. Later ticks
+/// are ignored.
+#[derive(Debug, Clone)]
+pub struct Shutdown {
+    /// Directory to persist the artifact and region states to
+    pub save_to: Option<std::path::PathBuf>,
+}
+
 /// Wait until expected number of patch actors have registered.
 ///
 /// Sent to coordinator, blocks until all actors are ready.
@@ -179,28 +348,31 @@ pub struct SensorsReady {
 // RegionActor Messages
 // ============================================================================
 
-/// Apply temporal decay to region fitness and confidence.
+/// Apply temporal decay to region fitness, confidence and pressure EMA.
 ///
 /// Broadcast to all RegionActors at the start of each tick.
 #[derive(Debug, Clone)]
 pub struct ApplyDecay {
     /// Current timestamp for decay calculation
     pub now_ms: u64,
-    /// Half-life for fitness decay (milliseconds)
-    pub fitness_half_life_ms: u64,
-    /// Half-life for confidence decay (milliseconds)
-    pub confidence_half_life_ms: u64,
+    /// Current tick number (for tick-based half-lives)
+    pub tick: usize,
+    /// Half-lives and EMA smoothing, with per-axis and per-kind overrides
+    pub decay: DecayConfig,
 }
 
 /// Query a region's current pressure state.
 ///
-/// Sent to RegionActor, expects PressureResponse.
+/// Sent to RegionActor, expects PressureResponse (or PressureSummary when
+/// `summary_only` is set).
 #[derive(Debug, Clone)]
 pub struct QueryPressure {
     /// Correlation ID for this query
     pub correlation_id: String,
     /// Current timestamp
     pub now_ms: u64,
+    /// Reply with a content-free `PressureSummary` instead of a full response
+    pub summary_only: bool,
 }
 
 /// Response with region's pressure state.
@@ -214,9 +386,11 @@ pub struct PressureResponse {
     pub region_id: RegionId,
     /// Total weighted pressure
     pub total_pressure: f64,
-    /// Whether the region is currently inhibited
+    /// `total_pressure` after external steering (what activation compares)
+    pub steered_pressure: f64,
+    /// Whether the region is currently inhibited (or frozen)
     pub is_inhibited: bool,
-    /// Current state snapshot
+    /// Current state snapshot, including its `steering`
     pub state: RegionState,
     /// View of the region content
     pub view: RegionView,
@@ -224,6 +398,81 @@ pub struct PressureResponse {
     pub signals: Signals,
 }
 
+/// Content-free pressure state of a region.
+///
+/// Sent from RegionActor back to Coordinator for `QueryPressure` with
+/// `summary_only`. Full responses are then fetched only for regions
+/// selected for proposals.
+#[derive(Debug, Clone)]
+pub struct PressureSummary {
+    /// Correlation ID matching the original request
+    pub correlation_id: String,
+    /// The region that was queried
+    pub region_id: RegionId,
+    /// Enclosing region, for aggregating pressure up the hierarchy
+    pub parent: Option<RegionId>,
+    /// Total weighted pressure
+    pub total_pressure: f64,
+    /// `total_pressure` after external steering (what activation compares)
+    pub steered_pressure: f64,
+    /// Weighted pressure per axis
+    pub pressures: PressureVector,
+    /// Whether the region is currently inhibited (or frozen)
+    pub is_inhibited: bool,
+    /// Active steering, if any
+    pub steering: Option<Steering>,
+}
+
+/// Wake a RegionActor in async mode.
+///
+/// Broadcast once by the coordinator on the first observation; each region
+/// then re-sends it to itself every `interval_ms`. On each wake the region
+/// decays, re-measures, and requests a proposal if it is under pressure.
+#[derive(Debug, Clone)]
+pub struct RegionWake {
+    /// Current timestamp
+    pub now_ms: u64,
+    /// Half-lives and EMA smoothing (tick half-lives count wakes)
+    pub decay: DecayConfig,
+    /// Minimum total pressure before the region requests a proposal
+    pub min_total_pressure: f64,
+    /// Delay before the region wakes again (milliseconds)
+    pub interval_ms: u64,
+}
+
+/// Stop autonomous region wakeups.
+///
+/// Broadcast when an async-mode run ends so regions stop requesting proposals.
+#[derive(Debug, Clone)]
+pub struct SuspendRegions;
+
+/// Request from a RegionActor for a proposal for itself (async mode).
+///
+/// Sent to the coordinator, which dispatches a `ProposeForRegion` to the
+/// least-loaded patch actor and routes the best patch back as `RegionApplyPatch`.
+#[derive(Debug, Clone)]
+pub struct RequestProposal {
+    /// The region asking for a proposal
+    pub region_id: RegionId,
+    /// View of the region content
+    pub region_view: RegionView,
+    /// Current signals for this region
+    pub signals: Signals,
+    /// Current pressures for this region
+    pub pressures: PressureVector,
+    /// Current state for this region
+    pub state: RegionState,
+    /// Current timestamp
+    pub now_ms: u64,
+}
+
+/// Reply to `RequestProposal` when no usable patch was proposed (async mode).
+#[derive(Debug, Clone)]
+pub struct NoPatchProposed {
+    /// The region that asked
+    pub region_id: RegionId,
+}
+
 /// Apply a patch to a region with validation.
 ///
 /// Sent to RegionActor. The actor validates that the patch actually reduces
@@ -234,9 +483,11 @@ pub struct RegionApplyPatch {
     pub correlation_id: String,
     /// The patch to apply
     pub patch: Patch,
+    /// Who proposed the patch
+    pub origin: PatchOrigin,
     /// Current timestamp
     pub now_ms: u64,
-    /// Current tick number (for file naming)
+    /// Current tick number (for file naming and provenance)
     pub tick: usize,
     /// Inhibition window after applying (milliseconds)
     pub inhibit_ms: u64,
@@ -255,12 +506,16 @@ pub struct RegionPatchResult {
     pub region_id: RegionId,
     /// Whether the patch was accepted
     pub success: bool,
-    /// New content if patch was applied
+    /// Content the patch proposed (applied only if `success`)
     pub new_content: Option<String>,
     /// Actual measured pressure improvement (positive = better)
     pub pressure_delta: f64,
-    /// Error message if patch was rejected
-    pub error: Option<String>,
+    /// Improvement the patch predicted (weighted sum over axes)
+    pub expected_delta: f64,
+    /// Who proposed the patch (for reputation tracking)
+    pub origin: PatchOrigin,
+    /// Why the patch was rejected (None when accepted)
+    pub rejection: Option<Rejection>,
 }
 
 /// Update region content after artifact re-parse.
@@ -272,6 +527,72 @@ pub struct RefreshContent {
     pub new_content: String,
     /// Updated metadata
     pub metadata: HashMap<String, serde_json::Value>,
+    /// Enclosing region
+    pub parent: Option<RegionId>,
+    /// Nested regions
+    pub children: Vec<RegionId>,
+}
+
+/// Stagnation kick for one region - sent from Coordinator to a RegionActor.
+///
+/// Clears the region's inhibition and resets its decayed fitness and
+/// confidence so it competes for proposals again.
+#[derive(Debug, Clone)]
+pub struct KickRegion {
+    /// Current timestamp
+    pub now_ms: u64,
+}
+
+/// How `SteerRegions` changes each region's `Steering`.
+#[derive(Debug, Clone)]
+pub enum SteeringUpdate {
+    /// Never activate; reject all patches
+    Freeze {
+        /// Why the regions are frozen
+        reason: Option<String>,
+    },
+    /// Lift a freeze (an active bias is kept)
+    Unfreeze,
+    /// Reshape activation pressure through the next `ticks` ticks, replacing
+    /// any earlier bias
+    Bias {
+        /// Added to the pressure
+        bias: f64,
+        /// Multiplies the pressure (before the bias)
+        focus: f64,
+        /// Ticks to apply for
+        ticks: usize,
+        /// Why the regions are steered
+        reason: Option<String>,
+    },
+}
+
+/// Steer regions of a running kernel (sent or broadcast to the coordinator).
+///
+/// The coordinator forwards the update to each region's actor as a
+/// `SteerRegion`; unknown regions are skipped.
+#[derive(Debug, Clone)]
+pub struct SteerRegions {
+    /// Regions to steer
+    pub regions: Vec<RegionId>,
+    /// What to change
+    pub update: SteeringUpdate,
+}
+
+/// Apply a steering update - sent from Coordinator to a RegionActor.
+#[derive(Debug, Clone)]
+pub struct SteerRegion {
+    /// What to change
+    pub update: SteeringUpdate,
+}
+
+/// Replace the pressure axes a RegionActor weighs its signals with.
+///
+/// Sent from Coordinator to every RegionActor after an accepted `UpdateConfig`.
+#[derive(Debug, Clone)]
+pub struct UpdatePressureAxes {
+    /// The new axis configuration
+    pub axes: Vec<PressureAxisConfig>,
 }
 
 /// Request coordinator to write artifact with proposed patch for validation.
@@ -337,6 +658,53 @@ pub struct EvaluatePatchResponse {
     pub pressure_delta: f64,
     /// The new content (for updating region state if accepted)
     pub new_content: String,
+    /// Why a hard or prioritized axis rejected the patch, if one did
+    pub rejection: Option<Rejection>,
+}
+
+/// Request the provenance history of a region.
+///
+/// Sent to the coordinator, which forwards it to the region's actor. The
+/// answer is broadcast as a `ProvenanceReport`.
+#[derive(Debug, Clone)]
+pub struct QueryProvenance {
+    /// Correlation ID echoed in the report
+    pub correlation_id: String,
+    /// Region to report on
+    pub region_id: RegionId,
+}
+
+/// Provenance history of a region - broadcast in answer to `QueryProvenance`.
+///
+/// `records` is empty for unknown regions and regions never patched.
+#[derive(Debug, Clone)]
+pub struct ProvenanceReport {
+    /// Correlation ID matching the query
+    pub correlation_id: String,
+    /// The region reported on
+    pub region_id: RegionId,
+    /// Applied patches, oldest first
+    pub records: Vec<ProvenanceRecord>,
+}
+
+/// Request the lowest-pressure artifact state seen so far.
+///
+/// Sent to the coordinator, which answers with a `BestSoFarReport` broadcast.
+#[derive(Debug, Clone)]
+pub struct QueryBestSoFar {
+    /// Correlation ID echoed in the report
+    pub correlation_id: String,
+}
+
+/// Best-so-far state - broadcast in answer to `QueryBestSoFar`.
+///
+/// `best` is `None` until the first tick has completed.
+#[derive(Debug, Clone)]
+pub struct BestSoFarReport {
+    /// Correlation ID matching the query
+    pub correlation_id: String,
+    /// Lowest-pressure state seen so far
+    pub best: Option<BestSoFar>,
 }
 
 /// Register region actors with the coordinator.
@@ -346,6 +714,41 @@ pub struct EvaluatePatchResponse {
 pub struct RegisterRegionActors {
     /// Map of region IDs to their actor handles
     pub actors: HashMap<RegionId, acton_reactive::prelude::ActorHandle>,
+    /// Spawns replacement RegionActors when regions are split or merged
+    pub spawner: Option<RegionSpawner>,
+}
+
+/// Hand a region's state back to the coordinator before its actor stops.
+///
+/// Sent to a RegionActor whose region was split or merged away. The actor
+/// answers with `RegionRetired` and ignores async wakeups from then on.
+#[derive(Debug, Clone)]
+pub struct RetireRegion {
+    /// Correlation ID of the restructure
+    pub correlation_id: String,
+}
+
+/// Final state of a retired region (RegionActor → Coordinator).
+#[derive(Debug, Clone)]
+pub struct RegionRetired {
+    /// Correlation ID of the restructure
+    pub correlation_id: String,
+    /// The retired region
+    pub region_id: RegionId,
+    /// Its state, carried over to the regions replacing it
+    pub state: RegionState,
+}
+
+/// Replacement RegionActors are running (coordinator → itself).
+///
+/// Sent once split and merged regions have actors, before the deferred
+/// tick resumes.
+#[derive(Debug, Clone)]
+pub struct RegionsRestructured {
+    /// Regions whose actors were stopped
+    pub retired: Vec<RegionId>,
+    /// Actors for the new regions
+    pub actors: HashMap<RegionId, acton_reactive::prelude::ActorHandle>,
 }
 
 /// Save the current artifact state to a file.
@@ -357,6 +760,61 @@ pub struct SaveArtifact {
     pub path: std::path::PathBuf,
 }
 
+/// Ask the coordinator for its best regions (island model).
+///
+/// Answered with a `RegionExport` broadcast.
+#[derive(Debug, Clone)]
+pub struct ExportRegions {
+    /// Correlation ID echoed in the export
+    pub correlation_id: String,
+    /// Lowest-pressure regions to include (0 = none, just totals and source)
+    pub count: usize,
+}
+
+/// A region offered to other islands, with its pressure on the source island.
+#[derive(Debug, Clone)]
+pub struct MigrantRegion {
+    /// The region's content on the source island
+    pub view: RegionView,
+    /// Its total pressure there
+    pub pressure: f64,
+}
+
+/// An island's best regions - broadcast in answer to `ExportRegions`.
+#[derive(Debug, Clone)]
+pub struct RegionExport {
+    /// Correlation ID from the request
+    pub correlation_id: String,
+    /// Lowest-pressure regions, lowest first
+    pub regions: Vec<MigrantRegion>,
+    /// Total pressure of the latest tick
+    pub total_pressure: f64,
+    /// Artifact source (if the artifact supports `source()`)
+    pub source: Option<String>,
+}
+
+/// Offer regions from other islands to the coordinator.
+///
+/// Each migrant whose region exists here is turned into a replace patch and
+/// applied only if the artifact's `evaluate_patch` (and the pressure axes)
+/// accept it as an improvement. Answered with a `RegionsImported` broadcast.
+#[derive(Debug, Clone)]
+pub struct ImportRegions {
+    /// Correlation ID echoed in the report
+    pub correlation_id: String,
+    /// Regions offered, best first
+    pub migrants: Vec<MigrantRegion>,
+}
+
+/// Outcome of an `ImportRegions` request.
+#[derive(Debug, Clone)]
+pub struct RegionsImported {
+    /// Correlation ID from the request
+    pub correlation_id: String,
+    /// Regions replaced by a migrant
+    pub accepted: Vec<RegionId>,
+}
+
 /// Set the output directory for validation artifacts.
 ///
 /// Sent to coordinator to configure where patched artifacts are written for validation.
@@ -0,0 +1,283 @@
+//! Runtime monitors for the convergence-theorem assumptions.
+//!
+//! The convergence argument relies on four properties the kernel is meant
+//! to maintain: every accepted patch improves its region by at least δ_min,
+//! total pressure never rises under greedy (weighted) acceptance, patching
+//! one region moves any other by at most ε, and a patched region is left
+//! alone for its inhibition window. With `MonitorConfig::enabled` the
+//! coordinator checks each of them as the run goes and reports what it finds
+//! as `AssumptionViolation`s in the `TickResult`; `KernelResult` carries the
+//! counts.
+//!
+//! The checks only observe: a violation is reported, never corrected.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::MonitorConfig;
+use crate::coupling::CouplingSample;
+use crate::messages::RegionPatchResult;
+use crate::region::RegionId;
+
+/// One observed breach of a convergence assumption.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AssumptionViolation {
+    /// An accepted patch improved its region by less than δ_min
+    DeltaBelowMinimum {
+        /// Region that was patched
+        region: RegionId,
+        /// Realized improvement (positive = better)
+        delta: f64,
+        /// Configured δ_min
+        min_delta: f64,
+    },
+    /// Total pressure rose under greedy acceptance
+    PressureIncreased {
+        /// Total pressure of the previous tick
+        previous: f64,
+        /// Total pressure of this tick
+        current: f64,
+    },
+    /// A patch moved another region's pressure by more than ε
+    CouplingExceeded {
+        /// Region that was patched
+        source: RegionId,
+        /// Region whose pressure moved
+        target: RegionId,
+        /// Signed change in the target's pressure
+        delta: f64,
+        /// Declared bound ε
+        bound: f64,
+    },
+    /// A region was patched again inside its inhibition window
+    InhibitionViolated {
+        /// Region that was patched
+        region: RegionId,
+        /// Time since its previous patch (milliseconds)
+        since_ms: u64,
+        /// Configured inhibition window (milliseconds)
+        inhibit_ms: u64,
+    },
+}
+
+/// Violations seen during a run, by assumption.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViolationCounts {
+    /// `AssumptionViolation::DeltaBelowMinimum`
+    pub delta_below_minimum: usize,
+    /// `AssumptionViolation::PressureIncreased`
+    pub pressure_increased: usize,
+    /// `AssumptionViolation::CouplingExceeded`
+    pub coupling_exceeded: usize,
+    /// `AssumptionViolation::InhibitionViolated`
+    pub inhibition_violated: usize,
+}
+
+impl ViolationCounts {
+    /// Count one violation.
+    pub fn record(&mut self, violation: &AssumptionViolation) {
+        match violation {
+            AssumptionViolation::DeltaBelowMinimum { .. } => self.delta_below_minimum += 1,
+            AssumptionViolation::PressureIncreased { .. } => self.pressure_increased += 1,
+            AssumptionViolation::CouplingExceeded { .. } => self.coupling_exceeded += 1,
+            AssumptionViolation::InhibitionViolated { .. } => self.inhibition_violated += 1,
+        }
+    }
+
+    /// Violations of any kind.
+    pub fn total(&self) -> usize {
+        self.delta_below_minimum
+            + self.pressure_increased
+            + self.coupling_exceeded
+            + self.inhibition_violated
+    }
+}
+
+/// Remembers when each region was last patched, for the inhibition check.
+#[derive(Debug, Clone, Default)]
+pub struct AssumptionMonitor {
+    last_patched_ms: HashMap<RegionId, u64>,
+}
+
+impl AssumptionMonitor {
+    /// Create a monitor that has seen nothing yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check the accepted patches among `results`.
+    ///
+    /// Inhibition is only checked when the results carry a tick time
+    /// (`now_ms`); async regions time their own windows.
+    pub fn check_patches(
+        &mut self,
+        results: &[RegionPatchResult],
+        now_ms: Option<u64>,
+        inhibit_ms: u64,
+        config: &MonitorConfig,
+    ) -> Vec<AssumptionViolation> {
+        let mut violations = Vec::new();
+        if !config.enabled {
+            return violations;
+        }
+        for result in results.iter().filter(|r| r.success) {
+            if result.pressure_delta < config.min_delta {
+                violations.push(AssumptionViolation::DeltaBelowMinimum {
+                    region: result.region_id.clone(),
+                    delta: result.pressure_delta,
+                    min_delta: config.min_delta,
+                });
+            }
+            let Some(now_ms) = now_ms else {
+                continue;
+            };
+            if let Some(last) = self
+                .last_patched_ms
+                .insert(result.region_id.clone(), now_ms)
+            {
+                let since_ms = now_ms.saturating_sub(last);
+                if since_ms < inhibit_ms {
+                    violations.push(AssumptionViolation::InhibitionViolated {
+                        region: result.region_id.clone(),
+                        since_ms,
+                        inhibit_ms,
+                    });
+                }
+            }
+        }
+        violations
+    }
+
+    /// Forget the last patch of regions whose inhibition was cleared
+    /// (stagnation kicks).
+    pub fn release(&mut self, regions: &[RegionId]) {
+        for id in regions {
+            self.last_patched_ms.remove(id);
+        }
+    }
+}
+
+/// Check that total pressure did not rise from `previous` to `current`.
+pub fn check_pressure(
+    previous: f64,
+    current: f64,
+    config: &MonitorConfig,
+) -> Option<AssumptionViolation> {
+    (config.enabled && current > previous + config.pressure_tolerance)
+        .then_some(AssumptionViolation::PressureIncreased { previous, current })
+}
+
+/// Check coupling samples against the declared bound.
+///
+/// Samples attribute a change to every region patched in the same tick (see
+/// `crate::coupling`), so a tick with several patches can over-report.
+pub fn check_coupling(
+    samples: &[CouplingSample],
+    config: &MonitorConfig,
+) -> Vec<AssumptionViolation> {
+    let Some(bound) = config.coupling_bound.filter(|_| config.enabled) else {
+        return Vec::new();
+    };
+    samples
+        .iter()
+        .filter(|s| s.delta.abs() > bound)
+        .map(|s| AssumptionViolation::CouplingExceeded {
+            source: s.source.clone(),
+            target: s.target.clone(),
+            delta: s.delta,
+            bound,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::region::PatchOrigin;
+    use mti::prelude::*;
+
+    fn result(region: &RegionId, success: bool, delta: f64) -> RegionPatchResult {
+        RegionPatchResult {
+            correlation_id: "patch".to_string(),
+            region_id: region.clone(),
+            success,
+            new_content: None,
+            pressure_delta: delta,
+            expected_delta: delta,
+            origin: PatchOrigin::default(),
+            rejection: None,
+        }
+    }
+
+    #[test]
+    fn test_monitors_report_each_assumption() {
+        let config = MonitorConfig {
+            enabled: true,
+            min_delta: 0.1,
+            coupling_bound: Some(0.5),
+            pressure_tolerance: 0.0,
+        };
+        let r1 = "region".create_type_id::<V7>();
+        let r2 = "region".create_type_id::<V7>();
+        let mut monitor = AssumptionMonitor::new();
+
+        // Rejected patches are not checked
+        let results = [result(&r1, true, 0.05), result(&r2, false, -1.0)];
+        let violations = monitor.check_patches(&results, Some(1_000), 30_000, &config);
+        assert!(matches!(
+            violations.as_slice(),
+            [AssumptionViolation::DeltaBelowMinimum { delta: 0.05, .. }]
+        ));
+
+        // r1 again inside its window; a kick would have released it
+        let again = [result(&r1, true, 1.0)];
+        let violations = monitor.check_patches(&again, Some(11_000), 30_000, &config);
+        assert_eq!(
+            violations,
+            vec![AssumptionViolation::InhibitionViolated {
+                region: r1.clone(),
+                since_ms: 10_000,
+                inhibit_ms: 30_000,
+            }]
+        );
+        monitor.release(std::slice::from_ref(&r1));
+        assert!(
+            monitor
+                .check_patches(&again, Some(12_000), 30_000, &config)
+                .is_empty()
+        );
+
+        assert!(check_pressure(3.0, 2.0, &config).is_none());
+        assert!(check_pressure(2.0, 3.0, &config).is_some());
+
+        let samples = [
+            CouplingSample {
+                tick: 1,
+                source: r1.clone(),
+                target: r2.clone(),
+                delta: -0.75,
+            },
+            CouplingSample {
+                tick: 1,
+                source: r1,
+                target: r2,
+                delta: 0.25,
+            },
+        ];
+        let violations = check_coupling(&samples, &config);
+        assert_eq!(violations.len(), 1);
+
+        let mut counts = ViolationCounts::default();
+        for violation in &violations {
+            counts.record(violation);
+        }
+        assert_eq!((counts.coupling_exceeded, counts.total()), (1, 1));
+
+        // Nothing is checked unless enabled
+        let off = MonitorConfig::default();
+        assert!(check_pressure(2.0, 3.0, &off).is_none());
+        assert!(check_coupling(&samples, &off).is_empty());
+    }
+}
@@ -0,0 +1,310 @@
+//! Signal normalization: put every axis on a comparable scale before weighting.
+//!
+//! Sensors report signals in their own units (ratios, counts, variances), so
+//! raw axis weights say little about how much each axis matters. Each
+//! `PressureAxisConfig` picks a `Normalization` mode, and one shared
+//! `SignalNormalizer` applies it wherever signals become pressure: region
+//! measurement (which also updates the running statistics) and
+//! `measure_pressure_inline_normalized` validation (which only reads them).
+//!
+//! Every mode yields a pressure of at least 0. The running modes (`MinMax`,
+//! `ZScore`, `Rank`) rate a value against the history seen so far, so a value
+//! at or past the best seen has pressure 0 however much further it improves.
+//! They suit activation (which regions to work on); a patch's full
+//! improvement is only visible to `Artifact::evaluate_patch`.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::PressureAxisConfig;
+
+/// Observations kept per axis for `Normalization::Rank`.
+const RANK_WINDOW: usize = 1024;
+
+/// How an axis's signal is rescaled before its weight is applied.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum Normalization {
+    /// Use the raw signal
+    #[default]
+    None,
+    /// Map `[min, max]` onto `[0, 1]`, clamping values outside it
+    Fixed {
+        /// Signal value mapped to 0.0
+        min: f64,
+        /// Signal value mapped to 1.0
+        max: f64,
+    },
+    /// Map the smallest and largest values seen so far onto `[0, 1]`
+    MinMax,
+    /// Standard score against the running mean and standard deviation,
+    /// floored at 0 (values at or below the mean have no pressure)
+    ZScore,
+    /// Fraction of recent observations below the value (`[0, 1]`)
+    Rank,
+}
+
+/// Running statistics of one axis's raw signal.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NormalizationStats {
+    /// Observations so far
+    pub count: usize,
+    /// Smallest value seen
+    pub min: f64,
+    /// Largest value seen
+    pub max: f64,
+    /// Running mean
+    pub mean: f64,
+    /// Running standard deviation (population)
+    pub std_dev: f64,
+}
+
+/// Statistics for one axis plus the recent values used for ranking.
+#[derive(Debug, Default)]
+struct AxisState {
+    count: usize,
+    min: f64,
+    max: f64,
+    mean: f64,
+    /// Sum of squared deviations from the mean (Welford)
+    m2: f64,
+    recent: VecDeque<f64>,
+}
+
+impl AxisState {
+    fn observe(&mut self, value: f64, keep_recent: bool) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+
+        if keep_recent {
+            if self.recent.len() == RANK_WINDOW {
+                self.recent.pop_front();
+            }
+            self.recent.push_back(value);
+        }
+    }
+
+    fn std_dev(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            (self.m2 / self.count as f64).sqrt()
+        }
+    }
+
+    fn stats(&self) -> NormalizationStats {
+        NormalizationStats {
+            count: self.count,
+            min: self.min,
+            max: self.max,
+            mean: self.mean,
+            std_dev: self.std_dev(),
+        }
+    }
+}
+
+/// Applies each axis's normalization, sharing statistics across regions.
+#[derive(Debug, Default)]
+pub struct SignalNormalizer {
+    /// Mode and running statistics per normalized axis, by axis name
+    axes: HashMap<String, (Normalization, Mutex<AxisState>)>,
+}
+
+impl SignalNormalizer {
+    /// Create a normalizer for the axes that have a normalization mode.
+    pub fn new(axes: &[PressureAxisConfig]) -> Self {
+        let axes = axes
+            .iter()
+            .filter(|a| a.normalization != Normalization::None)
+            .map(|a| {
+                (
+                    a.name.clone(),
+                    (a.normalization, Mutex::new(AxisState::default())),
+                )
+            })
+            .collect();
+        Self { axes }
+    }
+
+    /// Record a raw measurement of `axis`, then normalize it.
+    pub fn observe(&self, axis: &PressureAxisConfig, value: f64) -> f64 {
+        if let Some((mode, state)) = self.axes.get(&axis.name)
+            && let Ok(mut state) = state.lock()
+        {
+            state.observe(value, *mode == Normalization::Rank);
+        }
+        self.normalize(axis, value)
+    }
+
+    /// Normalize a raw value of `axis` without recording it.
+    ///
+    /// Running modes return 0.0 until the axis has been observed.
+    pub fn normalize(&self, axis: &PressureAxisConfig, value: f64) -> f64 {
+        let Some((mode, state)) = self.axes.get(&axis.name) else {
+            return value;
+        };
+        if let Normalization::Fixed { min, max } = *mode {
+            return scale(value, min, max);
+        }
+        let Ok(state) = state.lock() else {
+            return value;
+        };
+        if state.count == 0 {
+            return 0.0;
+        }
+        match mode {
+            Normalization::MinMax => scale(value, state.min, state.max),
+            Normalization::ZScore => {
+                let std_dev = state.std_dev();
+                if std_dev > 0.0 {
+                    ((value - state.mean) / std_dev).max(0.0)
+                } else {
+                    0.0
+                }
+            }
+            Normalization::Rank => {
+                let below = state.recent.iter().filter(|v| **v < value).count();
+                below as f64 / state.recent.len() as f64
+            }
+            Normalization::None | Normalization::Fixed { .. } => value,
+        }
+    }
+
+    /// Current statistics of every normalized axis, by axis name.
+    pub fn stats(&self) -> HashMap<String, NormalizationStats> {
+        self.axes
+            .iter()
+            .filter_map(|(name, (_, state))| {
+                state.lock().ok().map(|state| (name.clone(), state.stats()))
+            })
+            .collect()
+    }
+}
+
+/// Map `[min, max]` onto `[0, 1]`, clamped (0.0 for an empty range).
+fn scale(value: f64, min: f64, max: f64) -> f64 {
+    if max > min {
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pressure::measure_pressure_inline_normalized;
+    use crate::toy::{ToySensor, toy_axis};
+
+    fn axis(normalization: Normalization) -> PressureAxisConfig {
+        PressureAxisConfig {
+            name: "overlaps".to_string(),
+            weight: 1.0,
+            expr: "overlap_count".to_string(),
+            kind_weights: Default::default(),
+            hard: false,
+            priority: 0,
+            normalization,
+        }
+    }
+
+    #[test]
+    fn test_fixed_and_none() {
+        let none = axis(Normalization::None);
+        let fixed = axis(Normalization::Fixed {
+            min: 0.0,
+            max: 10.0,
+        });
+        assert_eq!(
+            SignalNormalizer::new(std::slice::from_ref(&none)).observe(&none, 7.0),
+            7.0
+        );
+
+        let normalizer = SignalNormalizer::new(std::slice::from_ref(&fixed));
+        assert_eq!(normalizer.normalize(&fixed, 5.0), 0.5);
+        assert_eq!(normalizer.normalize(&fixed, 20.0), 1.0);
+        assert!(normalizer.stats()["overlaps"].count == 0);
+    }
+
+    #[test]
+    fn test_running_modes() {
+        let min_max = axis(Normalization::MinMax);
+        let normalizer = SignalNormalizer::new(std::slice::from_ref(&min_max));
+        assert_eq!(normalizer.normalize(&min_max, 3.0), 0.0);
+        for value in [2.0, 4.0, 6.0] {
+            normalizer.observe(&min_max, value);
+        }
+        assert_eq!(normalizer.normalize(&min_max, 5.0), 0.75);
+
+        let stats = &normalizer.stats()["overlaps"];
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.mean, 4.0);
+
+        let z_score = axis(Normalization::ZScore);
+        let normalizer = SignalNormalizer::new(std::slice::from_ref(&z_score));
+        for value in [2.0, 4.0, 6.0] {
+            normalizer.observe(&z_score, value);
+        }
+        assert_eq!(normalizer.normalize(&z_score, 4.0), 0.0);
+        assert_eq!(normalizer.normalize(&z_score, 2.0), 0.0);
+        assert!(normalizer.normalize(&z_score, 6.0) > 1.0);
+
+        let rank = axis(Normalization::Rank);
+        let normalizer = SignalNormalizer::new(std::slice::from_ref(&rank));
+        for value in [1.0, 2.0, 3.0, 4.0] {
+            normalizer.observe(&rank, value);
+        }
+        assert_eq!(normalizer.normalize(&rank, 3.0), 0.5);
+    }
+
+    #[test]
+    fn test_validation_reads_region_statistics() {
+        let modes = [
+            Normalization::MinMax,
+            Normalization::ZScore,
+            Normalization::Rank,
+        ];
+        for normalization in modes {
+            let axis = PressureAxisConfig {
+                normalization,
+                ..toy_axis()
+            };
+            let normalizer = SignalNormalizer::new(std::slice::from_ref(&axis));
+            let inline = |content: &str| {
+                measure_pressure_inline_normalized(
+                    content,
+                    "toy",
+                    &ToySensor,
+                    std::slice::from_ref(&axis),
+                    &normalizer,
+                )
+                .unwrap()
+            };
+
+            // Nothing observed yet: validation sees no pressure
+            assert_eq!(inline("5"), 0.0);
+
+            // Region measurements feed the statistics validation reads
+            for value in [2.0, 4.0, 6.0, 8.0] {
+                normalizer.observe(&axis, value);
+            }
+            assert_eq!(inline("6"), normalizer.normalize(&axis, 6.0));
+            assert!(inline("8") > inline("4"), "{normalization:?}");
+
+            // Past the best seen there is no pressure left, never less
+            assert_eq!(inline("1"), 0.0, "{normalization:?}");
+            assert_eq!(inline("0"), 0.0, "{normalization:?}");
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Pareto archive: non-dominated artifact states across pressure axes.
+//!
+//! Each tick the coordinator sums region pressures per axis. In
+//! `AcceptanceMode::Pareto` it offers that vector, with a snapshot of the
+//! artifact's source, to a `ParetoArchive`. The archive keeps only states no
+//! other archived state dominates (lower or equal on every axis and lower on
+//! at least one), so the caller can pick its own trade-off at the end.
+
+use serde::{Deserialize, Serialize};
+
+use crate::pressure::PressureVector;
+
+/// Changes smaller than this are treated as no change.
+const EPSILON: f64 = 1e-9;
+
+/// One archived artifact state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParetoSnapshot {
+    /// Tick the state was measured on
+    pub tick: usize,
+    /// Total pressure per axis
+    pub axis_pressures: PressureVector,
+    /// Artifact source at that tick (if the artifact supports `source()`)
+    pub source: Option<String>,
+}
+
+/// Whether `a` dominates `b`: no axis higher and at least one lower.
+///
+/// Axes missing from a vector count as 0.0.
+pub fn dominates(a: &PressureVector, b: &PressureVector) -> bool {
+    let axes = a.keys().chain(b.keys());
+    let mut strictly_better = false;
+    for axis in axes {
+        let pa = a.get(axis).copied().unwrap_or(0.0);
+        let pb = b.get(axis).copied().unwrap_or(0.0);
+        if pa > pb + EPSILON {
+            return false;
+        }
+        if pa < pb - EPSILON {
+            strictly_better = true;
+        }
+    }
+    strictly_better
+}
+
+/// Set of mutually non-dominated snapshots.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParetoArchive {
+    snapshots: Vec<ParetoSnapshot>,
+}
+
+impl ParetoArchive {
+    /// Create an empty archive.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether a state with these pressures would enter the archive.
+    ///
+    /// False if an archived state dominates it or has the same pressures.
+    pub fn admits(&self, axis_pressures: &PressureVector) -> bool {
+        !self.snapshots.iter().any(|s| {
+            dominates(&s.axis_pressures, axis_pressures)
+                || !has_difference(axis_pressures, &s.axis_pressures)
+        })
+    }
+
+    /// Add a snapshot if it is admitted, dropping the snapshots it dominates.
+    ///
+    /// Returns whether the snapshot was added.
+    pub fn offer(&mut self, snapshot: ParetoSnapshot) -> bool {
+        if !self.admits(&snapshot.axis_pressures) {
+            return false;
+        }
+        self.snapshots
+            .retain(|s| !dominates(&snapshot.axis_pressures, &s.axis_pressures));
+        self.snapshots.push(snapshot);
+        true
+    }
+
+    /// Archived snapshots, oldest first.
+    pub fn snapshots(&self) -> &[ParetoSnapshot] {
+        &self.snapshots
+    }
+
+    /// Take the archived snapshots, oldest first.
+    pub fn into_snapshots(self) -> Vec<ParetoSnapshot> {
+        self.snapshots
+    }
+}
+
+/// Whether any axis differs between `a` and `b`.
+fn has_difference(a: &PressureVector, b: &PressureVector) -> bool {
+    a.keys().chain(b.keys()).any(|axis| {
+        let pa = a.get(axis).copied().unwrap_or(0.0);
+        let pb = b.get(axis).copied().unwrap_or(0.0);
+        (pa - pb).abs() > EPSILON
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(tick: usize, gaps: f64, overlaps: f64) -> ParetoSnapshot {
+        ParetoSnapshot {
+            tick,
+            axis_pressures: [
+                ("gaps".to_string(), gaps),
+                ("overlaps".to_string(), overlaps),
+            ]
+            .into_iter()
+            .collect(),
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_dominance() {
+        let a = snapshot(0, 1.0, 2.0).axis_pressures;
+        let b = snapshot(0, 1.0, 3.0).axis_pressures;
+        let c = snapshot(0, 0.5, 4.0).axis_pressures;
+        assert!(dominates(&a, &b));
+        assert!(!dominates(&b, &a));
+        assert!(!dominates(&a, &c));
+        assert!(!dominates(&c, &a));
+        assert!(!dominates(&a, &a));
+    }
+
+    #[test]
+    fn test_archive_keeps_only_non_dominated() {
+        let mut archive = ParetoArchive::new();
+        assert!(archive.offer(snapshot(1, 4.0, 4.0)));
+        // Trades gaps for overlaps: both kept
+        assert!(archive.offer(snapshot(2, 2.0, 5.0)));
+        // Dominated by tick 1, and a duplicate of tick 2
+        assert!(!archive.offer(snapshot(3, 5.0, 5.0)));
+        assert!(!archive.offer(snapshot(4, 2.0, 5.0)));
+        // Dominates both
+        assert!(archive.offer(snapshot(5, 1.0, 3.0)));
+
+        let ticks: Vec<usize> = archive.snapshots().iter().map(|s| s.tick).collect();
+        assert_eq!(ticks, vec![5]);
+    }
+}
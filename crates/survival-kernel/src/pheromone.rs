@@ -0,0 +1,229 @@
+//! Pheromones: traces accepted and rejected patches leave on their regions.
+//!
+//! With `PheromoneConfig::enabled` the coordinator deposits a `Pheromone`
+//! for every patch result: `Accepted` for patches that were applied (a trail
+//! to follow), `Rejected` for patches that were not (a trail to avoid). Every
+//! tick the store evaporates, evicting deposits whose weight falls below the
+//! threshold. Each `ProposeForRegion` carries the region's heaviest deposits
+//! of both kinds; when a patch is then accepted there, the accepted deposits
+//! the proposer was shown are reinforced.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::PheromoneConfig;
+use crate::region::RegionId;
+
+/// Which trail a deposit marks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PheromoneKind {
+    /// Left by a patch that was applied
+    Accepted,
+    /// Left by a patch that was rejected
+    Rejected,
+}
+
+/// One deposit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pheromone {
+    /// Identifies the deposit for `PheromoneStore::reinforce`
+    pub id: u64,
+    /// Region the patch targeted
+    pub region: RegionId,
+    /// Whether the patch was accepted or rejected
+    pub kind: PheromoneKind,
+    /// Content the patch proposed
+    pub content: String,
+    /// Measured pressure improvement of the patch (positive = better)
+    pub pressure_delta: f64,
+    /// Tick the deposit was made on
+    pub tick: usize,
+    /// Current weight (starts at 1.0, evaporates, grows when reinforced)
+    pub weight: f64,
+    /// Times the deposit has been reinforced
+    pub reinforcements: u32,
+}
+
+/// All deposits, keyed by region and kind.
+#[derive(Debug, Clone, Default)]
+pub struct PheromoneStore {
+    /// Deposits of each region and kind, oldest first
+    entries: HashMap<(RegionId, PheromoneKind), Vec<Pheromone>>,
+    next_id: u64,
+}
+
+impl PheromoneStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Leave a deposit with weight 1.0; returns its id.
+    ///
+    /// Over `config.max_per_region` deposits of this region and kind, the
+    /// lightest (oldest on ties) is evicted.
+    pub fn deposit(
+        &mut self,
+        region: RegionId,
+        kind: PheromoneKind,
+        content: String,
+        pressure_delta: f64,
+        tick: usize,
+        config: &PheromoneConfig,
+    ) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        let trail = self.entries.entry((region.clone(), kind)).or_default();
+        trail.push(Pheromone {
+            id,
+            region,
+            kind,
+            content,
+            pressure_delta,
+            tick,
+            weight: 1.0,
+            reinforcements: 0,
+        });
+
+        if trail.len() > config.max_per_region {
+            let lightest = trail
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| a.weight.total_cmp(&b.weight))
+                .map(|(i, _)| i);
+            if let Some(i) = lightest {
+                trail.remove(i);
+            }
+        }
+        id
+    }
+
+    /// Decay every deposit by `config.decay_factor` and evict the faint ones.
+    pub fn evaporate(&mut self, config: &PheromoneConfig) {
+        for trail in self.entries.values_mut() {
+            for pheromone in trail.iter_mut() {
+                pheromone.weight *= config.decay_factor;
+            }
+            trail.retain(|p| p.weight >= config.eviction_threshold);
+        }
+        self.entries.retain(|_, trail| !trail.is_empty());
+    }
+
+    /// Strengthen a deposit; false if it has evaporated.
+    pub fn reinforce(&mut self, id: u64, config: &PheromoneConfig) -> bool {
+        let Some(pheromone) = self
+            .entries
+            .values_mut()
+            .flat_map(|trail| trail.iter_mut())
+            .find(|p| p.id == id)
+        else {
+            return false;
+        };
+        pheromone.weight = (pheromone.weight + config.reinforcement).min(config.max_weight);
+        pheromone.reinforcements += 1;
+        true
+    }
+
+    /// Up to `max` deposits of this region and kind, heaviest first.
+    pub fn query(&self, region: &RegionId, kind: PheromoneKind, max: usize) -> Vec<Pheromone> {
+        let mut matches = self
+            .entries
+            .get(&(region.clone(), kind))
+            .cloned()
+            .unwrap_or_default();
+        matches.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+        matches.truncate(max);
+        matches
+    }
+
+    /// Deposits currently held.
+    pub fn len(&self) -> usize {
+        self.entries.values().map(Vec::len).sum()
+    }
+
+    /// Whether the store holds no deposits.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mti::prelude::*;
+
+    #[test]
+    fn test_deposit_evaporate_reinforce_query() {
+        let config = PheromoneConfig {
+            enabled: true,
+            decay_factor: 0.5,
+            eviction_threshold: 0.15,
+            max_per_region: 2,
+            ..PheromoneConfig::default()
+        };
+        let r1 = "region".create_type_id::<V7>();
+        let r2 = "region".create_type_id::<V7>();
+        let mut store = PheromoneStore::new();
+
+        let good = store.deposit(
+            r1.clone(),
+            PheromoneKind::Accepted,
+            "a".into(),
+            1.0,
+            1,
+            &config,
+        );
+        store.deposit(
+            r1.clone(),
+            PheromoneKind::Rejected,
+            "b".into(),
+            -1.0,
+            1,
+            &config,
+        );
+        store.deposit(
+            r2.clone(),
+            PheromoneKind::Accepted,
+            "c".into(),
+            0.5,
+            1,
+            &config,
+        );
+        assert!(store.reinforce(good, &config));
+
+        // Over capacity the lightest goes, oldest first: d, not the reinforced a
+        store.deposit(
+            r1.clone(),
+            PheromoneKind::Accepted,
+            "d".into(),
+            0.2,
+            2,
+            &config,
+        );
+        store.deposit(
+            r1.clone(),
+            PheromoneKind::Accepted,
+            "e".into(),
+            0.3,
+            2,
+            &config,
+        );
+        let accepted = store.query(&r1, PheromoneKind::Accepted, 5);
+        let contents: Vec<_> = accepted.iter().map(|p| p.content.as_str()).collect();
+        assert_eq!(contents, vec!["a", "e"]);
+        assert_eq!(accepted[0].weight, 1.3);
+        assert_eq!(accepted[0].reinforcements, 1);
+
+        // Only the reinforced deposit outlasts three halvings (1.3 → 0.1625)
+        for _ in 0..3 {
+            store.evaporate(&config);
+        }
+        assert_eq!(store.query(&r1, PheromoneKind::Accepted, 5).len(), 1);
+        assert!(store.query(&r1, PheromoneKind::Rejected, 5).is_empty());
+        store.evaporate(&config);
+        assert!(store.is_empty());
+        assert!(!store.reinforce(good, &config));
+    }
+}
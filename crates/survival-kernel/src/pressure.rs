@@ -9,7 +9,8 @@
 
 use std::collections::HashMap;
 
-use crate::region::RegionView;
+use crate::normalize::SignalNormalizer;
+use crate::region::{NeighborView, RegionView};
 
 /// Signals are measurable features computed from a region.
 /// These form the "state surface" that agents observe.
@@ -42,6 +43,28 @@ pub trait Sensor: Send + Sync {
     /// This is synchronous - sensors should compute signals from the region data
     /// without making external calls.
     fn measure(&self, region: &RegionView) -> anyhow::Result<Signals>;
+
+    /// Measure signals for a region given its neighbors (see `Artifact::neighbors`).
+    ///
+    /// Override this to read the neighborhood instead of global state. Regions
+    /// measuring themselves in async mode pass no neighbors.
+    ///
+    /// Default implementation ignores the neighbors.
+    fn measure_with_neighbors(
+        &self,
+        region: &RegionView,
+        _neighbors: &[NeighborView],
+    ) -> anyhow::Result<Signals> {
+        self.measure(region)
+    }
+
+    /// Whether signals depend on state outside the region (e.g. a global count).
+    ///
+    /// Global sensors are never answered from the content-hash cache, and
+    /// clean regions only see their updated values on periodic full re-measures.
+    fn is_global(&self) -> bool {
+        false
+    }
 }
 
 /// A pressure function computes "badness" from signals.
@@ -79,6 +102,32 @@ pub fn measure_pressure_inline(
     kind: &str,
     sensor: &dyn Sensor,
     pressure_axes: &[crate::config::PressureAxisConfig],
+) -> anyhow::Result<f64> {
+    weighted_inline_pressure(content, kind, sensor, pressure_axes, |_, value| value)
+}
+
+/// Like [`measure_pressure_inline`], but normalizes each signal first.
+///
+/// `normalizer` should be the one regions measure with; its statistics are
+/// read, not updated.
+pub fn measure_pressure_inline_normalized(
+    content: &str,
+    kind: &str,
+    sensor: &dyn Sensor,
+    pressure_axes: &[crate::config::PressureAxisConfig],
+    normalizer: &SignalNormalizer,
+) -> anyhow::Result<f64> {
+    weighted_inline_pressure(content, kind, sensor, pressure_axes, |axis, value| {
+        normalizer.normalize(axis, value)
+    })
+}
+
+fn weighted_inline_pressure(
+    content: &str,
+    kind: &str,
+    sensor: &dyn Sensor,
+    pressure_axes: &[crate::config::PressureAxisConfig],
+    normalize: impl Fn(&crate::config::PressureAxisConfig, f64) -> f64,
 ) -> anyhow::Result<f64> {
     use mti::prelude::*;
     use uuid::Uuid;
@@ -93,6 +142,8 @@ pub fn measure_pressure_inline(
         kind: kind.to_string(),
         content: content.to_string(),
         metadata: HashMap::new(),
+        parent: None,
+        children: Vec::new(),
     };
 
     let signals = sensor.measure(&view)?;
@@ -102,6 +153,7 @@ pub fn measure_pressure_inline(
         .map(|axis| {
             // Get the signal value for this axis (using expr as signal name)
             let signal_value = signals.get(&axis.expr).copied().unwrap_or(0.0);
+            let signal_value = normalize(axis, signal_value);
             // Apply kind-specific weight if present, otherwise use base weight
             let weight = axis.kind_weights.get(kind).copied().unwrap_or(axis.weight);
             signal_value * weight
@@ -0,0 +1,73 @@
+//! Proposers: patch generation without writing an actor.
+//!
+//! A patch actor has to subscribe to `CoordinatorReady`, announce itself with
+//! `PatchActorReady`, answer every `ProposeForRegion` with a `PatchProposal`
+//! carrying the request's correlation ID (even when it has nothing to offer),
+//! and leave with `PatchActorGone`. A `Proposer` only maps a request to a
+//! `Proposal`; `AsyncKernelBuilder::add_proposer` (or `add_async_proposer`)
+//! wraps it in a `ProposerActor` that does the rest.
+//!
+//! Use `Proposer` for local computation (it runs on a blocking thread) and
+//! `AsyncProposer` when proposing means waiting on I/O, such as an LLM call.
+
+use futures::future::BoxFuture;
+
+use crate::messages::ProposeForRegion;
+use crate::region::Patch;
+
+/// What a proposer offers for one region.
+#[derive(Debug, Clone, Default)]
+pub struct Proposal {
+    /// Proposed patches with scores (higher = better)
+    pub patches: Vec<(f64, Patch)>,
+    /// Prompt tokens used (for metrics tracking)
+    pub prompt_tokens: u32,
+    /// Completion tokens generated (for metrics tracking)
+    pub completion_tokens: u32,
+    /// Model behind the proposal, if any (recorded in provenance)
+    pub model: Option<String>,
+    /// Sampling band of the proposal, if any (recorded in provenance)
+    pub sampling_band: Option<String>,
+}
+
+impl Proposal {
+    /// A proposal of the given patches with no token usage.
+    pub fn new(patches: Vec<(f64, Patch)>) -> Self {
+        Self {
+            patches,
+            ..Self::default()
+        }
+    }
+
+    /// Record token usage.
+    pub fn with_tokens(mut self, prompt_tokens: u32, completion_tokens: u32) -> Self {
+        self.prompt_tokens = prompt_tokens;
+        self.completion_tokens = completion_tokens;
+        self
+    }
+}
+
+/// Synchronous patch generation.
+///
+/// An error is logged and answered with an empty proposal.
+pub trait Proposer: Send + Sync {
+    /// Name reported with each proposal (keys reputation).
+    fn name(&self) -> &str;
+
+    /// Propose patches for the requested region.
+    fn propose(&self, request: &ProposeForRegion) -> anyhow::Result<Proposal>;
+}
+
+/// Asynchronous patch generation.
+///
+/// An error is logged and answered with an empty proposal.
+pub trait AsyncProposer: Send + Sync {
+    /// Name reported with each proposal (keys reputation).
+    fn name(&self) -> &str;
+
+    /// Propose patches for the requested region.
+    fn propose<'a>(
+        &'a self,
+        request: &'a ProposeForRegion,
+    ) -> BoxFuture<'a, anyhow::Result<Proposal>>;
+}
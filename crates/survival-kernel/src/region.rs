@@ -21,6 +21,11 @@ pub type RegionId = MagicTypeId;
 /// Regions are the smallest independently scorable units:
 /// - For text: paragraphs, sentences, or spans
 /// - For code: functions, modules, or AST nodes
+///
+/// Regions may nest (a day enclosing its time blocks). Every level is a region
+/// in its own right that can be measured and patched; the coordinator
+/// aggregates pressure upward and never applies patches to a region and one
+/// nested in it in the same round.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegionView {
     pub id: RegionId,
@@ -30,6 +35,34 @@ pub struct RegionView {
     pub content: String,
     /// Arbitrary metadata for sensors/actors
     pub metadata: HashMap<String, serde_json::Value>,
+    /// Enclosing coarser region, if regions are nested
+    #[serde(default)]
+    pub parent: Option<RegionId>,
+    /// Finer regions nested inside this one
+    #[serde(default)]
+    pub children: Vec<RegionId>,
+}
+
+impl RegionView {
+    /// Hash of the region's kind and content.
+    ///
+    /// Used to skip re-measuring identical content. Stable within a process only.
+    pub fn content_hash(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.kind.hash(&mut hasher);
+        self.content.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// A neighboring region as seen by a sensor, with its coupling weight.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeighborView {
+    /// The neighbor's current view
+    pub view: RegionView,
+    /// Strength of the neighbor relation (from `Artifact::neighbors`)
+    pub weight: f64,
 }
 
 /// A mutation that can be applied to a region.
@@ -44,7 +77,7 @@ pub struct Patch {
 }
 
 /// The operation to apply to a region.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", content = "value")]
 pub enum PatchOp {
     /// Replace the region's content entirely
@@ -71,8 +104,109 @@ pub struct RegionState {
     pub pressure_ema: HashMap<String, f64>,
     /// Inhibition window: suppress actions until this timestamp
     pub suppress_until_ms: Option<u64>,
-    /// Audit trail of applied patches
-    pub provenance: Vec<String>,
+    /// Audit trail of applied patches, oldest first
+    pub provenance: Vec<ProvenanceRecord>,
+    /// External steering from a human or supervisory process
+    #[serde(default)]
+    pub steering: Steering,
+}
+
+/// External steering of one region, set via `SteerRegions`.
+///
+/// A frozen region counts as inhibited and rejects every patch. A bias and
+/// focus multiplier reshape the pressure used for activation (measured
+/// pressure is unchanged) up to `until_tick`. Ticks are the region's own
+/// count: kernel ticks, or wakes in async mode.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Steering {
+    /// Never activate and reject all patches until unfrozen
+    pub frozen: bool,
+    /// Added to the region's pressure while biased
+    pub bias: f64,
+    /// Multiplies the region's pressure while biased (before the bias)
+    pub focus: f64,
+    /// Last tick the bias and focus apply on (None = no bias)
+    pub until_tick: Option<usize>,
+    /// Why the region is steered (e.g. "finish Friday first")
+    pub reason: Option<String>,
+}
+
+impl Default for Steering {
+    fn default() -> Self {
+        Self {
+            frozen: false,
+            bias: 0.0,
+            focus: 1.0,
+            until_tick: None,
+            reason: None,
+        }
+    }
+}
+
+impl Steering {
+    /// Whether the bias and focus apply on `tick`.
+    pub fn is_biased(&self, tick: usize) -> bool {
+        self.until_tick.is_some_and(|until| tick <= until)
+    }
+
+    /// Whether the region is frozen or biased on `tick`.
+    pub fn is_active(&self, tick: usize) -> bool {
+        self.frozen || self.is_biased(tick)
+    }
+
+    /// Pressure used for activation on `tick`, given the measured pressure.
+    pub fn steer(&self, pressure: f64, tick: usize) -> f64 {
+        if self.is_biased(tick) {
+            pressure * self.focus + self.bias
+        } else {
+            pressure
+        }
+    }
+}
+
+/// Audit record for one patch applied to a region.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProvenanceRecord {
+    /// Tick on which the patch was applied
+    pub tick: usize,
+    /// Timestamp when the patch was applied
+    pub timestamp_ms: u64,
+    /// Name of the actor that proposed the patch
+    pub actor_name: String,
+    /// Model behind the proposing actor, if it reported one
+    pub model: Option<String>,
+    /// Sampling band of the proposing actor, if it reported one
+    pub sampling_band: Option<String>,
+    /// The patch's rationale
+    pub rationale: String,
+    /// Rewrites made by patch transformers before evaluation
+    #[serde(default)]
+    pub transforms: Vec<String>,
+    /// Improvement the patch predicted (weighted sum over axes)
+    pub expected_delta: f64,
+    /// Improvement measured at validation (positive = better)
+    pub actual_delta: f64,
+    /// `RegionView::content_hash` before the patch
+    pub hash_before: u64,
+    /// `RegionView::content_hash` after the patch
+    pub hash_after: u64,
+    /// Steering active on the region when the patch was applied
+    #[serde(default)]
+    pub steering: Option<Steering>,
+}
+
+/// Who proposed a patch, carried from proposal to application for provenance.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PatchOrigin {
+    /// Name of the actor that proposed the patch
+    pub actor_name: String,
+    /// Model behind the actor, if known
+    pub model: Option<String>,
+    /// Sampling band of the actor, if known
+    pub sampling_band: Option<String>,
+    /// Rewrites made by patch transformers (see `crate::transform`)
+    #[serde(default)]
+    pub transforms: Vec<String>,
 }
 
 impl RegionState {
@@ -85,12 +219,72 @@ impl RegionState {
             pressure_ema: HashMap::new(),
             suppress_until_ms: None,
             provenance: Vec::new(),
+            steering: Steering::default(),
         }
     }
 
-    /// Check if this region is currently inhibited.
+    /// Check if this region is currently inhibited (or frozen).
     pub fn is_inhibited(&self, now_ms: u64) -> bool {
-        self.suppress_until_ms.is_some_and(|until| now_ms < until)
+        self.steering.frozen || self.suppress_until_ms.is_some_and(|until| now_ms < until)
+    }
+
+    /// Append a provenance record, dropping the oldest beyond `limit` (0 = unbounded).
+    pub fn record_provenance(&mut self, record: ProvenanceRecord, limit: usize) {
+        self.provenance.push(record);
+        if limit > 0 && self.provenance.len() > limit {
+            let excess = self.provenance.len() - limit;
+            self.provenance.drain(..excess);
+        }
+    }
+
+    /// Combine the states of regions merged into one.
+    ///
+    /// Fitness and pressure EMAs are averaged, confidence takes the minimum,
+    /// inhibition the latest window, and provenance is interleaved by tick.
+    /// Steering is taken from the first steered region.
+    pub fn merged(states: &[RegionState]) -> Self {
+        let Some(first) = states.first() else {
+            return Self::default();
+        };
+        if states.len() == 1 {
+            return first.clone();
+        }
+
+        let count = states.len() as f64;
+        let mut pressure_ema: HashMap<String, (f64, usize)> = HashMap::new();
+        for state in states {
+            for (axis, value) in &state.pressure_ema {
+                let entry = pressure_ema.entry(axis.clone()).or_default();
+                entry.0 += value;
+                entry.1 += 1;
+            }
+        }
+        let mut provenance: Vec<ProvenanceRecord> = states
+            .iter()
+            .flat_map(|s| s.provenance.iter().cloned())
+            .collect();
+        provenance.sort_by_key(|r| (r.tick, r.timestamp_ms));
+
+        Self {
+            last_updated_ms: states.iter().map(|s| s.last_updated_ms).max().unwrap_or(0),
+            fitness: states.iter().map(|s| s.fitness).sum::<f64>() / count,
+            confidence: states
+                .iter()
+                .map(|s| s.confidence)
+                .fold(f64::INFINITY, f64::min),
+            pressure_ema: pressure_ema
+                .into_iter()
+                .map(|(axis, (sum, n))| (axis, sum / n as f64))
+                .collect(),
+            suppress_until_ms: states.iter().filter_map(|s| s.suppress_until_ms).max(),
+            provenance,
+            steering: states
+                .iter()
+                .map(|s| &s.steering)
+                .find(|s| s.frozen || s.until_tick.is_some())
+                .cloned()
+                .unwrap_or_default(),
+        }
     }
 }
 
@@ -114,7 +308,7 @@ pub struct AtomicRegionState {
     /// Inhibition timestamp (0 = not inhibited)
     suppress_until_ms: AtomicU64,
     /// Audit trail of applied patches (requires lock for modification)
-    pub provenance: RwLock<Vec<String>>,
+    pub provenance: RwLock<Vec<ProvenanceRecord>>,
 }
 
 impl AtomicRegionState {
@@ -235,6 +429,7 @@ impl AtomicRegionState {
                 None
             },
             provenance: self.provenance.read().clone(),
+            steering: Steering::default(),
         }
     }
 
@@ -298,3 +493,91 @@ impl Clone for AtomicRegionState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(tick: usize) -> ProvenanceRecord {
+        ProvenanceRecord {
+            tick,
+            timestamp_ms: tick as u64 * 1000,
+            actor_name: "actor".to_string(),
+            model: Some("model".to_string()),
+            sampling_band: None,
+            rationale: format!("patch {tick}"),
+            transforms: Vec::new(),
+            expected_delta: 1.0,
+            actual_delta: 0.5,
+            hash_before: tick as u64,
+            hash_after: tick as u64 + 1,
+            steering: None,
+        }
+    }
+
+    #[test]
+    fn test_provenance_history_is_bounded() {
+        let mut state = RegionState::new(0);
+        for tick in 0..5 {
+            state.record_provenance(record(tick), 3);
+        }
+
+        let ticks: Vec<usize> = state.provenance.iter().map(|r| r.tick).collect();
+        assert_eq!(ticks, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_provenance_history_unbounded_with_zero_limit() {
+        let mut state = RegionState::new(0);
+        for tick in 0..5 {
+            state.record_provenance(record(tick), 0);
+        }
+        assert_eq!(state.provenance.len(), 5);
+    }
+
+    #[test]
+    fn test_merged_state_combines_regions() {
+        let mut a = RegionState::new(100);
+        a.fitness = 0.8;
+        a.confidence = 0.9;
+        a.pressure_ema.insert("conflicts".to_string(), 2.0);
+        a.suppress_until_ms = Some(500);
+        a.record_provenance(record(3), 0);
+
+        let mut b = RegionState::new(200);
+        b.fitness = 0.4;
+        b.confidence = 0.3;
+        b.pressure_ema.insert("conflicts".to_string(), 4.0);
+        b.record_provenance(record(1), 0);
+
+        let merged = RegionState::merged(&[a, b]);
+        assert_eq!(merged.last_updated_ms, 200);
+        assert!((merged.fitness - 0.6).abs() < 1e-9);
+        assert_eq!(merged.confidence, 0.3);
+        assert_eq!(merged.pressure_ema["conflicts"], 3.0);
+        assert_eq!(merged.suppress_until_ms, Some(500));
+        let ticks: Vec<usize> = merged.provenance.iter().map(|r| r.tick).collect();
+        assert_eq!(ticks, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_steering_biases_until_tick_and_freezes() {
+        let mut state = RegionState::new(0);
+        assert_eq!(state.steering.steer(2.0, 1), 2.0);
+        assert!(!state.steering.is_active(1));
+
+        state.steering.bias = 1.0;
+        state.steering.focus = 3.0;
+        state.steering.until_tick = Some(4);
+        assert_eq!(state.steering.steer(2.0, 4), 7.0);
+        assert_eq!(state.steering.steer(2.0, 5), 2.0);
+
+        assert!(!state.is_inhibited(0));
+        state.steering.frozen = true;
+        assert!(state.is_inhibited(0));
+        assert!(state.steering.is_active(5));
+
+        let merged = RegionState::merged(&[RegionState::new(0), state.clone()]);
+        assert_eq!(merged.steering, state.steering);
+    }
+}
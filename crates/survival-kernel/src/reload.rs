@@ -0,0 +1,223 @@
+//! Hot config reload: swap `KernelConfig` during a run.
+//!
+//! An `UpdateConfig` message carries a complete replacement config. The
+//! coordinator checks it against the running one with `check_update`, which
+//! rejects settings fixed when the kernel's actors were spawned and configs
+//! that are invalid outright. An accepted update takes effect on the next
+//! tick: new pressure axes are pushed to every RegionActor, and the change is
+//! reported as a `ConfigChange` in that tick's `TickResult`.
+
+use std::collections::HashSet;
+
+use crate::config::{ExecutionMode, KernelConfig};
+use crate::normalize::Normalization;
+
+/// One accepted config update, reported in the next `TickResult`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChange {
+    /// Last tick completed under the previous config
+    pub tick: usize,
+    /// Top-level sections that changed (see `changed_sections`)
+    pub changed: Vec<String>,
+    /// The config now in effect
+    pub config: KernelConfig,
+}
+
+/// Why a config update was rejected.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ConfigError {
+    /// The execution mode is fixed for the whole run
+    #[error("execution mode cannot change during a run")]
+    ModeChanged,
+    /// Regions woken in async mode keep the wake settings they were given
+    #[error("`{0}` cannot change once async regions are awake")]
+    WakeSettingChanged(&'static str),
+    /// Mailbox capacity and provenance limits are set when actors spawn
+    #[error("`{0}` is fixed when actors are spawned")]
+    SpawnSettingChanged(&'static str),
+    /// Running normalization statistics belong to the original mode
+    #[error("normalization of axis `{0}` cannot change during a run")]
+    NormalizationChanged(String),
+    /// Two axes share a name
+    #[error("duplicate pressure axis `{0}`")]
+    DuplicateAxis(String),
+    /// A weight is negative or not finite
+    #[error("axis `{axis}` has invalid weight {weight}")]
+    InvalidWeight {
+        /// Axis with the bad weight
+        axis: String,
+        /// The weight (base or per-kind)
+        weight: f64,
+    },
+}
+
+/// Names of the top-level `KernelConfig` sections that differ.
+pub fn changed_sections(old: &KernelConfig, new: &KernelConfig) -> Vec<String> {
+    let sections = [
+        (
+            "tick_interval_ms",
+            old.tick_interval_ms != new.tick_interval_ms,
+        ),
+        ("max_ticks", old.max_ticks != new.max_ticks),
+        (
+            "stable_threshold",
+            old.stable_threshold != new.stable_threshold,
+        ),
+        ("mode", old.mode != new.mode),
+        ("pressure_axes", old.pressure_axes != new.pressure_axes),
+        ("decay", old.decay != new.decay),
+        ("activation", old.activation != new.activation),
+        ("selection", old.selection != new.selection),
+        ("membership", old.membership != new.membership),
+        ("measurement", old.measurement != new.measurement),
+        ("messaging", old.messaging != new.messaging),
+        ("provenance", old.provenance != new.provenance),
+        ("reputation", old.reputation != new.reputation),
+        ("diffusion", old.diffusion != new.diffusion),
+        ("granularity", old.granularity != new.granularity),
+        ("stagnation", old.stagnation != new.stagnation),
+        ("monitor", old.monitor != new.monitor),
+        ("pheromones", old.pheromones != new.pheromones),
+    ];
+    sections
+        .into_iter()
+        .filter(|(_, changed)| *changed)
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+/// Check that `new` can replace `old` in a running kernel.
+pub fn check_update(old: &KernelConfig, new: &KernelConfig) -> Result<(), ConfigError> {
+    if old.mode != new.mode {
+        return Err(ConfigError::ModeChanged);
+    }
+    if new.mode == ExecutionMode::Async {
+        // Carried by the RegionWake each region received when it woke
+        if old.tick_interval_ms != new.tick_interval_ms {
+            return Err(ConfigError::WakeSettingChanged("tick_interval_ms"));
+        }
+        if old.decay != new.decay {
+            return Err(ConfigError::WakeSettingChanged("decay"));
+        }
+        if old.activation.min_total_pressure != new.activation.min_total_pressure {
+            return Err(ConfigError::WakeSettingChanged(
+                "activation.min_total_pressure",
+            ));
+        }
+    }
+    if old.messaging.coordinator_inbox_capacity != new.messaging.coordinator_inbox_capacity {
+        return Err(ConfigError::SpawnSettingChanged(
+            "messaging.coordinator_inbox_capacity",
+        ));
+    }
+    if old.provenance != new.provenance {
+        return Err(ConfigError::SpawnSettingChanged("provenance"));
+    }
+
+    let mut names = HashSet::new();
+    for axis in &new.pressure_axes {
+        if !names.insert(axis.name.as_str()) {
+            return Err(ConfigError::DuplicateAxis(axis.name.clone()));
+        }
+        let weights = std::iter::once(axis.weight).chain(axis.kind_weights.values().copied());
+        for weight in weights {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(ConfigError::InvalidWeight {
+                    axis: axis.name.clone(),
+                    weight,
+                });
+            }
+        }
+        // The shared normalizer only tracks the axes it was created with
+        let previous = old
+            .pressure_axes
+            .iter()
+            .find(|a| a.name == axis.name)
+            .map_or(Normalization::None, |a| a.normalization);
+        if axis.normalization != previous {
+            return Err(ConfigError::NormalizationChanged(axis.name.clone()));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PressureAxisConfig;
+
+    fn axis(name: &str, weight: f64) -> PressureAxisConfig {
+        PressureAxisConfig {
+            name: name.to_string(),
+            weight,
+            expr: name.to_string(),
+            kind_weights: Default::default(),
+            hard: false,
+            priority: 0,
+            normalization: Normalization::None,
+        }
+    }
+
+    #[test]
+    fn test_safe_update_lists_changed_sections() {
+        let old = KernelConfig {
+            pressure_axes: vec![axis("gaps", 1.0)],
+            ..KernelConfig::default()
+        };
+        let mut new = old.clone();
+        new.activation.inhibit_ms = 60_000;
+        new.pressure_axes[0].weight = 2.0;
+        new.pressure_axes.push(axis("overlaps", 0.5));
+
+        assert_eq!(check_update(&old, &new), Ok(()));
+        assert_eq!(
+            changed_sections(&old, &new),
+            vec!["pressure_axes", "activation"]
+        );
+        assert!(changed_sections(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_unsafe_updates_are_rejected() {
+        let old = KernelConfig {
+            pressure_axes: vec![axis("gaps", 1.0)],
+            ..KernelConfig::default()
+        };
+
+        let mut new = old.clone();
+        new.mode = ExecutionMode::Async;
+        assert_eq!(check_update(&old, &new), Err(ConfigError::ModeChanged));
+
+        let mut new = old.clone();
+        new.pressure_axes.push(axis("gaps", 2.0));
+        assert_eq!(
+            check_update(&old, &new),
+            Err(ConfigError::DuplicateAxis("gaps".to_string()))
+        );
+
+        let mut new = old.clone();
+        new.pressure_axes[0].weight = f64::NAN;
+        assert!(matches!(
+            check_update(&old, &new),
+            Err(ConfigError::InvalidWeight { .. })
+        ));
+
+        let mut new = old.clone();
+        new.pressure_axes[0].normalization = Normalization::MinMax;
+        assert_eq!(
+            check_update(&old, &new),
+            Err(ConfigError::NormalizationChanged("gaps".to_string()))
+        );
+
+        let async_old = KernelConfig {
+            mode: ExecutionMode::Async,
+            ..old.clone()
+        };
+        let mut new = async_old.clone();
+        new.decay.fitness_half_life_ms = 0;
+        assert_eq!(
+            check_update(&async_old, &new),
+            Err(ConfigError::WakeSettingChanged("decay"))
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Patch actor reputation: how well each actor's proposals hold up.
+//!
+//! The coordinator compares every validated patch against what its proposer
+//! predicted. Actors whose patches are accepted and whose `expected_delta`
+//! does not overclaim earn a selection weight above 1.0; unreliable actors
+//! fall below it. Actors with no history have weight 1.0. Predictions are
+//! weighted by axis (see `weighted_expected_delta`) so they compare with the
+//! weighted pressure that was measured.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::PressureAxisConfig;
+
+/// Reputation of every patch actor, keyed by actor name.
+pub type ReputationTable = HashMap<String, ActorReputation>;
+
+/// Track record of one patch actor.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ActorReputation {
+    /// Patches validated (accepted or rejected)
+    pub attempts: usize,
+    /// Patches accepted and applied
+    pub accepted: usize,
+    /// Sum of measured improvement over accepted patches
+    pub realized_delta_sum: f64,
+    /// Sum of |expected - actual| improvement over all attempts
+    pub calibration_error_sum: f64,
+    /// Sum of max(expected - actual, 0) over all attempts
+    pub overestimate_sum: f64,
+}
+
+impl ActorReputation {
+    /// Record the validation outcome of one patch.
+    pub fn record(&mut self, accepted: bool, expected_delta: f64, actual_delta: f64) {
+        self.attempts += 1;
+        if accepted {
+            self.accepted += 1;
+            self.realized_delta_sum += actual_delta;
+        }
+        self.calibration_error_sum += (expected_delta - actual_delta).abs();
+        self.overestimate_sum += (expected_delta - actual_delta).max(0.0);
+    }
+
+    /// Fraction of attempts that were accepted (0.0 with no attempts).
+    pub fn acceptance_rate(&self) -> f64 {
+        ratio(self.accepted as f64, self.attempts)
+    }
+
+    /// Mean measured improvement of accepted patches.
+    pub fn mean_realized_delta(&self) -> f64 {
+        ratio(self.realized_delta_sum, self.accepted)
+    }
+
+    /// Mean absolute difference between predicted and measured improvement.
+    pub fn mean_calibration_error(&self) -> f64 {
+        ratio(self.calibration_error_sum, self.attempts)
+    }
+
+    /// Multiplier applied to this actor's proposal scores.
+    ///
+    /// Laplace-smoothed acceptance rate (so 1.0 with no history), scaled
+    /// down by how much the actor overclaims on average. Underclaiming is
+    /// not penalised.
+    pub fn weight(&self) -> f64 {
+        let acceptance = (self.accepted as f64 + 1.0) / (self.attempts as f64 + 2.0);
+        let overestimate = ratio(self.overestimate_sum, self.attempts);
+        2.0 * acceptance / (1.0 + overestimate)
+    }
+}
+
+fn ratio(sum: f64, count: usize) -> f64 {
+    if count == 0 { 0.0 } else { sum / count as f64 }
+}
+
+/// The improvement a patch predicts, weighted like measured pressure.
+///
+/// `expected` is keyed by axis name; each entry is scaled by that axis's
+/// weight for regions of `kind`. Entries naming no configured axis are
+/// ignored.
+pub fn weighted_expected_delta(
+    axes: &[PressureAxisConfig],
+    kind: &str,
+    expected: &HashMap<String, f64>,
+) -> f64 {
+    axes.iter()
+        .filter_map(|axis| {
+            let delta = expected.get(&axis.name)?;
+            let weight = axis.kind_weights.get(kind).copied().unwrap_or(axis.weight);
+            Some(delta * weight)
+        })
+        .sum()
+}
+
+/// Selection weight for `actor_name` (1.0 when unknown).
+pub fn weight_of(table: &ReputationTable, actor_name: &str) -> f64 {
+    table.get(actor_name).map_or(1.0, ActorReputation::weight)
+}
+
+/// Assign `count` requests to actors in proportion to `weights`.
+///
+/// Smooth weighted round-robin: deterministic, and equal weights reduce to
+/// plain round-robin. Returns one actor index per request.
+pub fn weighted_assignment(weights: &[f64], count: usize) -> Vec<usize> {
+    if weights.is_empty() {
+        return Vec::new();
+    }
+    let total: f64 = weights.iter().sum();
+    let mut current = vec![0.0; weights.len()];
+    (0..count)
+        .map(|_| {
+            for (c, w) in current.iter_mut().zip(weights) {
+                *c += w;
+            }
+            let chosen = current
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1).then(b.0.cmp(&a.0)))
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+            current[chosen] -= total;
+            chosen
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_actor_has_neutral_weight() {
+        assert_eq!(ActorReputation::default().weight(), 1.0);
+        assert_eq!(weight_of(&ReputationTable::new(), "nobody"), 1.0);
+    }
+
+    #[test]
+    fn test_reputation_tracks_outcomes() {
+        let mut rep = ActorReputation::default();
+        rep.record(true, 1.0, 2.0);
+        rep.record(false, 3.0, -1.0);
+
+        assert_eq!(rep.acceptance_rate(), 0.5);
+        assert_eq!(rep.mean_realized_delta(), 2.0);
+        assert_eq!(rep.mean_calibration_error(), 2.5);
+        // Smoothed acceptance 0.5, mean overestimate 2.0
+        assert!((rep.weight() - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reliable_actor_outweighs_unreliable() {
+        let mut good = ActorReputation::default();
+        let mut bad = ActorReputation::default();
+        for _ in 0..5 {
+            good.record(true, 1.0, 1.0);
+            bad.record(false, 1.0, 0.0);
+        }
+        assert!(good.weight() > 1.0);
+        assert!(bad.weight() < 1.0);
+    }
+
+    #[test]
+    fn test_expected_delta_is_weighted_by_axis() {
+        let axis = |name: &str, weight| PressureAxisConfig {
+            name: name.to_string(),
+            weight,
+            expr: format!("{name}_count"),
+            kind_weights: HashMap::from([("lunch".to_string(), 4.0)]),
+            hard: false,
+            priority: 0,
+            normalization: Default::default(),
+        };
+        let axes = [axis("overlap", 2.0), axis("unscheduled", 0.5)];
+        let expected = HashMap::from([
+            ("overlap".to_string(), 1.0),
+            ("unscheduled".to_string(), 2.0),
+            ("unknown".to_string(), 100.0),
+        ]);
+
+        assert_eq!(weighted_expected_delta(&axes, "block", &expected), 3.0);
+        assert_eq!(weighted_expected_delta(&axes, "lunch", &expected), 12.0);
+        assert_eq!(weighted_expected_delta(&[], "block", &expected), 0.0);
+    }
+
+    #[test]
+    fn test_weighted_assignment() {
+        assert_eq!(weighted_assignment(&[1.0, 1.0], 4), vec![0, 1, 0, 1]);
+
+        let assigned = weighted_assignment(&[3.0, 1.0], 8);
+        assert_eq!(assigned.iter().filter(|&&i| i == 0).count(), 6);
+        assert_eq!(assigned.iter().filter(|&&i| i == 1).count(), 2);
+    }
+}
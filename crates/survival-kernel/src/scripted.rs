@@ -0,0 +1,290 @@
+//! Scripted proposers: deterministic stand-ins for LLM patch actors.
+//!
+//! Each covers one behavior the kernel has to cope with: `ReplayProposer`
+//! hands out a fixed list of patches, `GarbageProposer` only proposes junk,
+//! `RandomProposer` flips a seeded coin between a good and a bad proposer,
+//! `SleepyProposer` answers late and `SilentProposer` never answers at all.
+//! Every one records the requests it was sent in a `RequestLog`, so a test
+//! can check what the coordinator asked for after handing the proposer to
+//! `AsyncKernelBuilder::add_proposer` (or `add_async_proposer`).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use acton_reactive::prelude::tokio;
+use futures::future::BoxFuture;
+use parking_lot::Mutex;
+
+use crate::messages::ProposeForRegion;
+use crate::proposer::{AsyncProposer, Proposal, Proposer};
+use crate::region::{Patch, PatchOp, RegionId};
+
+/// Requests a scripted proposer has been sent, shared with its creator.
+#[derive(Debug, Clone, Default)]
+pub struct RequestLog(Arc<Mutex<Vec<ProposeForRegion>>>);
+
+impl RequestLog {
+    /// Create an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn record(&self, request: &ProposeForRegion) {
+        self.0.lock().push(request.clone());
+    }
+
+    /// Every request so far, in arrival order.
+    pub fn requests(&self) -> Vec<ProposeForRegion> {
+        self.0.lock().clone()
+    }
+
+    /// Requests so far.
+    pub fn len(&self) -> usize {
+        self.0.lock().len()
+    }
+
+    /// Whether nothing has been requested.
+    pub fn is_empty(&self) -> bool {
+        self.0.lock().is_empty()
+    }
+
+    /// Requests so far for one region.
+    pub fn count_for(&self, region: &RegionId) -> usize {
+        self.0
+            .lock()
+            .iter()
+            .filter(|r| &r.region_id == region)
+            .count()
+    }
+}
+
+/// Replays a fixed list of patches.
+///
+/// Each request gets the first patch for its region that has not been handed
+/// out yet, or an empty proposal once the region's patches run out.
+pub struct ReplayProposer {
+    name: String,
+    patches: Mutex<Vec<Patch>>,
+    log: RequestLog,
+}
+
+impl ReplayProposer {
+    /// Replay `patches` in order.
+    pub fn new(name: impl Into<String>, patches: Vec<Patch>) -> Self {
+        Self {
+            name: name.into(),
+            patches: Mutex::new(patches),
+            log: RequestLog::new(),
+        }
+    }
+
+    /// The requests this proposer has been sent.
+    pub fn log(&self) -> RequestLog {
+        self.log.clone()
+    }
+
+    /// Patches not handed out yet.
+    pub fn remaining(&self) -> usize {
+        self.patches.lock().len()
+    }
+}
+
+impl Proposer for ReplayProposer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn propose(&self, request: &ProposeForRegion) -> anyhow::Result<Proposal> {
+        self.log.record(request);
+        let mut patches = self.patches.lock();
+        let next = patches
+            .iter()
+            .position(|p| p.region == request.region_id)
+            .map(|i| patches.remove(i));
+        Ok(Proposal::new(next.into_iter().map(|p| (1.0, p)).collect()))
+    }
+}
+
+/// Always proposes replacing the region with junk.
+pub struct GarbageProposer {
+    name: String,
+    count: AtomicU64,
+    log: RequestLog,
+}
+
+impl GarbageProposer {
+    /// Create a proposer of junk.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            count: AtomicU64::new(0),
+            log: RequestLog::new(),
+        }
+    }
+
+    /// The requests this proposer has been sent.
+    pub fn log(&self) -> RequestLog {
+        self.log.clone()
+    }
+}
+
+impl Proposer for GarbageProposer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn propose(&self, request: &ProposeForRegion) -> anyhow::Result<Proposal> {
+        self.log.record(request);
+        let n = self.count.fetch_add(1, Ordering::Relaxed);
+        Ok(Proposal::new(vec![(
+            1.0,
+            Patch {
+                region: request.region_id.clone(),
+                op: PatchOp::Replace(format!("\u{fffd}garbage #{n}\u{fffd}")),
+                rationale: "garbage".to_string(),
+                expected_delta: HashMap::new(),
+            },
+        )]))
+    }
+}
+
+/// Asks a good proposer with probability `p_good`, otherwise a bad one.
+///
+/// The coin is a seeded splitmix64 stream, so a seed replays the same
+/// sequence of choices (in request arrival order).
+pub struct RandomProposer {
+    name: String,
+    p_good: f64,
+    state: Mutex<u64>,
+    good: Box<dyn Proposer>,
+    bad: Box<dyn Proposer>,
+    log: RequestLog,
+}
+
+impl RandomProposer {
+    /// Create a proposer that is good with probability `p_good`.
+    pub fn new(
+        name: impl Into<String>,
+        seed: u64,
+        p_good: f64,
+        good: Box<dyn Proposer>,
+        bad: Box<dyn Proposer>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            p_good,
+            state: Mutex::new(seed),
+            good,
+            bad,
+            log: RequestLog::new(),
+        }
+    }
+
+    /// The requests this proposer has been sent.
+    pub fn log(&self) -> RequestLog {
+        self.log.clone()
+    }
+
+    /// Next draw in [0, 1).
+    fn draw(&self) -> f64 {
+        let mut state = self.state.lock();
+        *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = *state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+impl Proposer for RandomProposer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn propose(&self, request: &ProposeForRegion) -> anyhow::Result<Proposal> {
+        self.log.record(request);
+        if self.draw() < self.p_good {
+            self.good.propose(request)
+        } else {
+            self.bad.propose(request)
+        }
+    }
+}
+
+/// Answers like the wrapped proposer, but only after a delay.
+pub struct SleepyProposer {
+    delay: Duration,
+    inner: Box<dyn Proposer>,
+    log: RequestLog,
+}
+
+impl SleepyProposer {
+    /// Delay every answer of `inner` by `delay`.
+    pub fn new(delay: Duration, inner: Box<dyn Proposer>) -> Self {
+        Self {
+            delay,
+            inner,
+            log: RequestLog::new(),
+        }
+    }
+
+    /// The requests this proposer has been sent.
+    pub fn log(&self) -> RequestLog {
+        self.log.clone()
+    }
+}
+
+impl AsyncProposer for SleepyProposer {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn propose<'a>(
+        &'a self,
+        request: &'a ProposeForRegion,
+    ) -> BoxFuture<'a, anyhow::Result<Proposal>> {
+        self.log.record(request);
+        Box::pin(async move {
+            tokio::time::sleep(self.delay).await;
+            self.inner.propose(request)
+        })
+    }
+}
+
+/// Never answers; only a proposal timeout moves its requests on.
+pub struct SilentProposer {
+    name: String,
+    log: RequestLog,
+}
+
+impl SilentProposer {
+    /// Create a proposer that never replies.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            log: RequestLog::new(),
+        }
+    }
+
+    /// The requests this proposer has been sent.
+    pub fn log(&self) -> RequestLog {
+        self.log.clone()
+    }
+}
+
+impl AsyncProposer for SilentProposer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn propose<'a>(
+        &'a self,
+        request: &'a ProposeForRegion,
+    ) -> BoxFuture<'a, anyhow::Result<Proposal>> {
+        self.log.record(request);
+        Box::pin(futures::future::pending())
+    }
+}
@@ -0,0 +1,185 @@
+//! Stagnation kicks: escape a pressure plateau from inside the kernel.
+//!
+//! The coordinator feeds each tick's total pressure to a `StagnationTracker`.
+//! After `StagnationConfig::patience` ticks without beating the best pressure
+//! by `min_improvement`, it kicks: either the highest-pressure regions are
+//! perturbed and freed from inhibition, or the artifact is rolled back to the
+//! best snapshot and every region is kicked (see `StagnationResponse`). Kicked
+//! regions carry a seed into their next `ProposeForRegion`, asking patch
+//! actors to explore. Each kick is reported as a `StagnationKick`.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{StagnationConfig, StagnationResponse};
+use crate::region::RegionId;
+
+/// One stagnation kick, reported in the `TickResult` of its tick.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StagnationKick {
+    /// Tick the kick happened on
+    pub tick: usize,
+    /// What was done (`Perturb` when a restart had no snapshot to restore)
+    pub response: StagnationResponse,
+    /// Regions kicked
+    pub regions: Vec<RegionId>,
+    /// Regions whose content `Artifact::perturb_region` changed
+    pub perturbed: Vec<RegionId>,
+    /// Best total pressure seen before the kick
+    pub best_pressure: f64,
+    /// Seed of this kick; region `i` of `regions` explores with `seed + i`
+    pub seed: u64,
+}
+
+/// How the latest pressure compares to the best so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Progress {
+    /// New best pressure
+    Improved,
+    /// No improvement, but still within the patience window
+    Flat,
+    /// No improvement for `patience` ticks: time to kick
+    Stagnant,
+}
+
+/// Tracks the best pressure and how long it has stood.
+#[derive(Debug, Clone, Default)]
+pub struct StagnationTracker {
+    /// Lowest total pressure seen so far
+    best: Option<f64>,
+    /// Ticks since `best` last improved (or since the last kick)
+    stale_ticks: usize,
+    /// Kicks so far
+    kicks: u64,
+}
+
+impl StagnationTracker {
+    /// Create a tracker that has seen nothing yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a tick's total pressure.
+    ///
+    /// Reports `Stagnant` once every `config.patience` ticks without
+    /// improvement; never when `patience` is 0.
+    pub fn observe(&mut self, pressure: f64, config: &StagnationConfig) -> Progress {
+        let improved = self
+            .best
+            .is_none_or(|best| pressure < best - config.min_improvement);
+        if improved {
+            self.best = Some(pressure);
+            self.stale_ticks = 0;
+            return Progress::Improved;
+        }
+
+        self.stale_ticks += 1;
+        if config.patience > 0 && self.stale_ticks >= config.patience {
+            self.stale_ticks = 0;
+            Progress::Stagnant
+        } else {
+            Progress::Flat
+        }
+    }
+
+    /// Lowest total pressure seen so far.
+    pub fn best(&self) -> Option<f64> {
+        self.best
+    }
+
+    /// Seed for the next kick, distinct for every kick.
+    pub fn next_seed(&mut self, tick: usize) -> u64 {
+        self.kicks += 1;
+        splitmix64((tick as u64) << 32 ^ self.kicks)
+    }
+}
+
+/// The `k` highest-pressure regions, in descending pressure.
+///
+/// Ties keep `order`; regions without a recorded pressure are never chosen.
+pub fn highest_pressure(
+    order: &[RegionId],
+    pressures: &HashMap<RegionId, f64>,
+    k: usize,
+) -> Vec<RegionId> {
+    let mut ranked: Vec<(&RegionId, f64)> = order
+        .iter()
+        .filter_map(|id| Some((id, *pressures.get(id)?)))
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked
+        .into_iter()
+        .take(k)
+        .map(|(id, _)| id.clone())
+        .collect()
+}
+
+/// Mix bits so consecutive inputs give unrelated seeds.
+fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mti::prelude::*;
+
+    fn config(patience: usize) -> StagnationConfig {
+        StagnationConfig {
+            patience,
+            min_improvement: 0.5,
+            ..StagnationConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_tracker_kicks_after_patience() {
+        let config = config(2);
+        let mut tracker = StagnationTracker::new();
+        assert_eq!(tracker.observe(10.0, &config), Progress::Improved);
+        // Less than min_improvement does not count
+        assert_eq!(tracker.observe(9.8, &config), Progress::Flat);
+        assert_eq!(tracker.observe(9.9, &config), Progress::Stagnant);
+        // Patience restarts after a kick
+        assert_eq!(tracker.observe(9.9, &config), Progress::Flat);
+        assert_eq!(tracker.observe(4.0, &config), Progress::Improved);
+        assert_eq!(tracker.best(), Some(4.0));
+
+        let disabled = StagnationConfig::default();
+        let mut tracker = StagnationTracker::new();
+        for _ in 0..10 {
+            assert_ne!(tracker.observe(1.0, &disabled), Progress::Stagnant);
+        }
+    }
+
+    #[test]
+    fn test_seeds_differ_per_kick() {
+        let mut tracker = StagnationTracker::new();
+        let first = tracker.next_seed(5);
+        let second = tracker.next_seed(5);
+        assert_ne!(first, second);
+        assert_ne!(StagnationTracker::new().next_seed(6), first);
+    }
+
+    #[test]
+    fn test_highest_pressure() {
+        let ids: Vec<RegionId> = (0..4).map(|_| "region".create_type_id::<V7>()).collect();
+        let pressures: HashMap<RegionId, f64> = [
+            (ids[0].clone(), 1.0),
+            (ids[1].clone(), 3.0),
+            (ids[2].clone(), 3.0),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            highest_pressure(&ids, &pressures, 2),
+            vec![ids[1].clone(), ids[2].clone()]
+        );
+        assert_eq!(highest_pressure(&ids, &pressures, 10).len(), 3);
+    }
+}
@@ -0,0 +1,256 @@
+//! A toy artifact for exercising the kernel without an LLM.
+//!
+//! Each region of a `ToyArtifact` holds a non-negative integer as text, and
+//! its pressure is that integer: the artifact is solved when every region
+//! reads `0`. Content that is not such an integer costs `GARBAGE_PRESSURE`.
+//! With a collision penalty, every region whose value another region shares
+//! costs that much more, which couples the regions: two patches that are
+//! fine on their own can conflict once both are applied.
+//!
+//! `ToySensor` reports the same per-region pressure as the `value` signal
+//! (`toy_axis` is the matching pressure axis), and `Decrement` is a proposer
+//! that always knows a better value. Stagnation kicks restore snapshots and
+//! perturb regions by raising their value. Together with the proposers in
+//! `crate::scripted`, this is what the kernel's conformance tests run on.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, bail};
+use mti::prelude::*;
+
+use crate::artifact::Artifact;
+use crate::config::PressureAxisConfig;
+use crate::messages::ProposeForRegion;
+use crate::normalize::Normalization;
+use crate::pressure::{Sensor, Signals};
+use crate::proposer::{Proposal, Proposer};
+use crate::region::{Patch, PatchOp, RegionId, RegionView};
+
+/// Signal (and axis) name used by `ToySensor`.
+pub const TOY_SIGNAL: &str = "value";
+
+/// Pressure of a region whose content is not a non-negative integer.
+pub const GARBAGE_PRESSURE: f64 = 1_000.0;
+
+/// Pressure of one region's content.
+pub fn toy_pressure(content: &str) -> f64 {
+    parse_value(content).map_or(GARBAGE_PRESSURE, |v| v as f64)
+}
+
+fn parse_value(content: &str) -> Option<u64> {
+    content.trim().parse().ok()
+}
+
+/// The pressure axis that reads `ToySensor`'s signal, with weight 1.
+pub fn toy_axis() -> PressureAxisConfig {
+    PressureAxisConfig {
+        name: TOY_SIGNAL.to_string(),
+        weight: 1.0,
+        expr: TOY_SIGNAL.to_string(),
+        kind_weights: HashMap::new(),
+        hard: false,
+        priority: 0,
+        normalization: Normalization::None,
+    }
+}
+
+/// Regions of integers to be driven to zero.
+#[derive(Debug, Clone)]
+pub struct ToyArtifact {
+    regions: Vec<(RegionId, String)>,
+    collision_penalty: f64,
+}
+
+impl ToyArtifact {
+    /// One region per value, in order.
+    pub fn new(values: &[u64]) -> Self {
+        Self {
+            regions: values
+                .iter()
+                .map(|v| ("region".create_type_id::<V7>(), v.to_string()))
+                .collect(),
+            collision_penalty: 0.0,
+        }
+    }
+
+    /// Charge `penalty` for every region whose value another region shares.
+    pub fn with_collision_penalty(mut self, penalty: f64) -> Self {
+        self.collision_penalty = penalty;
+        self
+    }
+
+    /// Current content of every region, in order.
+    pub fn contents(&self) -> Vec<String> {
+        self.regions.iter().map(|(_, c)| c.clone()).collect()
+    }
+
+    fn pressure_of(&self, regions: &[(RegionId, String)]) -> f64 {
+        let values: Vec<Option<u64>> = regions.iter().map(|(_, c)| parse_value(c)).collect();
+        let collisions = values
+            .iter()
+            .enumerate()
+            .filter(|(i, v)| {
+                v.is_some()
+                    && values
+                        .iter()
+                        .enumerate()
+                        .any(|(j, other)| j != *i && other == *v)
+            })
+            .count();
+        regions.iter().map(|(_, c)| toy_pressure(c)).sum::<f64>()
+            + self.collision_penalty * collisions as f64
+    }
+
+    fn patched(&self, patch: &Patch) -> anyhow::Result<Vec<(RegionId, String)>> {
+        let PatchOp::Replace(content) = &patch.op else {
+            bail!("toy regions can only be replaced");
+        };
+        let mut regions = self.regions.clone();
+        let (_, current) = regions
+            .iter_mut()
+            .find(|(id, _)| *id == patch.region)
+            .with_context(|| format!("no region {}", patch.region))?;
+        *current = content.clone();
+        Ok(regions)
+    }
+}
+
+impl Artifact for ToyArtifact {
+    fn region_ids(&self) -> Vec<RegionId> {
+        self.regions.iter().map(|(id, _)| id.clone()).collect()
+    }
+
+    fn read_region(&self, id: RegionId) -> anyhow::Result<RegionView> {
+        let (_, content) = self
+            .regions
+            .iter()
+            .find(|(rid, _)| *rid == id)
+            .with_context(|| format!("no region {id}"))?;
+        Ok(RegionView {
+            id,
+            kind: "toy".to_string(),
+            content: content.clone(),
+            metadata: HashMap::new(),
+            parent: None,
+            children: Vec::new(),
+        })
+    }
+
+    fn apply_patch(&mut self, patch: Patch) -> anyhow::Result<()> {
+        self.regions = self.patched(&patch)?;
+        Ok(())
+    }
+
+    fn snapshot(&self) -> Option<Box<dyn Artifact>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn source(&self) -> Option<String> {
+        Some(self.contents().join("\n"))
+    }
+
+    fn evaluate_patch(&self, patch: &Patch) -> (bool, f64) {
+        match self.patched(patch) {
+            Ok(regions) => {
+                let delta = self.pressure_of(&self.regions) - self.pressure_of(&regions);
+                (delta > 0.0, delta)
+            }
+            Err(_) => (false, 0.0),
+        }
+    }
+
+    fn total_pressure(&self) -> Option<f64> {
+        Some(self.pressure_of(&self.regions))
+    }
+
+    fn perturb_region(&self, id: &RegionId, seed: u64) -> Option<Patch> {
+        // Raise the value by 1 to 3; garbage becomes a plain integer again
+        let (_, content) = self.regions.iter().find(|(rid, _)| rid == id)?;
+        let value = parse_value(content).unwrap_or(0) + 1 + seed % 3;
+        Some(Patch {
+            region: id.clone(),
+            op: PatchOp::Replace(value.to_string()),
+            rationale: "perturb".to_string(),
+            expected_delta: HashMap::new(),
+        })
+    }
+}
+
+/// Measures a toy region's pressure as the `value` signal.
+///
+/// Collision penalties are artifact-wide and not part of the signal.
+pub struct ToySensor;
+
+impl Sensor for ToySensor {
+    fn name(&self) -> &str {
+        "toy"
+    }
+
+    fn measure(&self, region: &RegionView) -> anyhow::Result<Signals> {
+        Ok(HashMap::from([(
+            TOY_SIGNAL.to_string(),
+            toy_pressure(&region.content),
+        )]))
+    }
+}
+
+/// Proposes one less than the region's value (nothing for zero or garbage).
+pub struct Decrement;
+
+impl Proposer for Decrement {
+    fn name(&self) -> &str {
+        "decrement"
+    }
+
+    fn propose(&self, request: &ProposeForRegion) -> anyhow::Result<Proposal> {
+        let patches = match parse_value(&request.region_view.content) {
+            Some(value) if value > 0 => vec![(
+                1.0,
+                Patch {
+                    region: request.region_id.clone(),
+                    op: PatchOp::Replace((value - 1).to_string()),
+                    rationale: "decrement".to_string(),
+                    expected_delta: HashMap::from([(TOY_SIGNAL.to_string(), 1.0)]),
+                },
+            )],
+            _ => Vec::new(),
+        };
+        Ok(Proposal::new(patches))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toy_pressure_and_collisions() {
+        let mut artifact = ToyArtifact::new(&[5, 3]).with_collision_penalty(10.0);
+        let ids = artifact.region_ids();
+        let replace = |i: usize, content: &str| Patch {
+            region: ids[i].clone(),
+            op: PatchOp::Replace(content.to_string()),
+            rationale: String::new(),
+            expected_delta: HashMap::new(),
+        };
+        assert_eq!(artifact.total_pressure(), Some(8.0));
+
+        // Each patch alone improves; after the first, the second collides
+        assert_eq!(artifact.evaluate_patch(&replace(0, "1")), (true, 4.0));
+        assert_eq!(artifact.evaluate_patch(&replace(1, "1")), (true, 2.0));
+        artifact.apply_patch(replace(0, "1")).unwrap();
+        assert_eq!(artifact.evaluate_patch(&replace(1, "1")), (false, -18.0));
+
+        assert!(!artifact.evaluate_patch(&replace(1, "junk")).0);
+        assert!(
+            artifact
+                .apply_patch(Patch {
+                    op: PatchOp::Delete,
+                    ..replace(1, "")
+                })
+                .is_err()
+        );
+        assert_eq!(artifact.source().as_deref(), Some("1\n3"));
+        assert_eq!(toy_pressure("junk"), GARBAGE_PRESSURE);
+    }
+}
@@ -0,0 +1,200 @@
+//! Patch transformers: repair, normalize, deduplicate, or veto proposals.
+//!
+//! The coordinator runs every proposed patch through the transformer chain
+//! (in the order added to `AsyncKernelBuilder`) before selection and
+//! evaluation. A transformer may pass a patch on, rewrite it, or veto it.
+//! Rewrites are recorded in the patch's provenance; vetoed patches are
+//! dropped.
+
+use std::sync::Arc;
+
+use crate::artifact::Artifact;
+use crate::region::{Patch, PatchOp};
+
+/// What a transformer did with one patch.
+#[derive(Debug, Clone)]
+pub enum Transformed {
+    /// Pass the patch on unchanged
+    Unchanged,
+    /// Pass on a rewritten patch; the note is recorded in provenance
+    Rewritten {
+        /// The replacement patch
+        patch: Patch,
+        /// What was changed
+        note: String,
+    },
+    /// Drop the patch
+    Vetoed {
+        /// Why the patch was dropped
+        reason: String,
+    },
+}
+
+/// A step in the patch transformer chain.
+///
+/// Implementations should be deterministic: the same patch against the same
+/// artifact state gives the same result.
+pub trait PatchTransformer: Send + Sync {
+    /// Name recorded alongside each rewrite or veto.
+    fn name(&self) -> &str;
+
+    /// Transform one patch.
+    ///
+    /// `earlier` holds the patches of the same proposal that already made it
+    /// through the whole chain, for deduplication.
+    fn transform(&self, patch: &Patch, artifact: &dyn Artifact, earlier: &[Patch]) -> Transformed;
+}
+
+/// A patch vetoed by a transformer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Veto {
+    /// Name of the vetoing transformer
+    pub transformer: String,
+    /// Its reason
+    pub reason: String,
+}
+
+/// Run `patch` through `transformers` in order.
+///
+/// Returns the final patch with one `"<transformer>: <note>"` entry per
+/// rewrite, or the first veto.
+pub fn run_chain(
+    transformers: &[Arc<dyn PatchTransformer>],
+    artifact: &dyn Artifact,
+    mut patch: Patch,
+    earlier: &[Patch],
+) -> Result<(Patch, Vec<String>), Veto> {
+    let mut notes = Vec::new();
+    for transformer in transformers {
+        match transformer.transform(&patch, artifact, earlier) {
+            Transformed::Unchanged => {}
+            Transformed::Rewritten {
+                patch: rewritten,
+                note,
+            } => {
+                notes.push(format!("{}: {}", transformer.name(), note));
+                patch = rewritten;
+            }
+            Transformed::Vetoed { reason } => {
+                return Err(Veto {
+                    transformer: transformer.name().to_string(),
+                    reason,
+                });
+            }
+        }
+    }
+    Ok((patch, notes))
+}
+
+/// Vetoes a patch identical (same region and operation) to an earlier one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DropDuplicatePatches;
+
+impl PatchTransformer for DropDuplicatePatches {
+    fn name(&self) -> &str {
+        "drop_duplicates"
+    }
+
+    fn transform(&self, patch: &Patch, _artifact: &dyn Artifact, earlier: &[Patch]) -> Transformed {
+        if earlier
+            .iter()
+            .any(|p| p.region == patch.region && p.op == patch.op)
+        {
+            Transformed::Vetoed {
+                reason: "duplicate of an earlier patch".to_string(),
+            }
+        } else {
+            Transformed::Unchanged
+        }
+    }
+}
+
+/// Trims surrounding whitespace from replacement content.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TrimContent;
+
+impl PatchTransformer for TrimContent {
+    fn name(&self) -> &str {
+        "trim"
+    }
+
+    fn transform(
+        &self,
+        patch: &Patch,
+        _artifact: &dyn Artifact,
+        _earlier: &[Patch],
+    ) -> Transformed {
+        let PatchOp::Replace(content) = &patch.op else {
+            return Transformed::Unchanged;
+        };
+        let trimmed = content.trim();
+        if trimmed.len() == content.len() {
+            return Transformed::Unchanged;
+        }
+        Transformed::Rewritten {
+            patch: Patch {
+                op: PatchOp::Replace(trimmed.to_string()),
+                ..patch.clone()
+            },
+            note: "trimmed whitespace".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::region::{RegionId, RegionView};
+    use mti::prelude::*;
+
+    struct EmptyArtifact;
+
+    impl Artifact for EmptyArtifact {
+        fn region_ids(&self) -> Vec<RegionId> {
+            Vec::new()
+        }
+
+        fn read_region(&self, id: RegionId) -> anyhow::Result<RegionView> {
+            anyhow::bail!("Region not found: {}", id)
+        }
+
+        fn apply_patch(&mut self, _patch: Patch) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn replace(region: &RegionId, content: &str) -> Patch {
+        Patch {
+            region: region.clone(),
+            op: PatchOp::Replace(content.to_string()),
+            rationale: String::new(),
+            expected_delta: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_chain_records_rewrites_in_order() {
+        let region: RegionId = "test".create_type_id::<V7>();
+        let chain: Vec<Arc<dyn PatchTransformer>> =
+            vec![Arc::new(TrimContent), Arc::new(DropDuplicatePatches)];
+
+        let (patch, notes) =
+            run_chain(&chain, &EmptyArtifact, replace(&region, "  fixed \n"), &[]).unwrap();
+        assert_eq!(patch.op, PatchOp::Replace("fixed".to_string()));
+        assert_eq!(notes, vec!["trim: trimmed whitespace".to_string()]);
+    }
+
+    #[test]
+    fn test_duplicate_is_vetoed_after_normalization() {
+        let region: RegionId = "test".create_type_id::<V7>();
+        let chain: Vec<Arc<dyn PatchTransformer>> =
+            vec![Arc::new(TrimContent), Arc::new(DropDuplicatePatches)];
+        let earlier = vec![replace(&region, "fixed")];
+
+        let veto =
+            run_chain(&chain, &EmptyArtifact, replace(&region, "fixed "), &earlier).unwrap_err();
+        assert_eq!(veto.transformer, "drop_duplicates");
+    }
+}
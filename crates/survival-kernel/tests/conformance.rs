@@ -0,0 +1,411 @@
+//! Kernel conformance: the coordinator, in tick and async mode, end to end
+//! without an LLM.
+//!
+//! Every test runs the real kernel (coordinator, region, sensor and proposer
+//! actors) on a `ToyArtifact`, with scripted proposers standing in for
+//! patch actors.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use acton_reactive::prelude::*;
+use survival_kernel::config::{ActivationConfig, DecayConfig};
+use survival_kernel::toy::toy_axis;
+use survival_kernel::{
+    Artifact, AsyncKernelBuilder, Decrement, ExecutionMode, GarbageProposer, KernelConfig,
+    KernelResult, MembershipConfig, MessagingConfig, MonitorConfig, Patch, PatchActorFactory,
+    PatchOp, ProposerActor, RandomProposer, RegionId, RegionView, ReplayProposer, Sensor, Signals,
+    SilentProposer, SleepyProposer, StopReason, ToyArtifact, ToySensor,
+};
+
+/// Fast ticks, no inhibition, pressure EMA equal to the last measurement.
+fn config(max_ticks: usize) -> KernelConfig {
+    let defaults = KernelConfig::default();
+    KernelConfig {
+        tick_interval_ms: 5,
+        max_ticks,
+        stable_threshold: 0,
+        pressure_axes: vec![toy_axis()],
+        decay: DecayConfig {
+            ema_alpha: 1.0,
+            ..defaults.decay.clone()
+        },
+        activation: ActivationConfig {
+            min_total_pressure: 0.5,
+            inhibit_ms: 0,
+        },
+        monitor: MonitorConfig {
+            enabled: true,
+            min_delta: 1.0,
+            ..MonitorConfig::default()
+        },
+        ..defaults
+    }
+}
+
+fn replace(region: &RegionId, content: &str) -> Patch {
+    Patch {
+        region: region.clone(),
+        op: PatchOp::Replace(content.to_string()),
+        rationale: "scripted".to_string(),
+        expected_delta: HashMap::new(),
+    }
+}
+
+fn kernel(config: KernelConfig, artifact: ToyArtifact) -> AsyncKernelBuilder {
+    AsyncKernelBuilder::new(config, Box::new(artifact)).add_sensor(Box::new(ToySensor))
+}
+
+async fn run(builder: AsyncKernelBuilder) -> KernelResult {
+    let mut runtime = ActonApp::launch_async().await;
+    let result = builder.run(&mut runtime, 0).await;
+    let _ = runtime.shutdown_all().await;
+    result
+}
+
+#[tokio::test]
+async fn tick_measures_then_proposes_then_applies() {
+    let artifact = ToyArtifact::new(&[4, 0, 7]);
+    let ids = artifact.region_ids();
+    let replay = ReplayProposer::new("replay", vec![replace(&ids[0], "3"), replace(&ids[2], "6")]);
+    let log = replay.log();
+
+    let result = run(kernel(config(1), artifact).add_proposer(Box::new(replay))).await;
+
+    // Only regions above the activation threshold are proposed for, and each
+    // request carries what was measured this tick
+    let requests = log.requests();
+    let requested: HashSet<_> = requests.iter().map(|r| r.region_id.clone()).collect();
+    assert_eq!(requested, HashSet::from([ids[0].clone(), ids[2].clone()]));
+    for request in &requests {
+        let value: f64 = request.region_view.content.parse().unwrap();
+        assert_eq!(request.signals["value"], value);
+    }
+
+    // Both patches were applied and the tick reports the patched pressure
+    let tick = &result.tick_results[0];
+    assert_eq!(result.stop_reason, StopReason::MaxTicks);
+    assert_eq!(tick.applied.len(), 2);
+    assert_eq!(tick.evaluated, 3);
+    assert_eq!(tick.total_pressure, 9.0);
+    assert_eq!(result.violations.total(), 0);
+}
+
+#[tokio::test]
+async fn patched_regions_are_inhibited() {
+    let artifact = ToyArtifact::new(&[9]);
+    let region = artifact.region_ids()[0].clone();
+    let patches = || vec![replace(&region, "5"), replace(&region, "1")];
+
+    // Inside the window the region is skipped, not proposed for
+    let replay = ReplayProposer::new("replay", patches());
+    let log = replay.log();
+    let inhibited = KernelConfig {
+        activation: ActivationConfig {
+            min_total_pressure: 0.5,
+            inhibit_ms: 60_000,
+        },
+        ..config(4)
+    };
+    let result = run(kernel(inhibited, artifact.clone()).add_proposer(Box::new(replay))).await;
+
+    assert_eq!(log.count_for(&region), 1);
+    assert_eq!(result.applied_patches.len(), 1);
+    assert_eq!(result.final_pressure, 5.0);
+    assert!(
+        result.tick_results[1..]
+            .iter()
+            .all(|t| t.applied.is_empty() && t.skipped == 1)
+    );
+    assert_eq!(result.violations.inhibition_violated, 0);
+
+    // Without a window the next tick patches it again
+    let replay = ReplayProposer::new("replay", patches());
+    let result = run(kernel(config(4), artifact).add_proposer(Box::new(replay))).await;
+    assert_eq!(result.applied_patches.len(), 2);
+    assert_eq!(result.final_pressure, 1.0);
+}
+
+#[tokio::test]
+async fn stable_ticks_stop_the_run() {
+    let stable = |max_ticks| KernelConfig {
+        stable_threshold: 2,
+        ..config(max_ticks)
+    };
+
+    // Nothing but garbage: every patch is rejected and the run converges
+    let garbage = GarbageProposer::new("garbage");
+    let log = garbage.log();
+    let result =
+        run(kernel(stable(10), ToyArtifact::new(&[3, 5])).add_proposer(Box::new(garbage))).await;
+    assert_eq!(
+        result.stop_reason,
+        StopReason::Converged { stable_ticks: 2 }
+    );
+    assert_eq!(result.ticks_executed, 2);
+    assert_eq!(log.len(), 4);
+    assert!(result.applied_patches.is_empty());
+    assert_eq!(result.pressure_history, vec![8.0, 8.0]);
+
+    // Solved: 2 → 1 → 0, then two ticks with nothing left to propose
+    let result =
+        run(kernel(stable(10), ToyArtifact::new(&[2])).add_proposer(Box::new(Decrement))).await;
+    assert_eq!(
+        result.stop_reason,
+        StopReason::Converged { stable_ticks: 2 }
+    );
+    assert_eq!(result.ticks_executed, 4);
+    assert_eq!(result.final_pressure, 0.0);
+}
+
+#[tokio::test]
+async fn conflicting_patches_are_rejected_on_re_evaluation() {
+    // Either patch alone improves; together the regions collide
+    let artifact = ToyArtifact::new(&[5, 3]).with_collision_penalty(10.0);
+    let ids = artifact.region_ids();
+    let replay = ReplayProposer::new("replay", vec![replace(&ids[0], "1"), replace(&ids[1], "1")]);
+
+    let result = run(kernel(config(1), artifact).add_proposer(Box::new(replay))).await;
+
+    let tick = &result.tick_results[0];
+    assert_eq!(tick.applied.len(), 1);
+    assert!([4.0, 6.0].contains(&tick.total_pressure));
+    let reputation = &result.reputation["replay"];
+    assert_eq!((reputation.attempts, reputation.accepted), (2, 1));
+}
+
+/// Run good, garbage and empty proposals mixed and return the result.
+///
+/// The inhibition window outlasts a tick, so recently patched regions sit
+/// some ticks out.
+async fn run_sloppy(config: KernelConfig) -> KernelResult {
+    let bad = RandomProposer::new(
+        "bad",
+        11,
+        0.5,
+        Box::new(GarbageProposer::new("garbage")),
+        Box::new(ReplayProposer::new("idle", Vec::new())),
+    );
+    let sloppy = RandomProposer::new("sloppy", 7, 0.4, Box::new(Decrement), Box::new(bad));
+    let config = KernelConfig {
+        activation: ActivationConfig {
+            min_total_pressure: 0.5,
+            inhibit_ms: 60,
+        },
+        ..config
+    };
+    run(kernel(config, ToyArtifact::new(&[6, 9, 4, 7, 5])).add_proposer(Box::new(sloppy))).await
+}
+
+fn assert_monotone(result: &KernelResult) {
+    assert_eq!(result.ticks_executed, 30);
+    assert!(
+        result.pressure_history.windows(2).all(|w| w[1] <= w[0]),
+        "pressure rose: {:?}",
+        result.pressure_history
+    );
+    assert!(result.final_pressure < 31.0);
+    assert_eq!(result.violations.total(), 0);
+}
+
+#[tokio::test]
+async fn pressure_never_rises_under_greedy_acceptance() {
+    assert_monotone(&run_sloppy(config(30)).await);
+}
+
+#[tokio::test]
+async fn pressure_never_rises_with_lagging_pressure_ema() {
+    // Region EMAs trail the patched values; the recorded totals must not
+    let lagging = KernelConfig {
+        decay: KernelConfig::default().decay,
+        ..config(30)
+    };
+    assert_monotone(&run_sloppy(lagging).await);
+}
+
+#[tokio::test]
+async fn pressure_never_rises_with_batched_messages() {
+    let batched = KernelConfig {
+        messaging: MessagingConfig {
+            batch_size: 2,
+            ..MessagingConfig::default()
+        },
+        ..config(30)
+    };
+    assert_monotone(&run_sloppy(batched).await);
+}
+
+/// A sensor whose every measurement fails.
+struct BrokenSensor;
+
+impl Sensor for BrokenSensor {
+    fn name(&self) -> &str {
+        "broken"
+    }
+
+    fn measure(&self, _region: &RegionView) -> anyhow::Result<Signals> {
+        anyhow::bail!("sensor offline")
+    }
+}
+
+#[tokio::test]
+async fn failed_measurements_do_not_stall_ticks() {
+    // Batched or not, a failing sensor's regions are counted and skipped
+    for batch_size in [0, 2] {
+        let config = KernelConfig {
+            messaging: MessagingConfig {
+                batch_size,
+                ..MessagingConfig::default()
+            },
+            ..config(3)
+        };
+        let builder = kernel(config, ToyArtifact::new(&[3, 5, 2]))
+            .add_sensor(Box::new(BrokenSensor))
+            .add_proposer(Box::new(Decrement));
+
+        let result = run(builder).await;
+
+        assert_eq!(
+            result.stop_reason,
+            StopReason::MaxTicks,
+            "batch {batch_size}"
+        );
+        assert_eq!(
+            result.pressure_history,
+            vec![7.0, 4.0, 2.0],
+            "batch {batch_size}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn slow_and_silent_proposers_do_not_stall_ticks() {
+    // Round-robin gives each proposer one region per tick; the silent one's
+    // region is handed to the sleepy one when its request times out
+    let silent = SilentProposer::new("silent");
+    let log = silent.log();
+    let sleepy = SleepyProposer::new(Duration::from_millis(20), Box::new(Decrement));
+    let config = KernelConfig {
+        membership: MembershipConfig {
+            heartbeat_timeout_ms: 0,
+            proposal_timeout_ms: 100,
+        },
+        ..config(3)
+    };
+
+    let result = run(kernel(config, ToyArtifact::new(&[3, 3]))
+        .add_async_proposer(Box::new(silent))
+        .add_async_proposer(Box::new(sleepy)))
+    .await;
+
+    assert_eq!(result.stop_reason, StopReason::MaxTicks);
+    assert!(result.tick_results.iter().all(|t| t.applied.len() == 2));
+    assert_eq!(result.final_pressure, 0.0);
+    assert_eq!(log.len(), 3);
+}
+
+#[tokio::test]
+async fn silent_patch_actors_are_evicted_and_replaced() {
+    // The silent actor never heartbeats or answers, so it misses its
+    // deadline; the supervisor's replacement finishes the job
+    let silent = SilentProposer::new("silent");
+    let log = silent.log();
+    let restarts = Arc::new(AtomicUsize::new(0));
+    let spawned = restarts.clone();
+    let factory: PatchActorFactory = Arc::new(move |mut runtime, restart| {
+        spawned.store(restart, Ordering::Relaxed);
+        Box::pin(async move {
+            ProposerActor::new(Arc::new(Decrement))
+                .spawn(&mut runtime)
+                .await
+        })
+    });
+    let config = KernelConfig {
+        membership: MembershipConfig {
+            heartbeat_timeout_ms: 50,
+            proposal_timeout_ms: 0,
+        },
+        ..config(20)
+    };
+
+    let silent = ProposerActor::new_async(Arc::new(silent)).with_heartbeat_interval(Duration::ZERO);
+
+    let result = run(kernel(config, ToyArtifact::new(&[2, 2]))
+        .add_proposer_actor(silent)
+        .supervise_patch_actors(factory, 1))
+    .await;
+
+    assert_eq!(log.len(), 2);
+    assert_eq!(restarts.load(Ordering::Relaxed), 1);
+    assert_eq!(result.final_pressure, 0.0);
+    assert_eq!(result.reputation["decrement"].accepted, 4);
+}
+
+#[tokio::test]
+async fn idle_proposers_heartbeat_past_the_timeout() {
+    // Ticks are further apart than the heartbeat timeout, so between ticks
+    // both proposers sit idle; only their heartbeats keep them registered
+    let idle = ReplayProposer::new("idle", Vec::new());
+    let log = idle.log();
+    let sleepy = SleepyProposer::new(Duration::from_millis(40), Box::new(idle));
+    let restarts = Arc::new(AtomicUsize::new(0));
+    let spawned = restarts.clone();
+    let factory: PatchActorFactory = Arc::new(move |mut runtime, _restart| {
+        spawned.fetch_add(1, Ordering::Relaxed);
+        Box::pin(async move {
+            ProposerActor::new(Arc::new(Decrement))
+                .spawn(&mut runtime)
+                .await
+        })
+    });
+    let config = KernelConfig {
+        tick_interval_ms: 100,
+        membership: MembershipConfig {
+            heartbeat_timeout_ms: 50,
+            proposal_timeout_ms: 0,
+        },
+        ..config(6)
+    };
+
+    let result = run(kernel(config, ToyArtifact::new(&[2, 2]))
+        .add_proposer(Box::new(Decrement))
+        .add_async_proposer(Box::new(sleepy))
+        .supervise_patch_actors(factory, 4))
+    .await;
+
+    // Still in the dispatch pool on later ticks, never replaced
+    assert_eq!(restarts.load(Ordering::Relaxed), 0);
+    assert!(log.len() > 1, "idle proposer dropped out: {}", log.len());
+    assert!(result.final_pressure < 4.0);
+}
+
+#[tokio::test]
+async fn async_regions_drive_pressure_down_on_their_own() {
+    // Regions wake, measure and request proposals themselves; ticks only
+    // observe. An idle proposer shares the requests without taking them all.
+    let config = KernelConfig {
+        mode: ExecutionMode::Async,
+        ..config(50)
+    };
+    let replay = ReplayProposer::new("replay", Vec::new());
+    let log = replay.log();
+
+    let result = run(kernel(config, ToyArtifact::new(&[4, 6, 3]))
+        .add_proposer(Box::new(Decrement))
+        .add_proposer(Box::new(replay)))
+    .await;
+
+    assert_eq!(result.stop_reason, StopReason::MaxTicks);
+    assert_eq!(result.final_pressure, 0.0);
+    assert!(
+        result.pressure_history.windows(2).all(|w| w[1] <= w[0]),
+        "pressure rose: {:?}",
+        result.pressure_history
+    );
+    assert_eq!(result.applied_patches.len(), 13);
+    assert_eq!(result.reputation["decrement"].accepted, 13);
+    assert!(!log.is_empty());
+    assert_eq!(result.violations.total(), 0);
+}